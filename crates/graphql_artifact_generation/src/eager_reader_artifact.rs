@@ -16,8 +16,8 @@ use std::{
 use crate::{
     generate_artifacts::{
         generate_client_field_parameter_type, generate_output_type, generate_parameters,
-        generate_path, ClientFieldFunctionImportStatement, RESOLVER_OUTPUT_TYPE,
-        RESOLVER_PARAMETERS_TYPE, RESOLVER_PARAM_TYPE, RESOLVER_READER,
+        generate_path, selection_set_nulls_parent, ClientFieldFunctionImportStatement,
+        RESOLVER_OUTPUT_TYPE, RESOLVER_PARAMETERS_TYPE, RESOLVER_PARAM_TYPE, RESOLVER_READER,
     },
     import_statements::{
         param_type_imports_to_import_param_statement, param_type_imports_to_import_statement,
@@ -203,6 +203,8 @@ pub(crate) fn generate_eager_reader_param_type_artifact(
         &mut param_type_imports,
         &mut loadable_fields,
         1,
+        false,
+        false,
     );
 
     let param_type_import_statement =
@@ -231,13 +233,22 @@ pub(crate) fn generate_eager_reader_param_type_artifact(
         ("".to_string(), "Record<PropertyKey, never>".to_string())
     };
 
+    // If a field selected with @required(action: LOG) or @required(action: NONE) is
+    // null, data is null instead.
+    let data_null_type =
+        if selection_set_nulls_parent(client_field.selection_set_for_parent_query()) {
+            " | null"
+        } else {
+            ""
+        };
+
     let indent = "  ";
     let param_type_content = format!(
         "{param_type_import_statement}\
         {loadable_field_imports}\
         {parameters_import}\n\
         export type {reader_param_type} = {{\n\
-        {indent}readonly data: {client_field_parameter_type},\n\
+        {indent}readonly data: {client_field_parameter_type}{data_null_type},\n\
         {indent}readonly parameters: {parameters_type},\n\
         }};\n",
     );
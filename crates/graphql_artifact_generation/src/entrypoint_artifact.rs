@@ -7,10 +7,11 @@ use intern::{string_key::Intern, Lookup};
 use isograph_config::GenerateFileExtensionsOption;
 use isograph_lang_types::{ClientFieldId, IsographSelectionVariant, ServerObjectId};
 use isograph_schema::{
-    create_merged_selection_map_for_field_and_insert_into_global_map,
+    assign_unique_labels, create_merged_selection_map_for_field_and_insert_into_global_map,
     current_target_merged_selections, get_imperatively_loaded_artifact_info,
-    get_reachable_variables, ClientFieldToCompletedMergeTraversalStateMap, FieldTraversalResult,
-    FieldType, MergedSelectionMap, RootOperationName, RootRefetchedPath,
+    get_reachable_variables, paginated_field_selection_map,
+    ClientFieldToCompletedMergeTraversalStateMap, FieldTraversalResult, FieldType,
+    MergedSelectionMap, RefetchedPathInfo, RootOperationName, RootRefetchedPath,
     ScalarClientFieldTraversalState, SchemaObject, ValidatedClientField, ValidatedSchema,
     ValidatedVariableDefinition,
 };
@@ -104,10 +105,12 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_field_traversal_result<'
         });
 
     let parent_object = schema.server_field_data.object(entrypoint.parent_object_id);
+    let mut labeled_selection_map = merged_selection_map.clone();
+    assign_unique_labels(&mut labeled_selection_map);
     let query_text = generate_query_text(
         query_name,
         schema,
-        merged_selection_map,
+        &labeled_selection_map,
         variable_definitions,
         root_operation_name,
     );
@@ -115,26 +118,43 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_field_traversal_result<'
         .refetch_paths
         .iter()
         .map(|((path, selection_variant), root_refetch_path)| {
-            let current_target_merged_selections = match selection_variant {
-                IsographSelectionVariant::Regular => {
-                    current_target_merged_selections(&path.linked_fields, merged_selection_map)
-                }
-                IsographSelectionVariant::Loadable(_) => {
-                    // Note: it would be cleaner to include a reference to the merged selection set here via
-                    // the selection_variant variable, instead of by looking it up like this.
-                    &encountered_client_field_map
-                        .get(&FieldType::ClientField(
-                            root_refetch_path.path_to_refetch_field_info.client_field_id,
-                        ))
-                        .expect(
-                            "Expected field to have been encountered, \
-                                since it is being used as a refetch field.",
+            let current_target_merged_selections =
+                match (selection_variant, &root_refetch_path.refetched_path_info) {
+                    (_, RefetchedPathInfo::PaginatedField(paginated_field_info)) => {
+                        let (connection_key, parent_path) = path.linked_fields.split_last().expect(
+                            "Expected path to paginated field to be non-empty. \
+                        This is indicative of a bug in Isograph.",
+                        );
+                        paginated_field_selection_map(
+                            current_target_merged_selections(parent_path, merged_selection_map),
+                            connection_key,
+                            paginated_field_info.direction,
                         )
-                        .merged_selection_map
-                }
-            };
+                    }
+                    (
+                        IsographSelectionVariant::Loadable(_),
+                        RefetchedPathInfo::ImperativelyLoadedField(path_to_refetch_field_info),
+                    ) => {
+                        // Note: it would be cleaner to include a reference to the merged selection set here via
+                        // the selection_variant variable, instead of by looking it up like this.
+                        encountered_client_field_map
+                            .get(&FieldType::ClientField(
+                                path_to_refetch_field_info.client_field_id,
+                            ))
+                            .expect(
+                                "Expected field to have been encountered, \
+                                since it is being used as a refetch field.",
+                            )
+                            .merged_selection_map
+                            .clone()
+                    }
+                    (_, RefetchedPathInfo::ImperativelyLoadedField(_)) => {
+                        current_target_merged_selections(&path.linked_fields, merged_selection_map)
+                            .clone()
+                    }
+                };
 
-            let reachable_variables = get_reachable_variables(current_target_merged_selections);
+            let reachable_variables = get_reachable_variables(&current_target_merged_selections);
             (
                 root_refetch_path.clone(),
                 current_target_merged_selections,
@@ -143,11 +163,14 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_field_traversal_result<'
         })
         .collect::<Vec<_>>();
 
-    let refetch_query_artifact_import =
-        generate_refetch_query_artifact_import(&refetch_paths_with_variables, file_extensions);
+    let refetch_query_artifact_import = generate_refetch_query_artifact_import(
+        schema,
+        &refetch_paths_with_variables,
+        file_extensions,
+    );
 
     let normalization_ast_text =
-        generate_normalization_ast_text(schema, merged_selection_map.values(), 0);
+        generate_normalization_ast_text(schema, labeled_selection_map.values(), 0);
 
     let concrete_type = schema.server_field_data.object(
         if schema
@@ -186,7 +209,7 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_field_traversal_result<'
             schema,
             entrypoint,
             root_refetch_path,
-            nested_selection_map,
+            &nested_selection_map,
             &reachable_variables,
             index,
         );
@@ -201,9 +224,10 @@ pub(crate) fn generate_entrypoint_artifacts_with_client_field_traversal_result<'
 }
 
 fn generate_refetch_query_artifact_import(
+    schema: &ValidatedSchema,
     root_refetched_paths: &[(
         RootRefetchedPath,
-        &MergedSelectionMap,
+        MergedSelectionMap,
         BTreeSet<VariableName>,
     )],
     file_extensions: GenerateFileExtensionsOption,
@@ -214,7 +238,7 @@ fn generate_refetch_query_artifact_import(
     let mut array_syntax = String::new();
     for (query_index, item) in root_refetched_paths.iter().enumerate() {
         let RootRefetchedPath {
-            path_to_refetch_field_info,
+            refetched_path_info,
             ..
         } = &item.0;
         output.push_str(&format!(
@@ -223,12 +247,34 @@ fn generate_refetch_query_artifact_import(
             query_index,
             file_extensions.ts()
         ));
+        // What are we doing here?
+        let top_level_field_arguments = match refetched_path_info {
+            RefetchedPathInfo::ImperativelyLoadedField(path_to_refetch_field_info) => {
+                path_to_refetch_field_info
+                    .imperatively_loaded_field_variant
+                    .top_level_schema_field_variables
+                    .clone()
+            }
+            RefetchedPathInfo::PaginatedField(paginated_field_info) => {
+                if schema
+                    .fetchable_types
+                    .contains_key(&paginated_field_info.refetch_field_parent_id)
+                {
+                    vec![]
+                } else {
+                    schema
+                        .server_field_data
+                        .object(paginated_field_info.refetch_field_parent_id)
+                        .refetch_field
+                        .as_ref()
+                        .map(|refetch_field| refetch_field.arguments.clone())
+                        .unwrap_or_default()
+                }
+            }
+        };
         let variable_names_str = variable_names_to_string(
             &item.2,
-            // What are we doing here?
-            path_to_refetch_field_info
-                .imperatively_loaded_field_variant
-                .top_level_schema_field_arguments
+            top_level_field_arguments
                 .iter()
                 .map(|x| x.name.item.lookup().intern().into()),
         );
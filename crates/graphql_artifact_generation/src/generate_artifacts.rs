@@ -1,27 +1,26 @@
 use common_lang_types::{
     ArtifactFileType, ArtifactPathAndContent, DescriptionValue, IsographObjectTypeName, Location,
-    SelectableFieldName, Span, WithLocation, WithSpan,
-};
-use graphql_lang_types::{
-    GraphQLNamedTypeAnnotation, GraphQLNonNullTypeAnnotation, GraphQLTypeAnnotation,
+    SelectableFieldName, StringLiteralValue, WithLocation, WithSpan,
 };
+use graphql_lang_types::{GraphQLNonNullTypeAnnotation, GraphQLTypeAnnotation};
 use intern::{string_key::Intern, Lookup};
 
 use isograph_config::{GenerateFileExtensionsOption, OptionalValidationLevel};
 use isograph_lang_types::{
-    ArgumentKeyAndValue, ClientFieldId, NonConstantValue, SelectableServerFieldId, SelectionType,
-    ServerFieldSelection, TypeAnnotation, UnionVariant, VariableDefinition,
+    ArgumentKeyAndValue, ClientFieldId, NonConstantValue, RequiredAction, SelectableServerFieldId,
+    SelectionCondition, SelectionConditionKind, SelectionType, ServerFieldSelection,
+    TypeAnnotation, UnionTypeAnnotation, UnionVariant, VariableDefinition,
 };
 use isograph_schema::{
-    get_provided_arguments, selection_map_wrapped, ClientFieldVariant, ClientType,
-    FieldTraversalResult, FieldType, NameAndArguments, NormalizationKey, RequiresRefinement,
-    SchemaObject, SchemaServerFieldVariant, UserWrittenComponentVariant, ValidatedClientField,
-    ValidatedIsographSelectionVariant, ValidatedSchema, ValidatedSelection,
-    ValidatedVariableDefinition,
+    get_provided_arguments, selection_map_wrapped, ClientFieldToCompletedMergeTraversalStateMap,
+    ClientFieldVariant, ClientType, FieldTraversalResult, FieldType, NameAndArguments,
+    NormalizationKey, RequiresRefinement, SchemaObject, SchemaServerFieldVariant,
+    UserWrittenComponentVariant, ValidatedClientField, ValidatedIsographSelectionVariant,
+    ValidatedSchema, ValidatedSelection, ValidatedVariableDefinition, TYPENAME_FIELD_NAME,
 };
 use lazy_static::lazy_static;
 use std::{
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeSet, HashSet},
     fmt::{self, Debug, Display},
     path::{Path, PathBuf},
 };
@@ -41,6 +40,7 @@ use crate::{
     refetch_reader_artifact::{
         generate_refetch_output_type_artifact, generate_refetch_reader_artifact,
     },
+    updater_artifact::generate_updater_artifact,
 };
 
 lazy_static! {
@@ -51,6 +51,7 @@ lazy_static! {
     pub static ref RESOLVER_OUTPUT_TYPE: ArtifactFileType = "output_type".intern().into();
     pub static ref ENTRYPOINT: ArtifactFileType = "entrypoint".intern().into();
     pub static ref ISO_TS: ArtifactFileType = "iso".intern().into();
+    pub static ref UPDATER: ArtifactFileType = "updater".intern().into();
 }
 
 /// Get all artifacts according to the following scheme:
@@ -74,14 +75,18 @@ lazy_static! {
 /// Also, for each user-written resolver, we must generate a param_type artifact.
 /// For each resolver that is reachable from a reader, we must also generate an
 /// output_type artifact.
+///
+/// `encountered_client_field_map` may contain merged selection maps that were
+/// already created (e.g. during validation). They are reused, and any missing
+/// merged selection maps are created.
 pub fn get_artifact_path_and_content(
     schema: &ValidatedSchema,
+    mut encountered_client_field_map: ClientFieldToCompletedMergeTraversalStateMap,
     project_root: &Path,
     artifact_directory: &Path,
     file_extensions: GenerateFileExtensionsOption,
     on_missing_babel_transform: OptionalValidationLevel,
 ) -> Vec<ArtifactPathAndContent> {
-    let mut encountered_client_field_map = BTreeMap::new();
     let mut path_and_contents = vec![];
     let mut encountered_output_types = HashSet::<ClientFieldId>::new();
 
@@ -154,81 +159,106 @@ pub fn get_artifact_path_and_content(
                                 file_extensions,
                             ));
 
-                            // Everything about this is quite sus
-                            let id_arg = ArgumentKeyAndValue {
-                                key: "id".intern().into(),
-                                value: NonConstantValue::Variable("id".intern().into()),
-                            };
-
-                            let type_to_refine_to = schema
-                                .server_field_data
-                                .object(encountered_client_field.parent_object_id);
+                            if schema.is_query_root(encountered_client_field.parent_object_id) {
+                                // Loadable fields on the query root are refetched by re-running
+                                // a root query, so the selections are not wrapped.
+                                path_and_contents.extend(
+                                    generate_entrypoint_artifacts_with_client_field_traversal_result(
+                                        schema,
+                                        encountered_client_field,
+                                        merged_selection_map,
+                                        traversal_state,
+                                        &encountered_client_field_map,
+                                        encountered_client_field
+                                            .variable_definitions
+                                            .iter()
+                                            .map(|variable_defition| &variable_defition.item),
+                                        &schema.find_query(),
+                                        file_extensions,
+                                    ),
+                                );
+                            } else {
+                                let parent_object = schema
+                                    .server_field_data
+                                    .object(encountered_client_field.parent_object_id);
+                                let refetch_field = parent_object.refetch_field.as_ref().expect(
+                                    "Expected loadable field's parent to have a refetch field. \
+                                    This is indicative of a bug in Isograph.",
+                                );
+                                let top_level_arguments = refetch_field.top_level_arguments();
 
-                            if schema
-                                .fetchable_types
-                                .contains_key(&encountered_client_field.parent_object_id)
-                            {
-                                panic!("Loadable fields on root objects are not yet supported");
-                            }
+                                let wrapped_map = selection_map_wrapped(
+                                    merged_selection_map.clone(),
+                                    refetch_field.field_name,
+                                    top_level_arguments.clone(),
+                                    refetch_field.field_concrete_type,
+                                    None,
+                                    None,
+                                    refetch_field.requires_refinement,
+                                );
+                                let refetch_field_variables = refetch_field
+                                    .arguments
+                                    .iter()
+                                    .map(|argument| ValidatedVariableDefinition {
+                                        name: WithLocation::new(
+                                            argument.name.item,
+                                            Location::Generated,
+                                        ),
+                                        type_: argument.type_.clone().map(|type_name| {
+                                            *schema
+                                                .server_field_data
+                                                .defined_types
+                                                .get(&type_name)
+                                                .expect(
+                                                    "Expected type to be found, \
+                                                    this indicates a bug in Isograph",
+                                                )
+                                        }),
+                                        default_value: None,
+                                    })
+                                    .collect::<Vec<_>>();
+                                let variable_definitions_iter = encountered_client_field
+                                    .variable_definitions
+                                    .iter()
+                                    .map(|variable_defition| &variable_defition.item)
+                                    .chain(refetch_field_variables.iter());
+                                let mut traversal_state = traversal_state.clone();
+                                traversal_state.refetch_paths = traversal_state
+                                    .refetch_paths
+                                    .into_iter()
+                                    .map(|(mut key, value)| {
+                                        if let RequiresRefinement::Yes(type_to_refine_to) =
+                                            refetch_field.requires_refinement
+                                        {
+                                            key.0.linked_fields.insert(
+                                                0,
+                                                NormalizationKey::InlineFragment(type_to_refine_to),
+                                            );
+                                        }
+                                        key.0.linked_fields.insert(
+                                            0,
+                                            NormalizationKey::ServerField(NameAndArguments {
+                                                name: refetch_field.field_name.into(),
+                                                arguments: top_level_arguments.clone(),
+                                            }),
+                                        );
+                                        (key, value)
+                                    })
+                                    .collect();
 
-                            let wrapped_map = selection_map_wrapped(
-                                merged_selection_map.clone(),
-                                "node".intern().into(),
-                                vec![id_arg.clone()],
-                                None,
-                                None,
-                                None,
-                                RequiresRefinement::Yes(type_to_refine_to.name),
-                            );
-                            let id_var = ValidatedVariableDefinition {
-                                name: WithLocation::new("id".intern().into(), Location::Generated),
-                                type_: GraphQLTypeAnnotation::NonNull(Box::new(
-                                    GraphQLNonNullTypeAnnotation::Named(
-                                        GraphQLNamedTypeAnnotation(WithSpan::new(
-                                            SelectableServerFieldId::Scalar(schema.id_type_id),
-                                            Span::todo_generated(),
-                                        )),
+                                path_and_contents.extend(
+                                    generate_entrypoint_artifacts_with_client_field_traversal_result(
+                                        schema,
+                                        encountered_client_field,
+                                        &wrapped_map,
+                                        &traversal_state,
+                                        &encountered_client_field_map,
+                                        variable_definitions_iter,
+                                        &schema.find_query(),
+                                        file_extensions,
                                     ),
-                                )),
-                                default_value: None,
-                            };
-                            let variable_definitions_iter = encountered_client_field
-                                .variable_definitions
-                                .iter()
-                                .map(|variable_defition| &variable_defition.item)
-                                .chain(std::iter::once(&id_var));
-                            let mut traversal_state = traversal_state.clone();
-                            traversal_state.refetch_paths = traversal_state
-                                .refetch_paths
-                                .into_iter()
-                                .map(|(mut key, value)| {
-                                    key.0.linked_fields.insert(
-                                        0,
-                                        NormalizationKey::InlineFragment(type_to_refine_to.name),
-                                    );
-                                    key.0.linked_fields.insert(
-                                        0,
-                                        NormalizationKey::ServerField(NameAndArguments {
-                                            name: "node".intern().into(),
-                                            arguments: vec![id_arg.clone()],
-                                        }),
-                                    );
-                                    (key, value)
-                                })
-                                .collect();
-
-                            path_and_contents.extend(
-                                generate_entrypoint_artifacts_with_client_field_traversal_result(
-                                    schema,
-                                    encountered_client_field,
-                                    &wrapped_map,
-                                    &traversal_state,
-                                    &encountered_client_field_map,
-                                    variable_definitions_iter,
-                                    &schema.find_query(),
-                                    file_extensions,
-                                ),
-                            );
+                                );
+                            }
                         }
                     }
                     ClientFieldVariant::ImperativelyLoadedField(variant) => {
@@ -258,6 +288,8 @@ pub fn get_artifact_path_and_content(
             user_written_client_field,
             file_extensions,
         ));
+        // and, if it selects updatable fields, an updater artifact
+        path_and_contents.extend(generate_updater_artifact(schema, user_written_client_field));
 
         match encountered_client_field_map
             .get(&FieldType::ClientField(user_written_client_field.id))
@@ -400,6 +432,41 @@ pub(crate) fn get_serialized_field_arguments(
     s
 }
 
+/// The conditions (from @skip and @include) of a normalization or reader AST node,
+/// which are omitted if the selection is unconditional.
+pub(crate) fn get_serialized_selection_conditions(
+    conditions: &[SelectionCondition],
+    indentation_level: u8,
+) -> String {
+    if conditions.is_empty() {
+        return "".to_string();
+    }
+
+    let indent_1 = "  ".repeat(indentation_level as usize);
+    let indent_2 = "  ".repeat((indentation_level + 1) as usize);
+    let mut s = format!("{indent_1}conditions: [\n");
+    for condition in conditions {
+        let kind = match condition.kind {
+            SelectionConditionKind::Skip => "Skip",
+            SelectionConditionKind::Include => "Include",
+        };
+        let value = match &condition.value {
+            NonConstantValue::Variable(variable_name) => {
+                format!("{{ kind: \"Variable\", name: \"{variable_name}\" }}")
+            }
+            NonConstantValue::Boolean(bool) => format!("{{ kind: \"Literal\", value: {bool} }}"),
+            NonConstantValue::Null => "{ kind: \"Literal\", value: null }".to_string(),
+            _ => panic!(
+                "Expected condition to be a variable or a boolean. \
+                This is indicative of a bug in Isograph."
+            ),
+        };
+        s.push_str(&format!("{indent_2}{{ kind: \"{kind}\", if: {value} }},\n"));
+    }
+    s.push_str(&format!("{indent_1}],\n"));
+    s
+}
+
 pub(crate) fn generate_output_type(client_field: &ValidatedClientField) -> ClientFieldOutputType {
     let variant = &client_field.variant;
     match variant {
@@ -431,6 +498,7 @@ pub fn generate_path(
     PathBuf::from(object_name.lookup()).join(field_name.lookup())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn generate_client_field_parameter_type(
     schema: &ValidatedSchema,
     selection_map: &[WithSpan<ValidatedSelection>],
@@ -438,6 +506,66 @@ pub(crate) fn generate_client_field_parameter_type(
     nested_client_field_imports: &mut ParamTypeImports,
     loadable_fields: &mut ParamTypeImports,
     indentation_level: u8,
+    // Connections selected with @paginated have a loadMore function
+    is_paginated: bool,
+    // The type of the updatable data, in which fields selected within an
+    // @updatable selection set can be written to, and which only contains
+    // server fields.
+    is_updatable: bool,
+) -> ClientFieldParameterType {
+    match &parent_type.concrete_subtypes {
+        // If __typename is selected on an abstract type, the parameter type is a union
+        // with one variant per concrete type, which can be narrowed by checking __typename.
+        Some(concrete_subtypes)
+            if !concrete_subtypes.is_empty() && selects_typename(selection_map) =>
+        {
+            let variants: Vec<String> = concrete_subtypes
+                .iter()
+                .map(|concrete_subtype_id| {
+                    generate_selection_set_parameter_type(
+                        schema,
+                        selection_map,
+                        parent_type,
+                        schema
+                            .server_field_data
+                            .object(*concrete_subtype_id)
+                            .concrete_type,
+                        nested_client_field_imports,
+                        loadable_fields,
+                        indentation_level,
+                        is_paginated,
+                        is_updatable,
+                    )
+                    .0
+                })
+                .collect();
+            ClientFieldParameterType(variants.join(" | "))
+        }
+        _ => generate_selection_set_parameter_type(
+            schema,
+            selection_map,
+            parent_type,
+            None,
+            nested_client_field_imports,
+            loadable_fields,
+            indentation_level,
+            is_paginated,
+            is_updatable,
+        ),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn generate_selection_set_parameter_type(
+    schema: &ValidatedSchema,
+    selection_map: &[WithSpan<ValidatedSelection>],
+    parent_type: &SchemaObject,
+    concrete_type: Option<IsographObjectTypeName>,
+    nested_client_field_imports: &mut ParamTypeImports,
+    loadable_fields: &mut ParamTypeImports,
+    indentation_level: u8,
+    is_paginated: bool,
+    is_updatable: bool,
 ) -> ClientFieldParameterType {
     // TODO use unwraps
     let mut client_field_parameter_type = "{\n".to_string();
@@ -447,24 +575,65 @@ pub(crate) fn generate_client_field_parameter_type(
             &mut client_field_parameter_type,
             selection,
             parent_type,
+            concrete_type,
             nested_client_field_imports,
             loadable_fields,
             indentation_level + 1,
+            is_updatable,
         );
     }
+    if is_paginated {
+        client_field_parameter_type.push_str(&format!(
+            "{}readonly loadMore: (count: number) => void,\n",
+            "  ".repeat((indentation_level + 1) as usize)
+        ));
+    }
     client_field_parameter_type.push_str(&format!("{}}}", "  ".repeat(indentation_level as usize)));
 
     ClientFieldParameterType(client_field_parameter_type)
 }
 
+fn selects_typename(selection_map: &[WithSpan<ValidatedSelection>]) -> bool {
+    selection_map.iter().any(|selection| match &selection.item {
+        ServerFieldSelection::ScalarField(scalar_field_selection) => {
+            scalar_field_selection.name.item == *TYPENAME_FIELD_NAME
+        }
+        ServerFieldSelection::LinkedField(_) => false,
+    })
+}
+
+/// If the field is a refinement pointer (e.g. asMember), the concrete type it refines to.
+fn get_refinement_pointer_concrete_type(
+    schema: &ValidatedSchema,
+    parent_type: &SchemaObject,
+    field_name: SelectableFieldName,
+) -> Option<IsographObjectTypeName> {
+    let server_field_id = parent_type
+        .encountered_fields
+        .get(&field_name)?
+        .as_server_field()?;
+    match &schema.server_field(*server_field_id).associated_data {
+        SelectionType::Object(associated_data) => match &associated_data.variant {
+            SchemaServerFieldVariant::InlineFragment(inline_fragment) => {
+                Some(inline_fragment.concrete_type)
+            }
+            SchemaServerFieldVariant::LinkedField => None,
+        },
+        SelectionType::Scalar(_) => None,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn write_param_type_from_selection(
     schema: &ValidatedSchema,
     query_type_declaration: &mut String,
     selection: &WithSpan<ValidatedSelection>,
     parent_type: &SchemaObject,
+    concrete_type: Option<IsographObjectTypeName>,
     nested_client_field_imports: &mut ParamTypeImports,
     loadable_fields: &mut ParamTypeImports,
     indentation_level: u8,
+    is_updatable: bool,
 ) {
     match &selection.item {
         ServerFieldSelection::ScalarField(scalar_field_selection) => {
@@ -480,6 +649,7 @@ fn write_param_type_from_selection(
 
                     write_optional_description(
                         field.description,
+                        field.deprecation_reason,
                         query_type_declaration,
                         indentation_level,
                     );
@@ -488,28 +658,53 @@ fn write_param_type_from_selection(
 
                     let output_type = match &field.associated_data {
                         // TODO there should be a clever way to print without cloning
-                        SelectionType::Scalar(type_name) => {
-                            type_name.clone().map(&mut |scalar_id| {
-                                schema.server_field_data.scalar(scalar_id).javascript_name
-                            })
-                        }
+                        SelectionType::Scalar(type_name) => non_null_if_required(
+                            nullable_if_client_only(
+                                type_name.clone().map(&mut |scalar_id| {
+                                    schema.server_field_data.scalar(scalar_id).javascript_name
+                                }),
+                                field.is_client_only,
+                            ),
+                            scalar_field_selection.associated_data.required,
+                        ),
                         // TODO not just scalars, enums as well. Both should have a javascript name
                         SelectionType::Object(_) => {
                             panic!("output_type_id should be a scalar")
                         }
                     };
 
+                    let output_type = match concrete_type {
+                        Some(concrete_type)
+                            if scalar_field_selection.name.item == *TYPENAME_FIELD_NAME =>
+                        {
+                            format!("\"{concrete_type}\"")
+                        }
+                        _ => print_javascript_type_declaration(&output_type),
+                    };
+
+                    // Only fields selected within an @updatable selection set can be
+                    // written to.
+                    let readonly =
+                        if is_updatable && scalar_field_selection.associated_data.is_updatable {
+                            ""
+                        } else {
+                            "readonly "
+                        };
+
                     query_type_declaration.push_str(&format!(
-                        "{}readonly {}: {},\n",
+                        "{}{readonly}{}{}: {},\n",
                         "  ".repeat(indentation_level as usize),
                         name_or_alias,
-                        print_javascript_type_declaration(&output_type)
+                        optional_marker(&scalar_field_selection.associated_data.conditions),
+                        output_type
                     ));
                 }
+                FieldType::ClientField(_) if is_updatable => {}
                 FieldType::ClientField(client_field_id) => {
                     let client_field = schema.client_field(client_field_id);
                     write_optional_description(
                         client_field.description,
+                        None,
                         query_type_declaration,
                         indentation_level,
                     );
@@ -525,6 +720,10 @@ fn write_param_type_from_selection(
                     let output_type = match scalar_field_selection.associated_data.selection_variant
                     {
                         ValidatedIsographSelectionVariant::Regular => inner_output_type,
+                        ValidatedIsographSelectionVariant::Paginated(_) => panic!(
+                            "Expected @paginated not to be used on a scalar field. \
+                            This is indicative of a bug in Isograph."
+                        ),
                         ValidatedIsographSelectionVariant::Loadable(_) => {
                             loadable_fields.insert(client_field.type_and_field);
                             let provided_arguments = get_provided_arguments(
@@ -560,8 +759,9 @@ fn write_param_type_from_selection(
 
                     query_type_declaration.push_str(
                         &(format!(
-                            "readonly {}: {},\n",
+                            "readonly {}{}: {},\n",
                             scalar_field_selection.name_or_alias().item,
+                            optional_marker(&scalar_field_selection.associated_data.conditions),
                             output_type
                         )),
                     );
@@ -569,6 +769,14 @@ fn write_param_type_from_selection(
             }
         }
         ServerFieldSelection::LinkedField(linked_field) => {
+            let refinement_pointer_concrete_type = get_refinement_pointer_concrete_type(
+                schema,
+                parent_type,
+                linked_field.name.item.into(),
+            );
+            if is_updatable && refinement_pointer_concrete_type.is_some() {
+                return;
+            }
             let parent_field = parent_type
                 .encountered_fields
                 .get(&linked_field.name.item.into())
@@ -578,6 +786,7 @@ fn write_param_type_from_selection(
             let field = schema.server_field(*parent_field);
             write_optional_description(
                 field.description,
+                field.deprecation_reason,
                 query_type_declaration,
                 indentation_level,
             );
@@ -603,19 +812,146 @@ fn write_param_type_from_selection(
                                 nested_client_field_imports,
                                 loadable_fields,
                                 indentation_level,
+                                !is_updatable
+                                    && matches!(
+                                        linked_field.associated_data.selection_variant,
+                                        ValidatedIsographSelectionVariant::Paginated(_)
+                                    ),
+                                is_updatable,
                             )
                         }),
                 };
+            let type_annotation = nullable_if_client_only(type_annotation, field.is_client_only);
+            // If a field selected with @required(action: LOG) or @required(action: NONE)
+            // is null, the object containing it is null instead.
+            let type_annotation = if selection_set_nulls_parent(&linked_field.selection_set) {
+                object_nullable(type_annotation)
+            } else {
+                type_annotation
+            };
+            let type_annotation =
+                non_null_if_required(type_annotation, linked_field.associated_data.required);
+
+            // In the variant for a concrete type, the refinement pointer to that type is
+            // non-null, and the refinement pointers to other types are null.
+            let output_type = match concrete_type.zip(refinement_pointer_concrete_type) {
+                Some((concrete_type, refined_type)) if concrete_type == refined_type => {
+                    let mut type_annotation = type_annotation;
+                    if let TypeAnnotation::Union(union_type_annotation) = &mut type_annotation {
+                        union_type_annotation.nullable = false;
+                    }
+                    print_javascript_type_declaration(&type_annotation)
+                }
+                Some(_) => "null".to_string(),
+                None => print_javascript_type_declaration(&type_annotation),
+            };
 
             query_type_declaration.push_str(&format!(
-                "readonly {}: {},\n",
+                "readonly {}{}: {},\n",
                 name_or_alias,
-                print_javascript_type_declaration(&type_annotation),
+                optional_marker(&linked_field.associated_data.conditions),
+                output_type,
             ));
         }
     }
 }
 
+/// Fields selected with @required are never null when read. Semantically non-null
+/// fields are covered too, since validation treats them as selected with
+/// @required(action: THROW).
+fn non_null_if_required<T: Ord + Debug>(
+    mut type_annotation: TypeAnnotation<T>,
+    required: Option<RequiredAction>,
+) -> TypeAnnotation<T> {
+    if required.is_some() {
+        if let TypeAnnotation::Union(union_type_annotation) = &mut type_annotation {
+            union_type_annotation.nullable = false;
+        }
+    }
+    type_annotation
+}
+
+/// Client-only fields are null until they are written to, even if their type in
+/// the schema is non-null.
+fn nullable_if_client_only<T: Ord + Debug>(
+    type_annotation: TypeAnnotation<T>,
+    is_client_only: bool,
+) -> TypeAnnotation<T> {
+    if !is_client_only {
+        return type_annotation;
+    }
+    match type_annotation {
+        TypeAnnotation::Scalar(scalar) => TypeAnnotation::Union(UnionTypeAnnotation {
+            variants: BTreeSet::from([UnionVariant::Scalar(scalar)]),
+            nullable: true,
+        }),
+        TypeAnnotation::Union(mut union_type_annotation) => {
+            union_type_annotation.nullable = true;
+            TypeAnnotation::Union(union_type_annotation)
+        }
+        TypeAnnotation::Plural(type_annotation) => TypeAnnotation::Union(UnionTypeAnnotation {
+            variants: BTreeSet::from([UnionVariant::Plural(*type_annotation)]),
+            nullable: true,
+        }),
+    }
+}
+
+/// Whether a null field selected with @required(action: LOG) or @required(action: NONE)
+/// makes the object containing it null when read.
+pub(crate) fn selection_set_nulls_parent(selection_set: &[WithSpan<ValidatedSelection>]) -> bool {
+    selection_set.iter().any(|selection| {
+        let required = match &selection.item {
+            ServerFieldSelection::ScalarField(scalar_field_selection) => {
+                scalar_field_selection.associated_data.required
+            }
+            ServerFieldSelection::LinkedField(linked_field_selection) => {
+                linked_field_selection.associated_data.required
+            }
+        };
+        matches!(required, Some(RequiredAction::Log | RequiredAction::Silent))
+    })
+}
+
+/// Make the objects in a type annotation nullable, i.e. the items of a list, or the
+/// type itself.
+fn object_nullable<T: Ord + Debug>(type_annotation: TypeAnnotation<T>) -> TypeAnnotation<T> {
+    match type_annotation {
+        TypeAnnotation::Scalar(scalar) => TypeAnnotation::Union(UnionTypeAnnotation {
+            variants: BTreeSet::from([UnionVariant::Scalar(scalar)]),
+            nullable: true,
+        }),
+        TypeAnnotation::Union(union_type_annotation) => {
+            let mut nullable = union_type_annotation.nullable;
+            let variants = union_type_annotation
+                .variants
+                .into_iter()
+                .map(|variant| match variant {
+                    UnionVariant::Scalar(scalar) => {
+                        nullable = true;
+                        UnionVariant::Scalar(scalar)
+                    }
+                    UnionVariant::Plural(type_annotation) => {
+                        UnionVariant::Plural(object_nullable(type_annotation))
+                    }
+                })
+                .collect();
+            TypeAnnotation::Union(UnionTypeAnnotation { variants, nullable })
+        }
+        TypeAnnotation::Plural(type_annotation) => {
+            TypeAnnotation::Plural(Box::new(object_nullable(*type_annotation)))
+        }
+    }
+}
+
+/// Fields with @skip or @include are absent if they are not selected.
+fn optional_marker(conditions: &[SelectionCondition]) -> &'static str {
+    if conditions.is_empty() {
+        ""
+    } else {
+        "?"
+    }
+}
+
 fn get_loadable_field_type_from_arguments(
     schema: &ValidatedSchema,
     arguments: Vec<ValidatedVariableDefinition>,
@@ -702,16 +1038,31 @@ pub(crate) fn generate_parameters<'a>(
 
 fn write_optional_description(
     description: Option<DescriptionValue>,
+    deprecation_reason: Option<StringLiteralValue>,
     query_type_declaration: &mut String,
     indentation_level: u8,
 ) {
-    if let Some(description) = description {
-        query_type_declaration.push_str(&"  ".repeat(indentation_level as usize).to_string());
-        query_type_declaration.push_str("/**\n");
-        query_type_declaration.push_str(description.lookup());
-        query_type_declaration.push('\n');
-        query_type_declaration.push_str(&"  ".repeat(indentation_level as usize).to_string());
-        query_type_declaration.push_str("*/\n");
+    match (description, deprecation_reason) {
+        (Some(description), deprecation_reason) => {
+            query_type_declaration.push_str(&"  ".repeat(indentation_level as usize).to_string());
+            query_type_declaration.push_str("/**\n");
+            query_type_declaration.push_str(description.lookup());
+            query_type_declaration.push('\n');
+            if let Some(deprecation_reason) = deprecation_reason {
+                query_type_declaration.push_str(&format!("@deprecated {}\n", deprecation_reason));
+            }
+            query_type_declaration.push_str(&"  ".repeat(indentation_level as usize).to_string());
+            query_type_declaration.push_str("*/\n");
+        }
+        (None, Some(deprecation_reason)) => {
+            // A single-line JSDoc comment, so that editors strike through the field
+            query_type_declaration.push_str(&format!(
+                "{}/** @deprecated {} */\n",
+                "  ".repeat(indentation_level as usize),
+                deprecation_reason
+            ));
+        }
+        (None, None) => {}
     }
 }
 
@@ -1,7 +1,9 @@
 use common_lang_types::{ArtifactPathAndContent, IsographObjectTypeName, SelectableFieldName};
 use intern::string_key::Intern;
 use isograph_lang_types::RefetchQueryIndex;
-use isograph_schema::{ImperativelyLoadedFieldArtifactInfo, ValidatedSchema, REFETCH_FIELD_NAME};
+use isograph_schema::{
+    assign_unique_labels, ImperativelyLoadedFieldArtifactInfo, ValidatedSchema, REFETCH_FIELD_NAME,
+};
 
 use crate::{
     generate_artifacts::{generate_path, NormalizationAstText, QueryText},
@@ -82,7 +84,7 @@ pub(crate) fn get_artifact_for_imperatively_loaded_field(
     imperatively_loaded_field_artifact_info: ImperativelyLoadedFieldArtifactInfo,
 ) -> ArtifactPathAndContent {
     let ImperativelyLoadedFieldArtifactInfo {
-        merged_selection_set,
+        mut merged_selection_set,
         root_fetchable_field,
         root_parent_object,
         refetch_query_index,
@@ -92,6 +94,7 @@ pub(crate) fn get_artifact_for_imperatively_loaded_field(
         concrete_type,
     } = imperatively_loaded_field_artifact_info;
 
+    assign_unique_labels(&mut merged_selection_set);
     let query_text = generate_query_text(
         query_name,
         schema,
@@ -9,5 +9,6 @@ mod normalization_ast_text;
 mod query_text;
 mod reader_ast;
 mod refetch_reader_artifact;
+mod updater_artifact;
 
 pub use generate_artifacts::get_artifact_path_and_content;
@@ -1,9 +1,15 @@
+use std::collections::BTreeSet;
+
+use intern::Lookup;
 use isograph_schema::{
-    MergedInlineFragmentSelection, MergedLinkedFieldSelection, MergedScalarFieldSelection,
-    MergedServerSelection, ValidatedSchema,
+    MergedDeferredFragmentSelection, MergedInlineFragmentSelection, MergedLinkedFieldSelection,
+    MergedScalarFieldSelection, MergedSelectionMap, MergedServerSelection, NormalizationKey,
+    ValidatedSchema,
 };
 
-use crate::generate_artifacts::{get_serialized_field_arguments, NormalizationAstText};
+use crate::generate_artifacts::{
+    get_serialized_field_arguments, get_serialized_selection_conditions, NormalizationAstText,
+};
 
 pub(crate) fn generate_normalization_ast_text<'schema, 'a>(
     schema: &'schema ValidatedSchema,
@@ -27,12 +33,15 @@ fn generate_normalization_ast_node(
     match &item {
         MergedServerSelection::ScalarField(scalar_field) => {
             let MergedScalarFieldSelection {
-                name, arguments, ..
+                name,
+                arguments,
+                conditions,
             } = scalar_field;
             let indent = "  ".repeat(indentation_level as usize);
             let indent_2 = "  ".repeat((indentation_level + 1) as usize);
             let serialized_arguments =
                 get_serialized_field_arguments(arguments, indentation_level + 1);
+            let conditions = get_serialized_selection_conditions(conditions, indentation_level + 1);
             // TODO this is bad, name is a WithLocation and impl's Display, we should fix
 
             format!(
@@ -40,6 +49,7 @@ fn generate_normalization_ast_node(
                 {indent_2}kind: \"Scalar\",\n\
                 {indent_2}fieldName: \"{name}\",\n\
                 {indent_2}arguments: {serialized_arguments},\n\
+                {conditions}\
                 {indent}}},\n"
             )
         }
@@ -48,6 +58,8 @@ fn generate_normalization_ast_node(
                 name,
                 selection_map,
                 arguments,
+                conditions,
+                stream,
                 ..
             } = linked_field;
 
@@ -66,6 +78,19 @@ fn generate_normalization_ast_node(
                 selection_map.values(),
                 indentation_level + 1,
             );
+            let strong_id_field = get_strong_id_field(selection_map)
+                .map(|strong_id_field| format!("{indent_2}strongIdField: {strong_id_field},\n"))
+                .unwrap_or_default();
+            let conditions = get_serialized_selection_conditions(conditions, indentation_level + 1);
+            let stream = stream
+                .as_ref()
+                .map(|stream| {
+                    format!(
+                        "{indent_2}stream: {{ label: \"{}\", initialCount: {} }},\n",
+                        stream.label, stream.initial_count
+                    )
+                })
+                .unwrap_or_default();
 
             format!(
                 "{indent}{{\n\
@@ -73,6 +98,9 @@ fn generate_normalization_ast_node(
                 {indent_2}fieldName: \"{name}\",\n\
                 {indent_2}arguments: {serialized_arguments},\n\
                 {indent_2}concreteType: {concrete_type},\n\
+                {strong_id_field}\
+                {conditions}\
+                {stream}\
                 {indent_2}selections: {selections},\n\
                 {indent}}},\n"
             )
@@ -99,5 +127,76 @@ fn generate_normalization_ast_node(
                 {indent}}},\n"
             )
         }
+        MergedServerSelection::DeferredFragment(deferred_fragment) => {
+            let MergedDeferredFragmentSelection {
+                label,
+                selection_map,
+            } = deferred_fragment;
+            let indent = "  ".repeat(indentation_level as usize);
+            let indent_2 = "  ".repeat((indentation_level + 1) as usize);
+
+            let selections = generate_normalization_ast_text(
+                schema,
+                selection_map.values(),
+                indentation_level + 1,
+            );
+
+            format!(
+                "{indent}{{\n\
+                {indent_2}kind: \"Defer\",\n\
+                {indent_2}label: \"{label}\",\n\
+                {indent_2}selections: {selections},\n\
+                {indent}}},\n"
+            )
+        }
+    }
+}
+
+/// The runtime normalizes objects using their strong id field. We only tell it the name
+/// of that field if it is not "id", or if there is no strong id field but a field named
+/// "id" is selected anyway. For abstract fields, the strong id field is selected in
+/// the inline fragments, and is used if all concrete types agree on its name.
+fn get_strong_id_field(selection_map: &MergedSelectionMap) -> Option<String> {
+    let selection_maps = std::iter::once(selection_map)
+        .chain(
+            selection_map
+                .values()
+                .filter_map(|selection| match selection {
+                    MergedServerSelection::InlineFragment(inline_fragment) => {
+                        Some(&inline_fragment.selection_map)
+                    }
+                    _ => None,
+                }),
+        )
+        .collect::<Vec<_>>();
+
+    let strong_id_field_names = selection_maps
+        .iter()
+        .filter_map(
+            |selection_map| match selection_map.get(&NormalizationKey::Id) {
+                Some(MergedServerSelection::ScalarField(id_field)) => Some(id_field.name),
+                _ => None,
+            },
+        )
+        .collect::<BTreeSet<_>>();
+
+    match strong_id_field_names
+        .into_iter()
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [] => {
+            let selects_non_strong_id_field = selection_maps.iter().any(|selection_map| {
+                selection_map.keys().any(|key| {
+                    matches!(key, NormalizationKey::ServerField(name_and_arguments)
+                        if name_and_arguments.name.lookup() == "id")
+                })
+            });
+            selects_non_strong_id_field.then(|| "null".to_string())
+        }
+        [strong_id_field_name] if strong_id_field_name.lookup() != "id" => {
+            Some(format!("\"{strong_id_field_name}\""))
+        }
+        _ => None,
     }
 }
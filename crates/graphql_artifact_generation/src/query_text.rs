@@ -1,6 +1,6 @@
 use common_lang_types::{QueryOperationName, UnvalidatedTypeName};
 use graphql_lang_types::GraphQLTypeAnnotation;
-use isograph_lang_types::{ArgumentKeyAndValue, NonConstantValue};
+use isograph_lang_types::{ArgumentKeyAndValue, NonConstantValue, SelectionCondition};
 use isograph_schema::{
     get_name, MergedSelectionMap, MergedServerSelection, RootOperationName, ValidatedSchema,
     ValidatedVariableDefinition,
@@ -83,7 +83,8 @@ fn write_selections_for_query_text<'a>(
                 }
                 let name = scalar_field.name;
                 let arguments = get_serialized_arguments_for_query_text(&scalar_field.arguments);
-                query_text.push_str(&format!("{}{},\\\n", name, arguments));
+                let conditions = get_serialized_conditions_for_query_text(&scalar_field.conditions);
+                query_text.push_str(&format!("{}{}{},\\\n", name, arguments, conditions));
             }
             MergedServerSelection::LinkedField(linked_field) => {
                 query_text.push_str(&"  ".repeat(indentation_level as usize).to_string());
@@ -93,7 +94,21 @@ fn write_selections_for_query_text<'a>(
                 }
                 let name = linked_field.name;
                 let arguments = get_serialized_arguments_for_query_text(&linked_field.arguments);
-                query_text.push_str(&format!("{}{} {{\\\n", name, arguments));
+                let conditions = get_serialized_conditions_for_query_text(&linked_field.conditions);
+                let stream = linked_field
+                    .stream
+                    .as_ref()
+                    .map(|stream| {
+                        format!(
+                            " @stream(label: \"{}\", initialCount: {})",
+                            stream.label, stream.initial_count
+                        )
+                    })
+                    .unwrap_or_default();
+                query_text.push_str(&format!(
+                    "{}{}{}{} {{\\\n",
+                    name, arguments, conditions, stream
+                ));
                 write_selections_for_query_text(
                     query_text,
                     linked_field.selection_map.values(),
@@ -118,6 +133,20 @@ fn write_selections_for_query_text<'a>(
                 query_text.push_str(&"  ".repeat(indentation_level as usize).to_string());
                 query_text.push_str("},\\\n")
             }
+            MergedServerSelection::DeferredFragment(deferred_fragment) => {
+                query_text.push_str(&"  ".repeat(indentation_level as usize).to_string());
+                query_text.push_str(&format!(
+                    "... @defer(label: \"{}\") {{\\\n",
+                    deferred_fragment.label
+                ));
+                write_selections_for_query_text(
+                    query_text,
+                    deferred_fragment.selection_map.values(),
+                    indentation_level + 1,
+                );
+                query_text.push_str(&"  ".repeat(indentation_level as usize).to_string());
+                query_text.push_str("},\\\n")
+            }
         }
     }
 }
@@ -145,6 +174,19 @@ fn get_serialized_arguments_for_query_text(arguments: &[ArgumentKeyAndValue]) ->
     }
 }
 
+fn get_serialized_conditions_for_query_text(conditions: &[SelectionCondition]) -> String {
+    conditions
+        .iter()
+        .map(|condition| {
+            format!(
+                " @{}(if: {})",
+                condition.kind.directive_name(),
+                serialize_non_constant_value_for_graphql(&condition.value)
+            )
+        })
+        .collect()
+}
+
 fn serialize_non_constant_value_for_graphql(value: &NonConstantValue) -> String {
     match value {
         NonConstantValue::Variable(variable_name) => format!("${}", variable_name),
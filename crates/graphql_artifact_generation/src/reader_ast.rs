@@ -2,22 +2,28 @@ use std::collections::{BTreeSet, HashSet};
 
 use common_lang_types::{SelectableFieldName, WithSpan};
 use isograph_lang_types::{
-    LoadableDirectiveParameters, RefetchQueryIndex, SelectionType, ServerFieldSelection,
+    LoadableDirectiveParameters, RefetchQueryIndex, RequiredAction, SelectionType,
+    ServerFieldSelection,
 };
 use isograph_schema::{
-    categorize_field_loadability, transform_arguments_with_child_context, FieldType, Loadability,
-    NameAndArguments, NormalizationKey, ObjectTypeAndFieldName, PathToRefetchField,
+    categorize_field_loadability, transform_arguments_with_child_context,
+    transform_conditions_with_child_context, ClientType, FieldType, Loadability, NameAndArguments,
+    NormalizationKey, ObjectTypeAndFieldName, PaginationDirection, PathToRefetchField,
     RefetchedPathsMap, SchemaServerFieldVariant, ValidatedClientField,
     ValidatedIsographSelectionVariant, ValidatedLinkedFieldSelection,
     ValidatedScalarFieldSelection, ValidatedSchema, ValidatedSelection, VariableContext,
+    REFETCH_FIELD_NAME,
 };
 
 use crate::{
-    generate_artifacts::{get_serialized_field_arguments, ReaderAst},
+    generate_artifacts::{
+        get_serialized_field_arguments, get_serialized_selection_conditions, ReaderAst,
+    },
     import_statements::{ImportedFileCategory, ReaderImports},
 };
 
 // Can we do this when visiting the client field in when generating entrypoints?
+#[allow(clippy::too_many_arguments)]
 fn generate_reader_ast_node(
     selection: &WithSpan<ValidatedSelection>,
     schema: &ValidatedSchema,
@@ -27,15 +33,21 @@ fn generate_reader_ast_node(
     root_refetched_paths: &RefetchedPathsMap,
     path: &mut Vec<NormalizationKey>,
     initial_variable_context: &VariableContext,
+    // The reader AST of an updater artifact, which marks the fields that can be
+    // written to, and which only reads server fields.
+    is_updatable: bool,
 ) -> String {
     match &selection.item {
         ServerFieldSelection::ScalarField(scalar_field_selection) => {
             match scalar_field_selection.associated_data.location {
-                FieldType::ServerField(_) => server_defined_scalar_field_ast_node(
+                FieldType::ServerField(server_field_id) => server_defined_scalar_field_ast_node(
                     scalar_field_selection,
+                    schema.server_field(server_field_id).is_client_only,
                     indentation_level,
                     initial_variable_context,
+                    is_updatable,
                 ),
+                FieldType::ClientField(_) if is_updatable => String::new(),
                 FieldType::ClientField(client_field_id) => {
                     let client_field = schema.client_field(client_field_id);
                     scalar_client_defined_field_ast_node(
@@ -51,24 +63,18 @@ fn generate_reader_ast_node(
                 }
             }
         }
+        ServerFieldSelection::LinkedField(linked_field_selection)
+            if is_updatable && is_refinement_pointer(schema, linked_field_selection) =>
+        {
+            String::new()
+        }
         ServerFieldSelection::LinkedField(linked_field_selection) => {
-            path.push(
-                NameAndArguments {
-                    // TODO use alias
-                    name: linked_field_selection.name.item.into(),
-                    // TODO this clearly does something, but why are we able to pass
-                    // the initial variable context here??
-                    arguments: transform_arguments_with_child_context(
-                        linked_field_selection
-                            .arguments
-                            .iter()
-                            .map(|x| x.item.into_key_and_value()),
-                        // TODO why is this not the transformed context?
-                        initial_variable_context,
-                    ),
-                }
-                .normalization_key(),
-            );
+            path.push(linked_field_normalization_key(
+                schema,
+                linked_field_selection,
+                // TODO why is this not the transformed context?
+                initial_variable_context,
+            ));
 
             let inner_reader_ast = generate_reader_ast_with_path(
                 schema,
@@ -78,8 +84,25 @@ fn generate_reader_ast_node(
                 root_refetched_paths,
                 path,
                 initial_variable_context,
+                is_updatable,
             );
 
+            let pagination = match linked_field_selection.associated_data.selection_variant {
+                ValidatedIsographSelectionVariant::Paginated(direction) if !is_updatable => {
+                    Some(paginated_field_pagination_text(
+                        schema,
+                        linked_field_selection,
+                        direction,
+                        root_refetched_paths,
+                        path,
+                        indentation_level + 1,
+                        reader_imports,
+                        initial_variable_context,
+                    ))
+                }
+                _ => None,
+            };
+
             path.pop();
 
             linked_field_ast_node(
@@ -89,11 +112,31 @@ fn generate_reader_ast_node(
                 inner_reader_ast,
                 initial_variable_context,
                 reader_imports,
+                pagination,
             )
         }
     }
 }
 
+/// Whether the linked field is a refinement pointer, e.g. asUser.
+fn is_refinement_pointer(
+    schema: &ValidatedSchema,
+    linked_field: &ValidatedLinkedFieldSelection,
+) -> bool {
+    match linked_field.associated_data.field_id {
+        FieldType::ServerField(server_field_id) => {
+            match &schema.server_field(server_field_id).associated_data {
+                SelectionType::Object(associated_data) => matches!(
+                    associated_data.variant,
+                    SchemaServerFieldVariant::InlineFragment(_)
+                ),
+                SelectionType::Scalar(_) => false,
+            }
+        }
+        FieldType::ClientField(_) => false,
+    }
+}
+
 fn linked_field_ast_node(
     schema: &ValidatedSchema,
     linked_field: &ValidatedLinkedFieldSelection,
@@ -101,6 +144,7 @@ fn linked_field_ast_node(
     inner_reader_ast: ReaderAst,
     initial_variable_context: &VariableContext,
     reader_imports: &mut ReaderImports,
+    pagination: Option<String>,
 ) -> String {
     let name = linked_field.name.item;
     let alias = linked_field
@@ -121,10 +165,11 @@ fn linked_field_ast_node(
     let indent_1 = "  ".repeat(indentation_level as usize);
     let indent_2 = "  ".repeat((indentation_level + 1) as usize);
 
-    let condition = match linked_field.associated_data.field_id {
+    let (condition, is_client_only) = match linked_field.associated_data.field_id {
         FieldType::ClientField(_) => todo!(),
         FieldType::ServerField(server_field_id) => {
-            match &schema.server_field(server_field_id).associated_data {
+            let server_field = schema.server_field(server_field_id);
+            let condition = match &server_field.associated_data {
                 SelectionType::Scalar(_) => panic!("Expected object"),
                 SelectionType::Object(associated_data) => match &associated_data.variant {
                     SchemaServerFieldVariant::InlineFragment(inline_fragment) => {
@@ -148,9 +193,21 @@ fn linked_field_ast_node(
                     }
                     SchemaServerFieldVariant::LinkedField => "null".to_string(),
                 },
-            }
+            };
+            (condition, server_field.is_client_only)
         }
     };
+    let is_client_only = get_serialized_is_client_only(is_client_only, indentation_level + 1);
+    let required =
+        get_serialized_required(linked_field.associated_data.required, indentation_level + 1);
+
+    let pagination = pagination
+        .map(|pagination| format!("{indent_2}pagination: {pagination},\n"))
+        .unwrap_or_default();
+    let conditions = get_serialized_selection_conditions(
+        &linked_field.associated_data.conditions,
+        indentation_level + 1,
+    );
 
     format!(
         "{indent_1}{{\n\
@@ -160,10 +217,121 @@ fn linked_field_ast_node(
         {indent_2}arguments: {arguments},\n\
         {indent_2}condition: {condition},\n\
         {indent_2}selections: {inner_reader_ast},\n\
+        {pagination}\
+        {is_client_only}\
+        {required}\
+        {conditions}\
         {indent_1}}},\n",
     )
 }
 
+/// What the runtime needs in order to fetch the next page of a connection selected
+/// with @paginated: the refetch query, how to read the variables that are passed to
+/// the parent's refetch field, and which arguments and fields hold the count and cursor.
+#[allow(clippy::too_many_arguments)]
+fn paginated_field_pagination_text(
+    schema: &ValidatedSchema,
+    linked_field: &ValidatedLinkedFieldSelection,
+    direction: PaginationDirection,
+    root_refetched_paths: &RefetchedPathsMap,
+    path: &[NormalizationKey],
+    indentation_level: u8,
+    reader_imports: &mut ReaderImports,
+    initial_variable_context: &VariableContext,
+) -> String {
+    let indent_1 = "  ".repeat(indentation_level as usize);
+    let indent_2 = "  ".repeat((indentation_level + 1) as usize);
+    let indent_3 = "  ".repeat((indentation_level + 2) as usize);
+
+    let refetch_query_index = find_imperatively_fetchable_query_index(
+        root_refetched_paths,
+        path,
+        linked_field.name.item.into(),
+    )
+    .0;
+
+    let parent_object_id = match linked_field.associated_data.field_id {
+        FieldType::ServerField(server_field_id) => {
+            schema.server_field(server_field_id).parent_type_id
+        }
+        FieldType::ClientField(_) => panic!(
+            "Expected paginated field to be a server field. \
+            This is indicative of a bug in Isograph."
+        ),
+    };
+
+    let refetch_selection_set = match schema
+        .server_field_data
+        .object(parent_object_id)
+        .encountered_fields
+        .get(&(*REFETCH_FIELD_NAME).into())
+    {
+        Some(FieldType::ClientField(ClientType::ClientField(refetch_field_id))) => schema
+            .client_field(*refetch_field_id)
+            .refetch_strategy
+            .as_ref()
+            .map(|refetch_strategy| refetch_strategy.refetch_selection_set()),
+        _ => None,
+    };
+    let refetch_reader_ast = match refetch_selection_set {
+        Some(refetch_selection_set) => {
+            let (reader_ast, additional_reader_imports) = generate_reader_ast(
+                schema,
+                refetch_selection_set,
+                indentation_level + 1,
+                &Default::default(),
+                initial_variable_context,
+            );
+            for import in additional_reader_imports {
+                reader_imports.insert(import);
+            }
+            reader_ast.0
+        }
+        None => "[]".to_string(),
+    };
+
+    let refetch_field_map = schema
+        .refetch_field_map(parent_object_id)
+        .iter()
+        .map(|item| {
+            format!(
+                "{indent_3}{{ from: \"{}\", to: \"{}\" }},\n",
+                item.from, item.to,
+            )
+        })
+        .collect::<String>();
+
+    let page_arguments = get_serialized_field_arguments(
+        &direction.page_arguments(transform_arguments_with_child_context(
+            linked_field
+                .arguments
+                .iter()
+                .map(|x| x.item.into_key_and_value()),
+            initial_variable_context,
+        )),
+        indentation_level + 1,
+    );
+
+    let count_variable = direction.count_variable();
+    let cursor_variable = direction.cursor_variable();
+    let has_more_field = direction.has_more_field();
+    let page_cursor_field = direction.page_cursor_field();
+
+    format!(
+        "{{\n\
+        {indent_2}refetchQuery: {refetch_query_index},\n\
+        {indent_2}direction: \"{direction:?}\",\n\
+        {indent_2}countVariable: \"{count_variable}\",\n\
+        {indent_2}cursorVariable: \"{cursor_variable}\",\n\
+        {indent_2}hasMoreField: \"{has_more_field}\",\n\
+        {indent_2}pageCursorField: \"{page_cursor_field}\",\n\
+        {indent_2}pageArguments: {page_arguments},\n\
+        {indent_2}refetchReaderAst: {refetch_reader_ast},\n\
+        {indent_2}refetchFieldMap: [\n{refetch_field_map}{indent_2}],\n\
+        {indent_1}}}"
+    )
+}
+
 #[allow(clippy::too_many_arguments)]
 fn scalar_client_defined_field_ast_node(
     scalar_field_selection: &ValidatedScalarFieldSelection,
@@ -271,6 +439,10 @@ fn user_written_variant_ast_node(
         nested_client_field.type_and_field,
         ImportedFileCategory::ResolverReader,
     ));
+    let conditions = get_serialized_selection_conditions(
+        &scalar_field_selection.associated_data.conditions,
+        indentation_level + 1,
+    );
 
     format!(
         "{indent_1}{{\n\
@@ -279,6 +451,7 @@ fn user_written_variant_ast_node(
         {indent_2}arguments: {arguments},\n\
         {indent_2}readerArtifact: {reader_artifact_import_name},\n\
         {indent_2}usedRefetchQueries: {nested_refetch_queries},\n\
+        {conditions}\
         {indent_1}}},\n",
     )
 }
@@ -317,6 +490,10 @@ fn imperatively_loaded_variant_ast_node(
     // Note that scalar_field_selection.arguments includes an id argument, which
     // may or may not be what we want here.
     let name = scalar_field_selection.name.item;
+    let conditions = get_serialized_selection_conditions(
+        &scalar_field_selection.associated_data.conditions,
+        indentation_level + 1,
+    );
 
     format!(
         "{indent_1}{{\n\
@@ -325,6 +502,7 @@ fn imperatively_loaded_variant_ast_node(
         {indent_2}refetchReaderArtifact: {refetch_reader_artifact_import_name},\n\
         {indent_2}refetchQuery: {refetch_query_index},\n\
         {indent_2}name: \"{name}\",\n\
+        {conditions}\
         {indent_1}}},\n",
     )
 }
@@ -342,6 +520,7 @@ fn loadably_selected_field_ast_node(
     let alias = scalar_field_selection.name_or_alias().item;
     let indent_1 = "  ".repeat(indentation_level as usize);
     let indent_2 = "  ".repeat((indentation_level + 1) as usize);
+    let indent_3 = "  ".repeat((indentation_level + 2) as usize);
 
     let type_and_field = client_field.type_and_field.underscore_separated();
     let entrypoint_text = if !loadable_directive_parameters.lazy_load_artifact {
@@ -351,7 +530,6 @@ fn loadably_selected_field_ast_node(
         ));
         format!("{type_and_field}__entrypoint")
     } else {
-        let indent_3 = "  ".repeat((indentation_level + 2) as usize);
         let field_parent_type = client_field.type_and_field.type_name;
         format!(
             "{{ \n\
@@ -395,6 +573,21 @@ fn loadably_selected_field_ast_node(
         reader_imports.insert(import);
     }
 
+    let refetch_field_map = schema
+        .refetch_field_map(client_field.parent_object_id)
+        .iter()
+        .map(|item| {
+            format!(
+                "{indent_3}{{ from: \"{}\", to: \"{}\" }},\n",
+                item.from, item.to,
+            )
+        })
+        .collect::<String>();
+    let conditions = get_serialized_selection_conditions(
+        &scalar_field_selection.associated_data.conditions,
+        indentation_level + 1,
+    );
+
     format!(
         "{indent_1}{{\n\
         {indent_2}kind: \"LoadablySelectedField\",\n\
@@ -402,15 +595,19 @@ fn loadably_selected_field_ast_node(
         {indent_2}name: \"{name}\",\n\
         {indent_2}queryArguments: {arguments},\n\
         {indent_2}refetchReaderAst: {reader_ast},\n\
+        {indent_2}refetchFieldMap: [\n{refetch_field_map}{indent_2}],\n\
         {indent_2}entrypoint: {entrypoint_text},\n\
+        {conditions}\
         {indent_1}}},\n"
     )
 }
 
 fn server_defined_scalar_field_ast_node(
     scalar_field_selection: &ValidatedScalarFieldSelection,
+    is_client_only: bool,
     indentation_level: u8,
     initial_variable_context: &VariableContext,
+    is_updatable: bool,
 ) -> String {
     let field_name = scalar_field_selection.name.item;
     let alias = scalar_field_selection
@@ -428,8 +625,23 @@ fn server_defined_scalar_field_ast_node(
         indentation_level + 1,
     );
 
+    let conditions = get_serialized_selection_conditions(
+        &scalar_field_selection.associated_data.conditions,
+        indentation_level + 1,
+    );
+
     let indent_1 = "  ".repeat(indentation_level as usize);
     let indent_2 = "  ".repeat((indentation_level + 1) as usize);
+    let is_client_only = get_serialized_is_client_only(is_client_only, indentation_level + 1);
+    let is_updatable = if is_updatable && scalar_field_selection.associated_data.is_updatable {
+        format!("{indent_2}isUpdatable: true,\n")
+    } else {
+        String::new()
+    };
+    let required = get_serialized_required(
+        scalar_field_selection.associated_data.required,
+        indentation_level + 1,
+    );
 
     format!(
         "{indent_1}{{\n\
@@ -437,10 +649,38 @@ fn server_defined_scalar_field_ast_node(
         {indent_2}fieldName: \"{field_name}\",\n\
         {indent_2}alias: {alias},\n\
         {indent_2}arguments: {arguments},\n\
+        {is_client_only}\
+        {is_updatable}\
+        {required}\
+        {conditions}\
         {indent_1}}},\n",
     )
 }
 
+/// Client-only fields are never fetched, so the runtime must not treat a
+/// missing value as missing data.
+fn get_serialized_is_client_only(is_client_only: bool, indentation_level: u8) -> String {
+    if is_client_only {
+        let indent = "  ".repeat(indentation_level as usize);
+        format!("{indent}isClientOnly: true,\n")
+    } else {
+        String::new()
+    }
+}
+
+/// What the runtime does if a field selected with @required, or a semantically
+/// non-null field, is null.
+fn get_serialized_required(required: Option<RequiredAction>, indentation_level: u8) -> String {
+    match required {
+        Some(required) => {
+            let indent = "  ".repeat(indentation_level as usize);
+            format!("{indent}required: \"{required}\",\n")
+        }
+        None => String::new(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn generate_reader_ast_with_path<'schema>(
     schema: &'schema ValidatedSchema,
     selection_set: &'schema Vec<WithSpan<ValidatedSelection>>,
@@ -450,6 +690,7 @@ fn generate_reader_ast_with_path<'schema>(
     root_refetched_paths: &RefetchedPathsMap,
     path: &mut Vec<NormalizationKey>,
     initial_variable_context: &VariableContext,
+    is_updatable: bool,
 ) -> ReaderAst {
     let mut reader_ast = "[\n".to_string();
     for item in selection_set {
@@ -461,6 +702,7 @@ fn generate_reader_ast_with_path<'schema>(
             root_refetched_paths,
             path,
             initial_variable_context,
+            is_updatable,
         );
         reader_ast.push_str(&s);
     }
@@ -540,10 +782,32 @@ pub(crate) fn generate_reader_ast<'schema>(
         // (and in theory some entrypoints).
         &mut vec![],
         initial_variable_context,
+        false,
     );
     (reader_ast, client_field_imports)
 }
 
+/// The reader AST of an updater artifact. Fields selected within an @updatable
+/// selection set are marked as updatable, and client fields and refinement
+/// pointers are not read.
+pub(crate) fn generate_updater_reader_ast<'schema>(
+    schema: &'schema ValidatedSchema,
+    selection_set: &'schema Vec<WithSpan<ValidatedSelection>>,
+    indentation_level: u8,
+    initial_variable_context: &VariableContext,
+) -> ReaderAst {
+    generate_reader_ast_with_path(
+        schema,
+        selection_set,
+        indentation_level,
+        &mut BTreeSet::new(),
+        &Default::default(),
+        &mut vec![],
+        initial_variable_context,
+        true,
+    )
+}
+
 fn refetched_paths_for_client_field(
     nested_client_field: &ValidatedClientField,
     schema: &ValidatedSchema,
@@ -608,29 +872,27 @@ fn refetched_paths_with_path(
                                     ),
                                 );
 
-                                paths.extend(new_paths.into_iter());
+                                paths.extend(new_paths);
                             }
                         }
                     }
                 }
             }
             ServerFieldSelection::LinkedField(linked_field_selection) => {
-                path.push(
-                    NameAndArguments {
-                        // TODO use alias
-                        name: linked_field_selection.name.item.into(),
-                        arguments: transform_arguments_with_child_context(
-                            linked_field_selection
-                                .arguments
-                                .iter()
-                                .map(|x| x.item.into_key_and_value()),
-                            // TODO this clearly does something, but why are we able to pass
-                            // the initial variable context here??
-                            initial_variable_context,
-                        ),
-                    }
-                    .normalization_key(),
-                );
+                path.push(linked_field_normalization_key(
+                    schema,
+                    linked_field_selection,
+                    initial_variable_context,
+                ));
+
+                if let ValidatedIsographSelectionVariant::Paginated(_) =
+                    linked_field_selection.associated_data.selection_variant
+                {
+                    paths.insert(PathToRefetchField {
+                        linked_fields: path.clone(),
+                        field_name: linked_field_selection.name.item.into(),
+                    });
+                }
 
                 let new_paths = refetched_paths_with_path(
                     &linked_field_selection.selection_set,
@@ -639,7 +901,7 @@ fn refetched_paths_with_path(
                     initial_variable_context,
                 );
 
-                paths.extend(new_paths.into_iter());
+                paths.extend(new_paths);
 
                 path.pop();
             }
@@ -648,3 +910,34 @@ fn refetched_paths_with_path(
 
     paths
 }
+
+fn linked_field_normalization_key(
+    schema: &ValidatedSchema,
+    linked_field_selection: &ValidatedLinkedFieldSelection,
+    variable_context: &VariableContext,
+) -> NormalizationKey {
+    NameAndArguments {
+        // TODO use alias
+        name: linked_field_selection.name.item.into(),
+        // TODO this clearly does something, but why are we able to pass
+        // the initial variable context here??
+        arguments: transform_arguments_with_child_context(
+            linked_field_selection
+                .arguments
+                .iter()
+                .map(|x| x.item.into_key_and_value()),
+            variable_context,
+        ),
+    }
+    .normalization_key(
+        schema.strong_id_field_name(linked_field_selection.associated_data.parent_object_id),
+    )
+    .with_conditions(&transform_conditions_with_child_context(
+        linked_field_selection
+            .associated_data
+            .conditions
+            .iter()
+            .cloned(),
+        variable_context,
+    ))
+}
@@ -1,5 +1,4 @@
 use common_lang_types::ArtifactPathAndContent;
-use intern::string_key::Intern;
 
 use isograph_config::GenerateFileExtensionsOption;
 use isograph_schema::{
@@ -27,7 +26,10 @@ pub(crate) fn generate_refetch_reader_artifact(
         Some(info) => {
             generate_function_import_statement_for_mutation_reader(&info.primary_field_field_map)
         }
-        None => generate_function_import_statement_for_refetch_reader(),
+        None => {
+            let field_map = schema.refetch_field_map(client_field.parent_object_id);
+            generate_function_import_statement_for_refetch_reader(&field_map)
+        }
     };
     let parent_type = schema
         .server_field_data
@@ -111,11 +113,10 @@ pub(crate) fn generate_refetch_output_type_artifact(
     }
 }
 
-fn generate_function_import_statement_for_refetch_reader() -> ClientFieldFunctionImportStatement {
-    let include_read_out_data = get_read_out_data(&[FieldMapItem {
-        from: "id".intern().into(),
-        to: "id".intern().into(),
-    }]);
+fn generate_function_import_statement_for_refetch_reader(
+    field_map: &[FieldMapItem],
+) -> ClientFieldFunctionImportStatement {
+    let include_read_out_data = get_read_out_data(field_map);
     let indent = "  ";
     // TODO we need to generate nested refetch queries, which may either be
     // passed from the original entrypoint or specific to the loadable field.
@@ -211,7 +212,7 @@ fn get_read_out_data(field_map: &[FieldMapItem]) -> String {
         let split_to_arg = item.split_to_arg();
         let mut path_segments = Vec::with_capacity(1 + split_to_arg.to_field_names.len());
         path_segments.push(split_to_arg.to_argument_name);
-        path_segments.extend(split_to_arg.to_field_names.into_iter());
+        path_segments.extend(split_to_arg.to_field_names);
 
         let last_index = path_segments.len() - 1;
         let mut path_so_far = "".to_string();
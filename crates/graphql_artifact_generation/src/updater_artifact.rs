@@ -0,0 +1,81 @@
+use std::collections::BTreeSet;
+
+use common_lang_types::{ArtifactPathAndContent, WithSpan};
+use isograph_lang_types::ServerFieldSelection;
+use isograph_schema::{ValidatedClientField, ValidatedSchema, ValidatedSelection};
+
+use crate::{
+    generate_artifacts::{generate_client_field_parameter_type, generate_path, UPDATER},
+    reader_ast::generate_updater_reader_ast,
+};
+
+/// If a client field selects fields within an @updatable selection set, generate an
+/// updater artifact, containing the type of the updatable data (in which the
+/// updatable fields can be written to) and the reader AST that is used to read it.
+///
+/// Only server fields are part of the updatable data. Client fields, client pointers
+/// and refinement pointers (e.g. asUser) are skipped.
+pub(crate) fn generate_updater_artifact(
+    schema: &ValidatedSchema,
+    client_field: &ValidatedClientField,
+) -> Option<ArtifactPathAndContent> {
+    let selection_set = client_field.selection_set_for_parent_query();
+    if !selection_set_is_updatable(selection_set) {
+        return None;
+    }
+
+    let parent_type = schema
+        .server_field_data
+        .object(client_field.parent_object_id);
+    let updatable_data_type = format!(
+        "{}__{}__updatable_data",
+        parent_type.name, client_field.name
+    );
+    let type_declaration = generate_client_field_parameter_type(
+        schema,
+        selection_set,
+        parent_type,
+        &mut BTreeSet::new(),
+        &mut BTreeSet::new(),
+        0,
+        false,
+        true,
+    )
+    .0;
+    let reader_ast = generate_updater_reader_ast(
+        schema,
+        selection_set,
+        0,
+        &client_field.initial_variable_context(),
+    )
+    .0;
+
+    let indent = "  ";
+    let file_content = format!(
+        "import type {{ ReaderAst, UpdaterArtifact }} from '@isograph/react';\n\n\
+        export type {updatable_data_type} = {type_declaration};\n\n\
+        const readerAst: ReaderAst<{updatable_data_type}> = {reader_ast};\n\n\
+        const artifact: UpdaterArtifact<{updatable_data_type}> = {{\n\
+        {indent}kind: \"UpdaterArtifact\",\n\
+        {indent}readerAst,\n\
+        }};\n\n\
+        export default artifact;\n",
+    );
+
+    Some(ArtifactPathAndContent {
+        relative_directory: generate_path(parent_type.name, client_field.name),
+        file_name_prefix: *UPDATER,
+        file_content,
+    })
+}
+
+fn selection_set_is_updatable(selection_set: &[WithSpan<ValidatedSelection>]) -> bool {
+    selection_set.iter().any(|selection| match &selection.item {
+        ServerFieldSelection::ScalarField(scalar_field_selection) => {
+            scalar_field_selection.associated_data.is_updatable
+        }
+        ServerFieldSelection::LinkedField(linked_field_selection) => {
+            selection_set_is_updatable(&linked_field_selection.selection_set)
+        }
+    })
+}
@@ -30,7 +30,18 @@ async fn start_compiler(compile_command: CompileCommand) {
         .unwrap_or("./isograph.config.json".into());
 
     if compile_command.watch {
-        match handle_watch_command(config_location).await {
+        let config = match create_config(config_location) {
+            Ok(config) => config,
+            Err(err) => {
+                error!(
+                    "{}\n{}",
+                    "Error when loading the config.\n".bright_red(),
+                    err
+                );
+                std::process::exit(1);
+            }
+        };
+        match handle_watch_command(config).await {
             Ok(res) => match res {
                 Ok(_) => {
                     info!("{}", "Successfully watched. Exiting.\n")
@@ -51,11 +62,21 @@ async fn start_compiler(compile_command: CompileCommand) {
 }
 
 async fn start_language_server(lsp_command: LspCommand) {
-    let config = create_config(
+    let config = match create_config(
         lsp_command
             .config
             .unwrap_or("./isograph.config.json".into()),
-    );
+    ) {
+        Ok(config) => config,
+        Err(err) => {
+            error!(
+                "{}\n{}",
+                "Error when loading the config.\n".bright_red(),
+                err
+            );
+            std::process::exit(1);
+        }
+    };
     info!("Starting language server");
     if let Err(_e) = isograph_lsp::start_language_server(config).await {
         error!(
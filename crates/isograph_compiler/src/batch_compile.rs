@@ -4,6 +4,7 @@ use crate::{with_duration::WithDuration, write_artifacts::GenerateArtifactsError
 use colored::Colorize;
 use common_lang_types::WithLocation;
 use graphql_schema_parser::SchemaParseError;
+use isograph_config::ConfigError;
 use isograph_lang_parser::IsographLiteralParseError;
 use isograph_schema::{ProcessClientFieldDeclarationError, ValidateSchemaError};
 use pretty_duration::pretty_duration;
@@ -21,7 +22,7 @@ pub struct CompilationStats {
 pub fn compile_and_print(config_location: PathBuf) -> Result<(), BatchCompileError> {
     info!("{}", "Starting to compile.".cyan());
     print_result(WithDuration::new(|| {
-        CompilerState::new(config_location).batch_compile()
+        CompilerState::new(config_location)?.batch_compile()
     }))
 }
 
@@ -58,6 +59,9 @@ pub fn print_result(
 
 #[derive(Error, Debug)]
 pub enum BatchCompileError {
+    #[error("{0}")]
+    UnableToLoadConfig(#[from] ConfigError),
+
     #[error("Unable to load schema file at path {path:?}.\nReason: {message}")]
     UnableToLoadSchema { path: PathBuf, message: String },
 
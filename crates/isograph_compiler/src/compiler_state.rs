@@ -1,13 +1,20 @@
 use std::path::PathBuf;
 
+use common_lang_types::WithLocation;
 use graphql_artifact_generation::get_artifact_path_and_content;
 use isograph_config::{
-    create_config, CompilerConfig, GenerateFileExtensionsOption, OptionalValidationLevel,
+    create_config, CompilerConfig, ConfigError, GenerateFileExtensionsOption,
+    OptionalValidationLevel,
 };
-use isograph_schema::{Schema, UnvalidatedSchema};
+use isograph_schema::{
+    ClientFieldToCompletedMergeTraversalStateMap, Schema, UnvalidatedSchema, ValidateSchemaError,
+    ValidatedSchema,
+};
+use tracing::warn;
 
 use crate::{
     batch_compile::{BatchCompileError, CompilationStats},
+    overlaid_source_files::OverlaidSourceFiles,
     source_files::SourceFiles,
     watch::SourceFileEvent,
     write_artifacts::write_artifacts_to_disk,
@@ -19,9 +26,13 @@ pub struct CompilerState {
 }
 
 impl CompilerState {
-    pub fn new(config_location: PathBuf) -> Self {
+    pub fn new(config_location: PathBuf) -> Result<Self, ConfigError> {
+        Ok(Self::from_config(create_config(config_location)?))
+    }
+
+    pub fn from_config(config: CompilerConfig) -> Self {
         Self {
-            config: create_config(config_location),
+            config,
             source_files: None,
         }
     }
@@ -130,6 +141,23 @@ impl CompilerState {
         })
     }
 
+    /// Validate the source files, without writing any artifacts. This is used by
+    /// the language server, which reports errors in documents that have not
+    /// been saved. Validation failures that are configured to be warnings are
+    /// added to `warnings` instead of being logged.
+    pub fn validate_overlaid_source_files(
+        &self,
+        source_files: &OverlaidSourceFiles,
+        warnings: &mut Vec<WithLocation<ValidateSchemaError>>,
+    ) -> Result<ValidatedSchema, BatchCompileError> {
+        validate_source_files(
+            source_files.source_files(&self.config)?,
+            &self.config,
+            warnings,
+        )
+        .map(|(validated_schema, _)| validated_schema)
+    }
+
     fn update_and_clone_source_files(
         &mut self,
         changes: &[SourceFileEvent],
@@ -154,18 +182,19 @@ pub fn validate_and_create_artifacts_from_source_files(
     file_extensions: GenerateFileExtensionsOption,
     on_missing_babel_transform: OptionalValidationLevel,
 ) -> Result<usize, BatchCompileError> {
-    // Create schema
-    let mut unvalidated_schema = UnvalidatedSchema::new();
-    source_files.create_unvalidated_schema(&mut unvalidated_schema, config)?;
-
-    // Validate
-    let validated_schema = Schema::validate_and_construct(unvalidated_schema)?;
+    let mut warnings = vec![];
+    let validation_result = validate_source_files(source_files, config, &mut warnings);
+    for warning in warnings {
+        warn!("{warning}");
+    }
+    let (validated_schema, encountered_client_field_map) = validation_result?;
 
     // Note: we calculate all of the artifact paths and contents first, so that writing to
     // disk can be as fast as possible and we minimize the chance that changes to the file
     // system occur while we're writing and we get unpredictable results.
     let artifacts = get_artifact_path_and_content(
         &validated_schema,
+        encountered_client_field_map,
         &config.project_root,
         &config.artifact_directory,
         file_extensions,
@@ -175,3 +204,26 @@ pub fn validate_and_create_artifacts_from_source_files(
     let total_artifacts_written = write_artifacts_to_disk(artifacts, &config.artifact_directory)?;
     Ok(total_artifacts_written)
 }
+
+fn validate_source_files(
+    source_files: SourceFiles,
+    config: &CompilerConfig,
+    warnings: &mut Vec<WithLocation<ValidateSchemaError>>,
+) -> Result<
+    (
+        ValidatedSchema,
+        ClientFieldToCompletedMergeTraversalStateMap,
+    ),
+    BatchCompileError,
+> {
+    // Create schema
+    let mut unvalidated_schema = UnvalidatedSchema::new();
+    source_files.create_unvalidated_schema(&mut unvalidated_schema, config)?;
+
+    // Validate
+    Ok(Schema::validate_and_construct(
+        unvalidated_schema,
+        config.options,
+        warnings,
+    )?)
+}
@@ -1,21 +1,126 @@
-use common_lang_types::{IsographDirectiveName, Location, WithLocation, WithSpan};
+use common_lang_types::{IsographDirectiveName, Location, TextSource, WithLocation, WithSpan};
 use intern::string_key::Intern;
 use isograph_lang_types::{
     from_isograph_field_directive, ClientFieldDeclaration,
     ClientFieldDeclarationWithUnvalidatedDirectives, ClientFieldDeclarationWithValidatedDirectives,
-    IsographFieldDirective, IsographSelectionVariant, LinkedFieldSelection, ScalarFieldSelection,
-    ServerFieldSelection,
+    ComponentDirectiveParameters, DeferDirectiveParameters, DeserializationError,
+    IsographDirectiveLocation, IsographFieldDirective, IsographSelectionVariant,
+    LinkedFieldSelection, LoadableDirectiveParameters, PaginatedDirectiveParameters,
+    RequiredDirectiveParameters, ScalarFieldSelection, ServerFieldSelection,
+    StreamDirectiveParameters, UpdatableDirectiveParameters,
 };
 use isograph_schema::ProcessClientFieldDeclarationError;
 use lazy_static::lazy_static;
 
 lazy_static! {
     static ref LOADABLE_DIRECTIVE_NAME: IsographDirectiveName = "loadable".intern().into();
+    static ref PAGINATED_DIRECTIVE_NAME: IsographDirectiveName = "paginated".intern().into();
+    static ref KNOWN_DIRECTIVES: Vec<KnownDirective> = vec![
+        KnownDirective {
+            name: "component".intern().into(),
+            locations: &[IsographDirectiveLocation::ClientFieldDeclaration],
+            validate_arguments: |directive| {
+                from_isograph_field_directive::<ComponentDirectiveParameters>(directive)
+                    .map(|_| ())
+            },
+        },
+        KnownDirective {
+            name: *LOADABLE_DIRECTIVE_NAME,
+            locations: &[IsographDirectiveLocation::ScalarFieldSelection],
+            validate_arguments: |directive| {
+                from_isograph_field_directive::<LoadableDirectiveParameters>(directive).map(|_| ())
+            },
+        },
+        KnownDirective {
+            name: *PAGINATED_DIRECTIVE_NAME,
+            locations: &[IsographDirectiveLocation::LinkedFieldSelection],
+            validate_arguments: |directive| {
+                from_isograph_field_directive::<PaginatedDirectiveParameters>(directive)
+                    .map(|_| ())
+            },
+        },
+        KnownDirective {
+            name: "skip".intern().into(),
+            locations: &[
+                IsographDirectiveLocation::ScalarFieldSelection,
+                IsographDirectiveLocation::LinkedFieldSelection,
+            ],
+            // The if argument is a variable, so it is validated against the client
+            // field's variable definitions when the schema is validated.
+            validate_arguments: |_| Ok(()),
+        },
+        KnownDirective {
+            name: "include".intern().into(),
+            locations: &[
+                IsographDirectiveLocation::ScalarFieldSelection,
+                IsographDirectiveLocation::LinkedFieldSelection,
+            ],
+            validate_arguments: |_| Ok(()),
+        },
+        KnownDirective {
+            name: "updatable".intern().into(),
+            locations: &[
+                IsographDirectiveLocation::ClientFieldDeclaration,
+                IsographDirectiveLocation::LinkedFieldSelection,
+            ],
+            validate_arguments: |directive| {
+                from_isograph_field_directive::<UpdatableDirectiveParameters>(directive)
+                    .map(|_| ())
+            },
+        },
+        KnownDirective {
+            name: "required".intern().into(),
+            locations: &[
+                IsographDirectiveLocation::ScalarFieldSelection,
+                IsographDirectiveLocation::LinkedFieldSelection,
+            ],
+            validate_arguments: |directive| {
+                from_isograph_field_directive::<RequiredDirectiveParameters>(directive).map(|_| ())
+            },
+        },
+        KnownDirective {
+            name: "defer".intern().into(),
+            // Whether the selection can be deferred (i.e. it is a linked field or a client
+            // field) is validated when the schema is validated.
+            locations: &[
+                IsographDirectiveLocation::ScalarFieldSelection,
+                IsographDirectiveLocation::LinkedFieldSelection,
+            ],
+            validate_arguments: |directive| {
+                from_isograph_field_directive::<DeferDirectiveParameters>(directive).map(|_| ())
+            },
+        },
+        KnownDirective {
+            name: "stream".intern().into(),
+            locations: &[IsographDirectiveLocation::LinkedFieldSelection],
+            validate_arguments: |directive| {
+                from_isograph_field_directive::<StreamDirectiveParameters>(directive).map(|_| ())
+            },
+        },
+    ];
+}
+
+/// A directive that can be written in an iso literal.
+struct KnownDirective {
+    name: IsographDirectiveName,
+    locations: &'static [IsographDirectiveLocation],
+    validate_arguments: fn(&IsographFieldDirective) -> Result<(), DeserializationError>,
+}
+
+/// The names of the directives that can be written at a given location.
+pub fn known_directive_names(
+    location: IsographDirectiveLocation,
+) -> impl Iterator<Item = IsographDirectiveName> {
+    KNOWN_DIRECTIVES
+        .iter()
+        .filter(move |known_directive| known_directive.locations.contains(&location))
+        .map(|known_directive| known_directive.name)
 }
 
 #[allow(clippy::complexity)]
 pub fn validate_isograph_field_directives(
     client_field: WithSpan<ClientFieldDeclarationWithUnvalidatedDirectives>,
+    text_source: TextSource,
 ) -> Result<
     WithSpan<ClientFieldDeclarationWithValidatedDirectives>,
     Vec<WithLocation<ProcessClientFieldDeclarationError>>,
@@ -32,31 +137,76 @@ pub fn validate_isograph_field_directives(
         dot,
         field_keyword,
     } = client_field.item;
+    let declaration_directive_errors = validate_known_directives(
+        &directives,
+        IsographDirectiveLocation::ClientFieldDeclaration,
+        text_source,
+    )
+    .err();
     let new_selecton_set = and_then_selection_set_and_collect_errors(
         selection_set,
         &|scalar_field_selection| {
+            validate_known_directives(
+                &scalar_field_selection.directives,
+                IsographDirectiveLocation::ScalarFieldSelection,
+                text_source,
+            )?;
             if let Some(directive) =
                 find_directive_named(&scalar_field_selection.directives, *LOADABLE_DIRECTIVE_NAME)
             {
                 let loadable_variant =
                     from_isograph_field_directive(&directive.item).map_err(|message| {
-                        WithLocation::new(
+                        vec![WithLocation::new(
                             ProcessClientFieldDeclarationError::UnableToDeserialize {
-                                directive_name: *LOADABLE_DIRECTIVE_NAME,
+                                directive_name: directive.item.name.item,
                                 message,
                             },
-                            Location::generated(),
-                        )
+                            Location::new(text_source, directive.span),
+                        )]
                     })?;
-                // TODO validate that the field is actually loadable (i.e. implements Node or
-                // whatnot)
+                // Whether the field can actually be refetched is validated when the schema
+                // is validated, since that requires knowing the selected field.
                 Ok(IsographSelectionVariant::Loadable(loadable_variant))
             } else {
                 Ok(IsographSelectionVariant::Regular)
             }
         },
-        &|_linked_field_selection| Ok(IsographSelectionVariant::Regular),
-    )?;
+        &|linked_field_selection| {
+            validate_known_directives(
+                &linked_field_selection.directives,
+                IsographDirectiveLocation::LinkedFieldSelection,
+                text_source,
+            )?;
+            if let Some(directive) = find_directive_named(
+                &linked_field_selection.directives,
+                *PAGINATED_DIRECTIVE_NAME,
+            ) {
+                let paginated_variant =
+                    from_isograph_field_directive(&directive.item).map_err(|message| {
+                        vec![WithLocation::new(
+                            ProcessClientFieldDeclarationError::UnableToDeserialize {
+                                directive_name: directive.item.name.item,
+                                message,
+                            },
+                            Location::new(text_source, directive.span),
+                        )]
+                    })?;
+                // Whether the field is a connection is validated when the schema is
+                // validated, since that requires knowing the selected field's type.
+                Ok(IsographSelectionVariant::Paginated(paginated_variant))
+            } else {
+                Ok(IsographSelectionVariant::Regular)
+            }
+        },
+    );
+    let new_selecton_set = match (declaration_directive_errors, new_selecton_set) {
+        (None, Ok(new_selection_set)) => new_selection_set,
+        (declaration_directive_errors, new_selection_set) => {
+            let mut errors = declaration_directive_errors.unwrap_or_default();
+            errors.extend(new_selection_set.err().unwrap_or_default());
+            return Err(errors);
+        }
+    };
     Ok(WithSpan::new(
         ClientFieldDeclarationWithValidatedDirectives {
             const_export_name,
@@ -74,6 +224,64 @@ pub fn validate_isograph_field_directives(
     ))
 }
 
+/// Validate that every directive is a known Isograph directive, that it is allowed
+/// at this location, that it is used at most once, and that its arguments can be
+/// deserialized.
+fn validate_known_directives(
+    directives: &[WithSpan<IsographFieldDirective>],
+    directive_location: IsographDirectiveLocation,
+    text_source: TextSource,
+) -> Result<(), Vec<WithLocation<ProcessClientFieldDeclarationError>>> {
+    let mut errors = vec![];
+    for (index, directive) in directives.iter().enumerate() {
+        let directive_name = directive.item.name.item;
+        let location = Location::new(text_source, directive.span);
+        if directives[..index]
+            .iter()
+            .any(|previous_directive| previous_directive.item.name.item == directive_name)
+        {
+            errors.push(WithLocation::new(
+                ProcessClientFieldDeclarationError::DuplicateDirective { directive_name },
+                location,
+            ));
+            continue;
+        }
+        match KNOWN_DIRECTIVES
+            .iter()
+            .find(|known_directive| known_directive.name == directive_name)
+        {
+            None => errors.push(WithLocation::new(
+                ProcessClientFieldDeclarationError::UnknownDirective { directive_name },
+                location,
+            )),
+            Some(known_directive) => {
+                if !known_directive.locations.contains(&directive_location) {
+                    errors.push(WithLocation::new(
+                        ProcessClientFieldDeclarationError::DirectiveNotSupportedAtLocation {
+                            directive_name,
+                            directive_location,
+                        },
+                        location,
+                    ));
+                } else if let Err(message) = (known_directive.validate_arguments)(&directive.item) {
+                    errors.push(WithLocation::new(
+                        ProcessClientFieldDeclarationError::UnableToDeserialize {
+                            directive_name,
+                            message,
+                        },
+                        location,
+                    ));
+                }
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 fn and_then_selection_set_and_collect_errors<
     TScalarField,
     TLinkedField,
@@ -82,10 +290,10 @@ fn and_then_selection_set_and_collect_errors<
     E,
 >(
     selection_set: Vec<WithSpan<ServerFieldSelection<TScalarField, TLinkedField>>>,
-    and_then_scalar: &impl Fn(&ScalarFieldSelection<TScalarField>) -> Result<TScalarField2, E>,
+    and_then_scalar: &impl Fn(&ScalarFieldSelection<TScalarField>) -> Result<TScalarField2, Vec<E>>,
     and_then_linked: &impl Fn(
         &LinkedFieldSelection<TScalarField, TLinkedField>,
-    ) -> Result<TLinkedField2, E>,
+    ) -> Result<TLinkedField2, Vec<E>>,
 ) -> Result<Vec<WithSpan<ServerFieldSelection<TScalarField2, TLinkedField2>>>, Vec<E>> {
     let mut errors = vec![];
     let mut transformed_selection_set = vec![];
@@ -116,7 +324,7 @@ fn and_then_selection_set_and_collect_errors<
                             Err(e) => errors.extend(e),
                         }
                     }
-                    Err(e) => errors.push(e),
+                    Err(e) => errors.extend(e),
                 }
             }
             ServerFieldSelection::ScalarField(s) => {
@@ -131,7 +339,7 @@ fn and_then_selection_set_and_collect_errors<
                         }),
                         with_span.span,
                     )),
-                    Err(e) => errors.push(e),
+                    Err(e) => errors.extend(e),
                 };
             }
         };
@@ -152,3 +360,150 @@ fn find_directive_named(
         .iter()
         .find(|directive| directive.item.name.item == name)
 }
+
+#[cfg(test)]
+mod test {
+    use common_lang_types::TextSource;
+    use intern::string_key::Intern;
+    use isograph_lang_parser::{parse_iso_literal, IsoLiteralExtractionResult};
+    use isograph_schema::ProcessClientFieldDeclarationError;
+
+    use super::validate_isograph_field_directives;
+
+    fn validate(iso_literal_text: &str) -> Vec<ProcessClientFieldDeclarationError> {
+        let text_source = TextSource {
+            path: "dummy".intern().into(),
+            span: None,
+        };
+        let client_field_declaration = match parse_iso_literal(
+            iso_literal_text,
+            "dummy".intern().into(),
+            Some("Foo"),
+            text_source,
+        ) {
+            Ok(IsoLiteralExtractionResult::ClientFieldDeclaration(declaration)) => declaration,
+            _ => panic!("Expected a client field declaration"),
+        };
+        match validate_isograph_field_directives(client_field_declaration, text_source) {
+            Ok(_) => vec![],
+            Err(errors) => errors.into_iter().map(|error| error.item).collect(),
+        }
+    }
+
+    #[test]
+    fn known_directives_are_accepted() {
+        assert!(validate(
+            "field Query.Foo @component { bar @loadable(lazyLoadArtifact: true), \
+            baz { qux } @paginated, quux @skip(if: $x), }"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn unknown_directives_are_rejected() {
+        let errors = validate("field Query.Foo @componnet { bar @loadabel, }");
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|error| matches!(
+            error,
+            ProcessClientFieldDeclarationError::UnknownDirective { .. }
+        )));
+    }
+
+    #[test]
+    fn repeated_directives_are_rejected() {
+        let errors = validate(
+            "field Query.Foo @component @component { bar @loadable @loadable(lazyLoadArtifact: true), \
+            baz { qux } @updatable @required(action: LOG) @updatable, }",
+        );
+        assert_eq!(errors.len(), 3);
+        assert!(errors.iter().all(|error| matches!(
+            error,
+            ProcessClientFieldDeclarationError::DuplicateDirective { .. }
+        )));
+    }
+
+    #[test]
+    fn misplaced_directives_are_rejected() {
+        let errors =
+            validate("field Query.Foo @loadable { bar @paginated, baz { qux } @component, }");
+        assert_eq!(errors.len(), 3);
+        assert!(errors.iter().all(|error| matches!(
+            error,
+            ProcessClientFieldDeclarationError::DirectiveNotSupportedAtLocation { .. }
+        )));
+    }
+
+    #[test]
+    fn invalid_directive_arguments_are_rejected() {
+        let errors = validate("field Query.Foo @component(x: 1) { bar @loadable(foo: true), }");
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(|error| matches!(
+            error,
+            ProcessClientFieldDeclarationError::UnableToDeserialize { .. }
+        )));
+    }
+
+    #[test]
+    fn updatable_directive_is_allowed_on_declarations_and_linked_fields() {
+        assert!(validate("field Query.Foo @updatable { bar, baz { qux } @updatable, }").is_empty());
+
+        let errors = validate("field Query.Foo { bar @updatable, }");
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ProcessClientFieldDeclarationError::DirectiveNotSupportedAtLocation { .. }
+        ));
+    }
+
+    #[test]
+    fn required_directive_requires_a_valid_action() {
+        assert!(validate("field Query.Foo { bar @required(action: THROW), }").is_empty());
+        assert!(validate("field Query.Foo { baz { qux } @required(action: LOG), }").is_empty());
+
+        let errors = validate(
+            "field Query.Foo { bar @required, baz @required(action: CRASH), \
+            qux @required(action: true), }",
+        );
+        assert_eq!(errors.len(), 3);
+        assert!(errors.iter().all(|error| matches!(
+            error,
+            ProcessClientFieldDeclarationError::UnableToDeserialize { .. }
+        )));
+    }
+
+    #[test]
+    fn defer_and_stream_directives_are_accepted_on_selections() {
+        assert!(validate(
+            "field Query.Foo { Bar @defer, baz { qux } @defer, \
+            quux { id } @stream(initialCount: 2), }"
+        )
+        .is_empty());
+
+        let errors = validate(
+            "field Query.Foo @defer { bar @stream, baz { qux } @stream(initialCount: true), }",
+        );
+        assert_eq!(errors.len(), 3);
+        assert!(matches!(
+            errors[0],
+            ProcessClientFieldDeclarationError::DirectiveNotSupportedAtLocation { .. }
+        ));
+        assert!(matches!(
+            errors[1],
+            ProcessClientFieldDeclarationError::DirectiveNotSupportedAtLocation { .. }
+        ));
+        assert!(matches!(
+            errors[2],
+            ProcessClientFieldDeclarationError::UnableToDeserialize { .. }
+        ));
+    }
+
+    #[test]
+    fn negative_stream_initial_count_is_rejected() {
+        let errors = validate("field Query.Foo { bar { baz } @stream(initialCount: -1), }");
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ProcessClientFieldDeclarationError::UnableToDeserialize { .. }
+        ));
+    }
+}
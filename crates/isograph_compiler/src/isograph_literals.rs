@@ -31,23 +31,25 @@ pub(crate) fn read_files_in_folder(
 
     read_dir_recursive(folder)?
         .into_iter()
-        .filter(|p| {
-            let extension = p.extension().and_then(|x| x.to_str());
-
-            matches!(
-                extension,
-                Some("ts") | Some("tsx") | Some("js") | Some("jsx")
-            )
-        })
-        .filter(|p| {
-            !p.to_str()
-                .expect("Expected path to be stringable")
-                .contains("__isograph")
-        })
+        .filter(|p| is_source_file(p))
         .map(|path| read_file(path, canonicalized_root_path))
         .collect()
 }
 
+/// Whether the file at this path is a JavaScript or TypeScript file that may
+/// contain iso literals, i.e. is not a generated file.
+pub(crate) fn is_source_file(path: &Path) -> bool {
+    let extension = path.extension().and_then(|x| x.to_str());
+
+    matches!(
+        extension,
+        Some("ts") | Some("tsx") | Some("js") | Some("jsx")
+    ) && !path
+        .to_str()
+        .expect("Expected path to be stringable")
+        .contains("__isograph")
+}
+
 pub fn read_file(
     path: PathBuf,
     canonicalized_root_path: &Path,
@@ -74,7 +76,7 @@ pub fn read_file(
     ))
 }
 
-fn read_dir_recursive(root_js_path: &Path) -> Result<Vec<PathBuf>, BatchCompileError> {
+pub(crate) fn read_dir_recursive(root_js_path: &Path) -> Result<Vec<PathBuf>, BatchCompileError> {
     let mut paths = vec![];
 
     visit_dirs_skipping_isograph(root_js_path, &mut |dir_entry| {
@@ -152,7 +154,8 @@ pub(crate) fn process_iso_literals(
         for (extraction_result, text_source) in iso_literals {
             match extraction_result {
                 IsoLiteralExtractionResult::ClientFieldDeclaration(client_field_declaration) => {
-                    match validate_isograph_field_directives(client_field_declaration) {
+                    match validate_isograph_field_directives(client_field_declaration, text_source)
+                    {
                         Ok(validated_client_field_declaration) => {
                             if let Err(e) = schema.process_client_field_declaration(
                                 validated_client_field_declaration,
@@ -245,7 +248,7 @@ pub struct IsoLiteralExtraction<'a> {
 
 pub fn extract_iso_literals_from_file_content(
     content: &str,
-) -> impl Iterator<Item = IsoLiteralExtraction> + '_ {
+) -> impl Iterator<Item = IsoLiteralExtraction<'_>> + '_ {
     EXTRACT_ISO_LITERAL.captures_iter(content).map(|captures| {
         let iso_literal_match = captures.get(4).unwrap();
         IsoLiteralExtraction {
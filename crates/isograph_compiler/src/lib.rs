@@ -2,6 +2,7 @@ pub mod batch_compile;
 mod compiler_state;
 mod field_directives;
 mod isograph_literals;
+mod overlaid_source_files;
 mod refetch_fields;
 mod schema;
 mod source_files;
@@ -10,6 +11,9 @@ mod with_duration;
 mod write_artifacts;
 
 pub use batch_compile::compile_and_print;
+pub use compiler_state::CompilerState;
+pub use field_directives::known_directive_names;
 pub use isograph_literals::extract_iso_literals_from_file_content;
 pub use isograph_literals::IsoLiteralExtraction;
+pub use overlaid_source_files::OverlaidSourceFiles;
 pub use watch::handle_watch_command;
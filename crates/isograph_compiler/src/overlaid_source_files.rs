@@ -0,0 +1,313 @@
+use std::{
+    collections::HashMap,
+    io,
+    path::{Path, PathBuf},
+};
+
+use common_lang_types::{SourceFileName, TextSource};
+use graphql_lang_types::{GraphQLTypeSystemDocument, GraphQLTypeSystemExtensionDocument};
+use isograph_config::CompilerConfig;
+use isograph_lang_parser::IsoLiteralExtractionResult;
+
+use crate::{
+    batch_compile::BatchCompileError,
+    isograph_literals::{is_source_file, read_and_parse_iso_literals, read_dir_recursive},
+    source_files::{
+        parse_graphql_schema, parse_graphql_schema_extensions, ContainsIso, SourceFiles,
+    },
+};
+
+/// The source files of a project, as seen by the language server: the contents
+/// of open documents, overlaid on top of the contents on disk.
+///
+/// Unlike [SourceFiles], each file is parsed on its own. When a file changes
+/// (in the editor or on disk), only that file is re-read and re-parsed, and a
+/// file that cannot be parsed does not prevent us from knowing the contents of
+/// the others.
+pub struct OverlaidSourceFiles {
+    schema: ParsedFile<GraphQLTypeSystemDocument>,
+    schema_extensions:
+        HashMap<PathBuf, ParsedFile<(SourceFileName, GraphQLTypeSystemExtensionDocument)>>,
+    /// Only files that contain iso literals (or that cannot be parsed) are
+    /// included.
+    iso_literals: HashMap<PathBuf, ParsedFile<IsoLiterals>>,
+    /// The contents of open documents, keyed by absolute path.
+    open_documents: HashMap<PathBuf, String>,
+}
+
+type IsoLiterals = (
+    SourceFileName,
+    Vec<(IsoLiteralExtractionResult, TextSource)>,
+);
+
+/// A file, after reading and parsing it. If parsing failed, we keep the contents
+/// of the file, so that the parse errors can be re-created whenever the project
+/// is validated.
+enum ParsedFile<T> {
+    Parsed(T),
+    Unparsable(String),
+    Unreadable(String),
+}
+
+enum SourceFileKind {
+    Schema,
+    SchemaExtension,
+    JavaScriptSourceFile,
+}
+
+impl OverlaidSourceFiles {
+    /// Read and parse the schema, schema extensions and every source file in the
+    /// project root. No documents are open.
+    pub fn read_and_parse_all_files(config: &CompilerConfig) -> Self {
+        let mut source_files = Self {
+            schema: read_and_parse_file(read_file(&config.schema), |content| {
+                parse_graphql_schema(content, &config.schema)
+            }),
+            schema_extensions: HashMap::new(),
+            iso_literals: HashMap::new(),
+            open_documents: HashMap::new(),
+        };
+        for schema_extension_path in config.schema_extensions.iter() {
+            source_files.update_schema_extension(schema_extension_path);
+        }
+        source_files.update_folder(config, &config.project_root);
+        source_files
+    }
+
+    /// Use `content` instead of the contents on disk for the file at `path`,
+    /// until the document is closed.
+    pub fn open_document(&mut self, config: &CompilerConfig, path: &Path, content: String) {
+        self.open_documents.insert(path.to_path_buf(), content);
+        self.update_path(config, path);
+    }
+
+    /// Stop using the contents of the open document at `path`, and re-read the
+    /// file from disk.
+    pub fn close_document(&mut self, config: &CompilerConfig, path: &Path) {
+        if self.open_documents.remove(path).is_some() {
+            self.update_path(config, path);
+        }
+    }
+
+    /// Re-read a file or folder that was created, modified or removed on disk.
+    /// Changes to open documents are ignored, since their contents come from
+    /// the editor.
+    pub fn path_changed_on_disk(&mut self, config: &CompilerConfig, path: &Path) {
+        if !self.open_documents.contains_key(path) {
+            self.update_path(config, path);
+        }
+    }
+
+    /// The files that contain iso literals (or that cannot be parsed, and may
+    /// contain iso literals).
+    pub fn paths_with_iso_literals(&self) -> impl Iterator<Item = &PathBuf> {
+        self.iso_literals.keys()
+    }
+
+    /// The path and contents of every source file in the project root, including
+    /// files without iso literals (e.g. files that only import artifacts). The
+    /// contents of open documents are used instead of the contents on disk.
+    pub fn read_all_source_files(&self, config: &CompilerConfig) -> Vec<(PathBuf, String)> {
+        read_dir_recursive(&config.project_root)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|path| !path.starts_with(&config.artifact_directory) && is_source_file(path))
+            .filter_map(|path| {
+                let content = self.read(&path).ok()?;
+                Some((path, content))
+            })
+            .collect()
+    }
+
+    /// Combine the parsed files into a [SourceFiles], which can be validated. If
+    /// any file could not be read or parsed, all of the resulting errors are
+    /// returned instead.
+    pub fn source_files(&self, config: &CompilerConfig) -> Result<SourceFiles, BatchCompileError> {
+        let mut errors = vec![];
+        let mut iso_literal_parse_errors = vec![];
+
+        let schema = match self.schema.parsed(&config.schema, |content| {
+            parse_graphql_schema(content, &config.schema)
+        }) {
+            Ok(schema) => Some(schema),
+            Err(e) => {
+                errors.push(e);
+                None
+            }
+        };
+
+        let mut schema_extensions = HashMap::new();
+        for (path, schema_extension) in self.schema_extensions.iter() {
+            match schema_extension.parsed(path, |content| {
+                parse_graphql_schema_extensions(content, path)
+            }) {
+                Ok((file_path, document)) => {
+                    schema_extensions.insert(file_path, document);
+                }
+                Err(e) => errors.push(e),
+            }
+        }
+
+        let mut contains_iso = ContainsIso::default();
+        for (path, iso_literals) in self.iso_literals.iter() {
+            match iso_literals.parsed(path, |content| parse_iso_literals(config, path, content)) {
+                Ok((file_path, iso_literals)) => {
+                    contains_iso.insert(file_path, iso_literals);
+                }
+                Err(BatchCompileError::UnableToParseIsographLiterals { messages }) => {
+                    iso_literal_parse_errors.extend(messages)
+                }
+                Err(e) => errors.push(e),
+            }
+        }
+        if !iso_literal_parse_errors.is_empty() {
+            errors.push(iso_literal_parse_errors.into());
+        }
+
+        match schema {
+            Some(schema) if errors.is_empty() => Ok(SourceFiles {
+                schema,
+                schema_extensions,
+                contains_iso,
+            }),
+            _ => Err(if errors.len() == 1 {
+                errors.pop().expect("Expected an error")
+            } else {
+                BatchCompileError::MultipleErrors { messages: errors }
+            }),
+        }
+    }
+
+    fn update_path(&mut self, config: &CompilerConfig, path: &Path) {
+        match categorize_path(config, path) {
+            Some(SourceFileKind::Schema) => {
+                self.schema = read_and_parse_file(self.read(path), |content| {
+                    parse_graphql_schema(content, path)
+                });
+            }
+            Some(SourceFileKind::SchemaExtension) => self.update_schema_extension(path),
+            Some(SourceFileKind::JavaScriptSourceFile) => self.update_iso_literals(config, path),
+            None => {
+                if path.starts_with(&config.project_root)
+                    && !path.starts_with(&config.artifact_directory)
+                {
+                    // This may be a folder that was created, moved or removed. In
+                    // every case, the files that we know of within it are stale.
+                    self.iso_literals
+                        .retain(|file_path, _| !file_path.starts_with(path));
+                    if path.is_dir() {
+                        self.update_folder(config, path);
+                    }
+                }
+            }
+        }
+    }
+
+    fn update_schema_extension(&mut self, path: &Path) {
+        let schema_extension = read_and_parse_file(self.read(path), |content| {
+            parse_graphql_schema_extensions(content, path)
+        });
+        self.schema_extensions
+            .insert(path.to_path_buf(), schema_extension);
+    }
+
+    fn update_iso_literals(&mut self, config: &CompilerConfig, path: &Path) {
+        let content = self.read(path);
+        if matches!(&content, Err(e) if e.kind() == io::ErrorKind::NotFound) {
+            self.iso_literals.remove(path);
+            return;
+        }
+        match read_and_parse_file(content, |content| parse_iso_literals(config, path, content)) {
+            ParsedFile::Parsed((_, iso_literals)) if iso_literals.is_empty() => {
+                self.iso_literals.remove(path);
+            }
+            iso_literals => {
+                self.iso_literals.insert(path.to_path_buf(), iso_literals);
+            }
+        }
+    }
+
+    fn update_folder(&mut self, config: &CompilerConfig, folder: &Path) {
+        // If the folder cannot be traversed, there are no files in it that we
+        // can parse.
+        for path in read_dir_recursive(folder).unwrap_or_default() {
+            if !path.starts_with(&config.artifact_directory) && is_source_file(&path) {
+                self.update_iso_literals(config, &path);
+            }
+        }
+    }
+
+    fn read(&self, path: &Path) -> io::Result<String> {
+        match self.open_documents.get(path) {
+            Some(content) => Ok(content.clone()),
+            None => read_file(path),
+        }
+    }
+}
+
+impl<T: Clone> ParsedFile<T> {
+    fn parsed(
+        &self,
+        path: &Path,
+        parse: impl FnOnce(&str) -> Result<T, BatchCompileError>,
+    ) -> Result<T, BatchCompileError> {
+        match self {
+            ParsedFile::Parsed(parsed) => Ok(parsed.clone()),
+            ParsedFile::Unparsable(content) => parse(content),
+            ParsedFile::Unreadable(message) => Err(BatchCompileError::UnableToReadFile {
+                path: path.to_path_buf(),
+                message: message.clone(),
+            }),
+        }
+    }
+}
+
+fn read_and_parse_file<T>(
+    content: io::Result<String>,
+    parse: impl FnOnce(&str) -> Result<T, BatchCompileError>,
+) -> ParsedFile<T> {
+    match content {
+        Ok(content) => match parse(&content) {
+            Ok(parsed) => ParsedFile::Parsed(parsed),
+            Err(_) => ParsedFile::Unparsable(content),
+        },
+        Err(e) => ParsedFile::Unreadable(e.to_string()),
+    }
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    std::fs::read_to_string(path)
+}
+
+fn parse_iso_literals(
+    config: &CompilerConfig,
+    path: &Path,
+    content: &str,
+) -> Result<IsoLiterals, BatchCompileError> {
+    let relative_path = path.strip_prefix(&config.project_root)?.to_path_buf();
+    Ok(read_and_parse_iso_literals(
+        relative_path,
+        content.to_string(),
+        &config.project_root,
+    )?)
+}
+
+fn categorize_path(config: &CompilerConfig, path: &Path) -> Option<SourceFileKind> {
+    if path == config.schema {
+        Some(SourceFileKind::Schema)
+    } else if config
+        .schema_extensions
+        .iter()
+        .any(|extension| extension == path)
+    {
+        Some(SourceFileKind::SchemaExtension)
+    } else if path.starts_with(&config.project_root)
+        && !path.starts_with(&config.artifact_directory)
+        && is_source_file(path)
+        && !path.is_dir()
+    {
+        Some(SourceFileKind::JavaScriptSourceFile)
+    } else {
+        None
+    }
+}
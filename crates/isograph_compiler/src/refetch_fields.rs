@@ -3,10 +3,9 @@ use std::collections::btree_map::Entry;
 use intern::string_key::Intern;
 use isograph_lang_types::ServerObjectId;
 use isograph_schema::{
-    generate_refetch_field_strategy, id_arguments, id_selection, id_top_level_arguments,
-    ClientField, ClientFieldVariant, ClientType, FieldType, ImperativelyLoadedFieldVariant,
-    ObjectTypeAndFieldName, RefetchStrategy, RequiresRefinement, SchemaObject,
-    UnvalidatedClientField, UnvalidatedSchema, NODE_FIELD_NAME, REFETCH_FIELD_NAME,
+    generate_refetch_field_strategy, ClientField, ClientFieldVariant, ClientType, FieldType,
+    ImperativelyLoadedFieldVariant, ObjectTypeAndFieldName, RefetchStrategy, SchemaObject,
+    UnvalidatedClientField, UnvalidatedSchema, REFETCH_FIELD_NAME,
 };
 
 use crate::batch_compile::BatchCompileError;
@@ -17,7 +16,7 @@ pub fn add_refetch_fields_to_objects(
     let query_id = schema.query_id();
 
     'objects: for object in schema.server_field_data.server_objects.iter_mut() {
-        if object.id_field.is_none() {
+        if object.refetch_field.is_none() {
             continue 'objects;
         }
 
@@ -42,6 +41,10 @@ fn add_refetch_field_to_object(
         Entry::Occupied(_) => return Some(Err(BatchCompileError::DuplicateRefetchField)),
         Entry::Vacant(vacant_entry) => {
             let next_client_field_id = client_fields.len().into();
+            let refetch_field = object
+                .refetch_field
+                .as_ref()
+                .expect("Expected object to have a refetch field");
 
             vacant_entry.insert(FieldType::ClientField(ClientType::ClientField(
                 next_client_field_id,
@@ -59,9 +62,10 @@ fn add_refetch_field_to_object(
                 variant: ClientFieldVariant::ImperativelyLoadedField(
                     ImperativelyLoadedFieldVariant {
                         client_field_scalar_selection_name: *REFETCH_FIELD_NAME,
-                        top_level_schema_field_name: *NODE_FIELD_NAME,
-                        top_level_schema_field_arguments: id_arguments(),
-                        top_level_schema_field_concrete_type: None,
+                        top_level_schema_field_name: refetch_field.field_name,
+                        top_level_schema_field_arguments: refetch_field.top_level_arguments(),
+                        top_level_schema_field_variables: refetch_field.arguments.clone(),
+                        top_level_schema_field_concrete_type: refetch_field.field_concrete_type,
                         primary_field_info: None,
 
                         root_object_id: query_id,
@@ -73,20 +77,19 @@ fn add_refetch_field_to_object(
                     field_name: "__refetch".intern().into(),
                 },
                 parent_object_id: object.id,
-                refetch_strategy: object.id_field.map(|_| {
-                    // Assume that if we have an id field, this implements Node
-                    RefetchStrategy::UseRefetchField(generate_refetch_field_strategy(
-                        vec![id_selection()],
+                refetch_strategy: Some(RefetchStrategy::UseRefetchField(
+                    generate_refetch_field_strategy(
+                        refetch_field.refetch_selection_set(),
                         query_id,
                         format!("refetch__{}", object.name).intern().into(),
-                        *NODE_FIELD_NAME,
-                        id_top_level_arguments(),
+                        refetch_field.field_name,
+                        refetch_field.top_level_arguments(),
+                        refetch_field.field_concrete_type,
+                        refetch_field.requires_refinement,
                         None,
-                        RequiresRefinement::Yes(object.name),
                         None,
-                        None,
-                    ))
-                }),
+                    ),
+                )),
             }));
         }
     }
@@ -64,6 +64,7 @@ impl SourceFiles {
             let _extension_outcome = schema
                 .process_graphql_type_extension_document(extension_document, config.options)?;
         }
+        schema.add_refetch_fields_to_object_types(config.options)?;
         process_iso_literals(schema, self.contains_iso)?;
         process_exposed_fields(schema)?;
         schema.add_fields_to_subtypes(&outcome.type_refinement_maps.supertype_to_subtype_map)?;
@@ -261,6 +262,13 @@ fn read_and_parse_graphql_schema(
     schema_path: &PathBuf,
 ) -> Result<GraphQLTypeSystemDocument, BatchCompileError> {
     let content = read_schema_file(schema_path)?;
+    parse_graphql_schema(&content, schema_path)
+}
+
+pub(crate) fn parse_graphql_schema(
+    content: &str,
+    schema_path: &Path,
+) -> Result<GraphQLTypeSystemDocument, BatchCompileError> {
     let schema_text_source = TextSource {
         path: schema_path
             .to_str()
@@ -269,7 +277,7 @@ fn read_and_parse_graphql_schema(
             .into(),
         span: None,
     };
-    let schema = parse_schema(&content, schema_text_source)
+    let schema = parse_schema(content, schema_text_source)
         .map_err(|with_span| with_span.to_with_location(schema_text_source))?;
     Ok(schema)
 }
@@ -280,19 +288,26 @@ fn intern_file_path(path: &Path) -> SourceFileName {
 
 pub fn read_and_parse_schema_extensions(
     schema_extension_path: &PathBuf,
+) -> Result<(SourceFileName, GraphQLTypeSystemExtensionDocument), BatchCompileError> {
+    let extension_content = read_schema_file(schema_extension_path)?;
+    parse_graphql_schema_extensions(&extension_content, schema_extension_path)
+}
+
+pub(crate) fn parse_graphql_schema_extensions(
+    extension_content: &str,
+    schema_extension_path: &Path,
 ) -> Result<(SourceFileName, GraphQLTypeSystemExtensionDocument), BatchCompileError> {
     let file_path = schema_extension_path
         .to_str()
         .expect("Expected schema extension to be valid string")
         .intern()
         .into();
-    let extension_content = read_schema_file(schema_extension_path)?;
     let extension_text_source = TextSource {
         path: file_path,
         span: None,
     };
 
-    let schema_extensions = parse_schema_extensions(&extension_content, extension_text_source)
+    let schema_extensions = parse_schema_extensions(extension_content, extension_text_source)
         .map_err(|with_span| with_span.to_with_location(extension_text_source))?;
 
     Ok((file_path, schema_extensions))
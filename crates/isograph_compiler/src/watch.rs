@@ -1,5 +1,5 @@
 use colored::Colorize;
-use isograph_config::CompilerConfig;
+use isograph_config::{create_config, CompilerConfig};
 use notify::{
     event::{CreateKind, ModifyKind, RemoveKind, RenameMode},
     Error, EventKind, RecommendedWatcher, RecursiveMode,
@@ -18,9 +18,9 @@ use crate::{
 const MAX_CHANGED_FILES: usize = 100;
 
 pub async fn handle_watch_command(
-    config_location: PathBuf,
+    config: CompilerConfig,
 ) -> Result<Result<(), Vec<Error>>, JoinError> {
-    let mut state = CompilerState::new(config_location);
+    let mut state = CompilerState::from_config(config);
     let (mut rx, mut watcher) = create_debounced_file_watcher(&state.config);
 
     info!("{}", "Starting to compile.".cyan());
@@ -36,10 +36,17 @@ pub async fn handle_watch_command(
                                 "{}",
                                 "Config change detected. Starting a full compilation.".cyan()
                             );
-                            state = CompilerState::new(state.config.config_location);
-                            watcher.stop();
-                            (rx, watcher) = create_debounced_file_watcher(&state.config);
-                            WithDuration::new(|| state.compile())
+                            // If the new config is invalid, keep watching with the
+                            // previous one, so that fixing the config is picked up.
+                            match create_config(state.config.config_location.clone()) {
+                                Ok(config) => {
+                                    state = CompilerState::from_config(config);
+                                    watcher.stop();
+                                    (rx, watcher) = create_debounced_file_watcher(&state.config);
+                                    WithDuration::new(|| state.compile())
+                                }
+                                Err(e) => WithDuration::new(|| Err(e.into())),
+                            }
                         } else if changes.len() < MAX_CHANGED_FILES {
                             info!("{}", "File changes detected. Starting to compile.".cyan());
                             WithDuration::new(|| state.update(&changes))
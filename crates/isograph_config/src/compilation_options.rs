@@ -1,7 +1,10 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use common_lang_types::SelectableFieldName;
+use intern::string_key::Intern;
 use schemars::JsonSchema;
 use serde::Deserialize;
+use thiserror::Error;
 use tracing::warn;
 
 pub static ISOGRAPH_FOLDER: &str = "__isograph";
@@ -25,11 +28,27 @@ pub struct CompilerConfig {
     pub options: ConfigOptions,
 }
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 pub struct ConfigOptions {
     pub on_invalid_id_type: OptionalValidationLevel,
     pub on_missing_babel_transform: OptionalValidationLevel,
+    pub on_deprecated_field_selection: OptionalValidationLevel,
     pub generate_file_extensions: GenerateFileExtensionsOption,
+    /// The name of the field that uniquely identifies an object, unless
+    /// the object has a field with a @strong directive.
+    pub strong_id_field_name: SelectableFieldName,
+}
+
+impl Default for ConfigOptions {
+    fn default() -> Self {
+        Self {
+            on_invalid_id_type: Default::default(),
+            on_missing_babel_transform: Default::default(),
+            on_deprecated_field_selection: OptionalValidationLevel::Warn,
+            generate_file_extensions: Default::default(),
+            strong_id_field_name: "id".intern().into(),
+        }
+    }
 }
 
 #[derive(Default, Debug, Clone, Copy)]
@@ -48,9 +67,10 @@ impl GenerateFileExtensionsOption {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub enum OptionalValidationLevel {
     /// If this validation error is encountered, it will be ignored
+    #[default]
     Ignore,
     /// If this validation error is encountered, a warning will be issued
     Warn,
@@ -73,11 +93,22 @@ impl OptionalValidationLevel {
             OptionalValidationLevel::Error => Err(on_error()),
         }
     }
-}
 
-impl Default for OptionalValidationLevel {
-    fn default() -> Self {
-        Self::Ignore
+    /// Like `on_failure`, but warnings are added to `warnings` instead of being
+    /// logged, so that the caller can report them (e.g. as diagnostics.)
+    pub fn on_failure_collecting_warnings<E>(
+        self,
+        warnings: &mut Vec<E>,
+        on_error: impl FnOnce() -> E,
+    ) -> Result<(), E> {
+        match self {
+            OptionalValidationLevel::Ignore => Ok(()),
+            OptionalValidationLevel::Warn => {
+                warnings.push(on_error());
+                Ok(())
+            }
+            OptionalValidationLevel::Error => Err(on_error()),
+        }
     }
 }
 
@@ -104,21 +135,17 @@ pub struct IsographProjectConfig {
     pub options: ConfigFileOptions,
 }
 
-pub fn create_config(config_location: PathBuf) -> CompilerConfig {
-    let config_contents = match std::fs::read_to_string(&config_location) {
-        Ok(contents) => contents,
-        Err(_) => match config_location.to_str() {
-            Some(loc) => {
-                panic!("Expected config to be found at {}", loc)
-            }
-            None => {
-                panic!("Expected config to be found.")
-            }
-        },
-    };
+pub fn create_config(config_location: PathBuf) -> Result<CompilerConfig, ConfigError> {
+    let config_contents =
+        std::fs::read_to_string(&config_location).map_err(|e| ConfigError::UnableToReadConfig {
+            path: config_location.clone(),
+            message: e.to_string(),
+        })?;
 
-    let config_parsed: IsographProjectConfig = serde_json::from_str(&config_contents)
-        .unwrap_or_else(|e| panic!("Error parsing config. Error: {}", e));
+    let config_parsed: IsographProjectConfig =
+        serde_json::from_str(&config_contents).map_err(|e| ConfigError::UnableToParseConfig {
+            message: e.to_string(),
+        })?;
 
     let mut config = config_location.clone();
     config.pop();
@@ -132,67 +159,83 @@ pub fn create_config(config_location: PathBuf) -> CompilerConfig {
                 .unwrap_or(&config_parsed.project_root),
         )
         .join(ISOGRAPH_FOLDER);
-    std::fs::create_dir_all(&artifact_dir).expect("Unable to create artifact directory");
+    create_dir_all(&artifact_dir)?;
 
     let project_root_dir = config_dir.join(&config_parsed.project_root);
-    std::fs::create_dir_all(&project_root_dir).expect("Unable to create project root directory");
-
-    CompilerConfig {
-        config_location: config_location.canonicalize().unwrap_or_else(|_| {
-            panic!(
-                "Unable to canonicalize config_file at {:?}.",
-                config_location
-            )
-        }),
-        project_root: project_root_dir.canonicalize().unwrap_or_else(|_| {
-            panic!(
-                "Unable to canonicalize project root at {:?}.",
-                config_parsed.project_root
-            )
-        }),
-        artifact_directory: artifact_dir.canonicalize().unwrap_or_else(|_| {
-            panic!(
-                "Unable to canonicalize artifact directory at {:?}.",
-                config_parsed.artifact_directory
-            )
-        }),
-        schema: config_dir
-            .join(&config_parsed.schema)
-            .canonicalize()
-            .unwrap_or_else(|_| {
-                panic!(
-                    "Unable to canonicalize schema path. Does {:?} exist?",
-                    config_parsed.schema
-                )
-            }),
+    create_dir_all(&project_root_dir)?;
+
+    Ok(CompilerConfig {
+        config_location: canonicalize(&config_location)?,
+        project_root: canonicalize(&project_root_dir)?,
+        artifact_directory: canonicalize(&artifact_dir)?,
+        schema: canonicalize(&config_dir.join(&config_parsed.schema))?,
         schema_extensions: config_parsed
             .schema_extensions
-            .into_iter()
-            .map(|schema_extension| {
-                config_dir
-                    .join(&schema_extension)
-                    .canonicalize()
-                    .unwrap_or_else(|_| {
-                        panic!(
-                            "Unable to canonicalize schema extension path. Does {:?} exist?",
-                            schema_extension
-                        )
-                    })
-            })
-            .collect(),
+            .iter()
+            .map(|schema_extension| canonicalize(&config_dir.join(schema_extension)))
+            .collect::<Result<_, _>>()?,
         options: create_options(config_parsed.options),
-    }
+    })
 }
 
-#[derive(Deserialize, Default, JsonSchema)]
+fn create_dir_all(path: &Path) -> Result<(), ConfigError> {
+    std::fs::create_dir_all(path).map_err(|e| ConfigError::UnableToCreateDirectory {
+        path: path.to_path_buf(),
+        message: e.to_string(),
+    })
+}
+
+fn canonicalize(path: &Path) -> Result<PathBuf, ConfigError> {
+    path.canonicalize()
+        .map_err(|e| ConfigError::UnableToCanonicalizePath {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })
+}
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Unable to read the config at {path:?}.\nReason: {message}")]
+    UnableToReadConfig { path: PathBuf, message: String },
+
+    #[error("Unable to parse the config.\nReason: {message}")]
+    UnableToParseConfig { message: String },
+
+    #[error("Unable to create the directory at {path:?}.\nReason: {message}")]
+    UnableToCreateDirectory { path: PathBuf, message: String },
+
+    #[error("Unable to canonicalize the path {path:?}. Does it exist?\nReason: {message}")]
+    UnableToCanonicalizePath { path: PathBuf, message: String },
+}
+
+#[derive(Deserialize, JsonSchema)]
 #[serde(default, deny_unknown_fields)]
 pub struct ConfigFileOptions {
     on_invalid_id_type: ConfigFileOptionalValidationLevel,
     on_missing_babel_transform: ConfigFileOptionalValidationLevel,
+    /// What the compiler should do if a field or enum value with a @deprecated
+    /// directive is selected. Defaults to warn.
+    on_deprecated_field_selection: ConfigFileOptionalValidationLevel,
     include_file_extensions_in_import_statements: bool,
+    /// The name of the field that uniquely identifies an object, and is used
+    /// to normalize it and to refetch it via node(id:). A field on a given type
+    /// can instead be marked with a @strong directive. Defaults to "id".
+    strong_id_field: String,
 }
 
-#[derive(Deserialize, Debug, Clone, Copy, JsonSchema)]
+impl Default for ConfigFileOptions {
+    fn default() -> Self {
+        Self {
+            on_invalid_id_type: ConfigFileOptionalValidationLevel::Error,
+            on_missing_babel_transform: ConfigFileOptionalValidationLevel::Error,
+            on_deprecated_field_selection: ConfigFileOptionalValidationLevel::Warn,
+            include_file_extensions_in_import_statements: false,
+            strong_id_field: "id".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum ConfigFileOptionalValidationLevel {
     /// If this validation error is encountered, it will be ignored
@@ -200,24 +243,23 @@ pub enum ConfigFileOptionalValidationLevel {
     /// If this validation error is encountered, a warning will be issued
     Warn,
     /// If this validation error is encountered, the compilation will fail
+    #[default]
     Error,
 }
 
-impl Default for ConfigFileOptionalValidationLevel {
-    fn default() -> Self {
-        Self::Error
-    }
-}
-
 fn create_options(options: ConfigFileOptions) -> ConfigOptions {
     ConfigOptions {
         on_invalid_id_type: create_optional_validation_level(options.on_invalid_id_type),
         on_missing_babel_transform: create_optional_validation_level(
             options.on_missing_babel_transform,
         ),
+        on_deprecated_field_selection: create_optional_validation_level(
+            options.on_deprecated_field_selection,
+        ),
         generate_file_extensions: create_generate_file_extensions(
             options.include_file_extensions_in_import_statements,
         ),
+        strong_id_field_name: options.strong_id_field.intern().into(),
     }
 }
 
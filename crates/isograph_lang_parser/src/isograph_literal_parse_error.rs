@@ -64,9 +64,6 @@ pub enum IsographLiteralParseError {
     )]
     DuplicateNameOrAlias { name_or_alias: FieldNameOrAlias },
 
-    #[error("Expected a boolean value (true or false).")]
-    ExpectedBoolean,
-
     #[error("Expected delimited `{delimiter} or `{closing_token}`")]
     ExpectedDelimiterOrClosingToken {
         closing_token: IsographLangTokenKind,
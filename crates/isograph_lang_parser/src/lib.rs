@@ -1,11 +1,13 @@
 mod description;
 mod isograph_literal_parse_error;
 mod parse_iso_literal;
+mod parse_partial_iso_literal;
 mod peekable_lexer;
 mod token_kind;
 
 pub(crate) use description::*;
 pub use isograph_literal_parse_error::*;
 pub use parse_iso_literal::*;
+pub use parse_partial_iso_literal::*;
 pub use peekable_lexer::*;
 pub use token_kind::*;
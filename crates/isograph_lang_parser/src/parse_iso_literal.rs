@@ -35,17 +35,33 @@ pub fn parse_iso_literal(
     text_source: TextSource,
 ) -> Result<IsoLiteralExtractionResult, WithLocation<IsographLiteralParseError>> {
     let mut tokens = PeekableLexer::new(iso_literal_text);
+    parse_iso_literal_inner(
+        &mut tokens,
+        definition_file_path,
+        const_export_name,
+        text_source,
+    )
+}
+
+/// Parse an iso literal from `tokens`. If `tokens` recovers at the end of the file,
+/// this produces a partial AST for an incomplete literal.
+pub(crate) fn parse_iso_literal_inner(
+    tokens: &mut PeekableLexer<'_>,
+    definition_file_path: FilePath,
+    const_export_name: Option<&str>,
+    text_source: TextSource,
+) -> Result<IsoLiteralExtractionResult, WithLocation<IsographLiteralParseError>> {
     let discriminator = tokens
         .parse_source_of_kind(IsographLangTokenKind::Identifier)
         .map_err(|with_span| with_span.map(IsographLiteralParseError::from))
         .map_err(|err| err.to_with_location(text_source))?;
     match discriminator.item {
         "entrypoint" => Ok(IsoLiteralExtractionResult::EntrypointDeclaration(
-            parse_iso_entrypoint_declaration(&mut tokens, text_source, discriminator.span)?,
+            parse_iso_entrypoint_declaration(tokens, text_source, discriminator.span)?,
         )),
         "field" => Ok(IsoLiteralExtractionResult::ClientFieldDeclaration(
             parse_iso_client_field_declaration(
-                &mut tokens,
+                tokens,
                 definition_file_path,
                 const_export_name,
                 text_source,
@@ -54,7 +70,7 @@ pub fn parse_iso_literal(
         )),
         "pointer" => Ok(IsoLiteralExtractionResult::ClientPointerDeclaration(
             parse_iso_client_pointer_declaration(
-                &mut tokens,
+                tokens,
                 definition_file_path,
                 const_export_name,
                 text_source,
@@ -78,18 +94,14 @@ fn parse_iso_entrypoint_declaration(
             let parent_type = tokens
                 .parse_string_key_type(IsographLangTokenKind::Identifier)
                 .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
-            let dot = tokens
-                .parse_token_of_kind(IsographLangTokenKind::Period)
-                .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
-            let client_field_name = tokens
-                .parse_string_key_type(IsographLangTokenKind::Identifier)
-                .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
+            let dot = parse_required_token(tokens, IsographLangTokenKind::Period)?;
+            let client_field_name = parse_required_identifier(tokens)?;
 
             Ok(EntrypointTypeAndField {
                 parent_type,
                 client_field_name,
                 entrypoint_keyword: WithSpan::new((), entrypoint_keyword),
-                dot: dot.map(|_| ()),
+                dot,
             })
         })
         .map_err(|with_span: WithSpan<_>| with_span.to_with_location(text_source))?;
@@ -142,13 +154,9 @@ fn parse_client_field_declaration_inner(
             .parse_string_key_type(IsographLangTokenKind::Identifier)
             .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
 
-        let dot = tokens
-            .parse_token_of_kind(IsographLangTokenKind::Period)
-            .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
+        let dot = parse_required_token(tokens, IsographLangTokenKind::Period)?;
 
-        let client_field_name: WithSpan<ScalarFieldName> = tokens
-            .parse_string_key_type(IsographLangTokenKind::Identifier)
-            .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
+        let client_field_name: WithSpan<ScalarFieldName> = parse_required_identifier(tokens)?;
 
         let variable_definitions = parse_variable_definitions(tokens, text_source)?;
 
@@ -169,7 +177,6 @@ fn parse_client_field_declaration_inner(
 
         // --------------------
         // TODO: use directives to:
-        // - ensure only component exists
         // - it ends up in the reader AST
         // --------------------
 
@@ -183,7 +190,7 @@ fn parse_client_field_declaration_inner(
             const_export_name: const_export_name.intern().into(),
             variable_definitions,
             field_keyword: WithSpan::new((), field_keyword_span),
-            dot: dot.map(|_| ()),
+            dot,
         })
     })
 }
@@ -226,13 +233,9 @@ fn parse_client_pointer_declaration_inner(
             .parse_string_key_type(IsographLangTokenKind::Identifier)
             .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
 
-        let dot = tokens
-            .parse_token_of_kind(IsographLangTokenKind::Period)
-            .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
+        let dot = parse_required_token(tokens, IsographLangTokenKind::Period)?;
 
-        let client_pointer_name: WithSpan<ScalarFieldName> = tokens
-            .parse_string_key_type(IsographLangTokenKind::Identifier)
-            .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
+        let client_pointer_name: WithSpan<ScalarFieldName> = parse_required_identifier(tokens)?;
 
         let variable_definitions = parse_variable_definitions(tokens, text_source)?;
 
@@ -258,7 +261,7 @@ fn parse_client_pointer_declaration_inner(
             const_export_name: const_export_name.intern().into(),
             variable_definitions,
             pointer_keyword: WithSpan::new((), pointer_keyword_span),
-            dot: dot.map(|_| ()),
+            dot,
         })
     })
 }
@@ -274,6 +277,7 @@ fn parse_selection_set(
     let selection_set = parse_optional_selection_set(tokens, text_source)?;
     match selection_set {
         Some(selection_set) => Ok(selection_set),
+        None if tokens.recovering_at_end_of_file() => Ok(vec![]),
         None => Err(WithSpan::new(
             IsographLiteralParseError::ExpectedSelectionSet,
             Span::new(0, 0),
@@ -297,6 +301,9 @@ fn parse_optional_selection_set(
         .parse_token_of_kind(IsographLangTokenKind::CloseBrace)
         .is_err()
     {
+        if tokens.recovering_at_end_of_file() {
+            break;
+        }
         let selection = parse_selection(tokens, text_source)?;
         let selection_name_or_alias = selection.item.name_or_alias().item;
         if !encountered_names_or_aliases.insert(selection_name_or_alias) {
@@ -328,14 +335,14 @@ fn parse_delimited_list<'a, TResult>(
     let mut items = vec![];
 
     // Handle empty list case
-    if tokens.parse_token_of_kind(closing_token).is_ok() {
+    if tokens.parse_token_of_kind(closing_token).is_ok() || tokens.recovering_at_end_of_file() {
         return Ok(items);
     }
 
     loop {
         items.push(parse_item(tokens)?);
 
-        if tokens.parse_token_of_kind(closing_token).is_ok() {
+        if tokens.parse_token_of_kind(closing_token).is_ok() || tokens.recovering_at_end_of_file() {
             break;
         }
 
@@ -361,6 +368,7 @@ fn parse_delimited_list<'a, TResult>(
 fn parse_comma_line_break_or_curly(tokens: &mut PeekableLexer<'_>) -> ParseResultWithSpan<()> {
     let comma = tokens.parse_token_of_kind(IsographLangTokenKind::Comma);
     if comma.is_ok()
+        || tokens.recovering_at_end_of_file()
         || tokens.source(tokens.white_space_span()).contains('\n')
         || matches!(tokens.peek().item, IsographLangTokenKind::CloseBrace)
     {
@@ -483,9 +491,7 @@ fn parse_argument(
         let name = tokens
             .parse_string_key_type(IsographLangTokenKind::Identifier)
             .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
-        tokens
-            .parse_token_of_kind(IsographLangTokenKind::Colon)
-            .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
+        parse_required_token(tokens, IsographLangTokenKind::Colon)?;
         let value = parse_non_constant_value(tokens)?.to_with_location(text_source);
         Ok::<_, WithSpan<IsographLiteralParseError>>(SelectionFieldArgument { name, value })
     })?;
@@ -495,6 +501,10 @@ fn parse_argument(
 fn parse_non_constant_value(
     tokens: &mut PeekableLexer,
 ) -> ParseResultWithSpan<WithSpan<NonConstantValue>> {
+    if tokens.recovering_at_end_of_file() {
+        return Ok(WithSpan::new(NonConstantValue::Null, tokens.peek().span));
+    }
+
     from_control_flow(|| {
         to_control_flow::<_, WithSpan<IsographLiteralParseError>>(|| {
             let _dollar_sign = tokens
@@ -516,19 +526,17 @@ fn parse_non_constant_value(
         })?;
 
         to_control_flow::<_, WithSpan<IsographLiteralParseError>>(|| {
-            let bool = tokens
+            let identifier = tokens
                 .parse_source_of_kind(IsographLangTokenKind::Identifier)
                 .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
 
-            let span = bool.span;
-
-            bool.and_then(|bool| match bool.parse::<bool>() {
-                Ok(b) => Ok(NonConstantValue::Boolean(b)),
-                Err(_) => Err(WithSpan::new(
-                    IsographLiteralParseError::ExpectedBoolean,
-                    span,
-                )),
-            })
+            // Identifiers other than true and false are enum values, e.g. THROW
+            Ok(
+                identifier.map(|identifier| match identifier.parse::<bool>() {
+                    Ok(b) => NonConstantValue::Boolean(b),
+                    Err(_) => NonConstantValue::Enum(identifier.intern().into()),
+                }),
+            )
         })?;
 
         ControlFlow::Continue(WithSpan::new(
@@ -571,9 +579,7 @@ fn parse_variable_definition(
             .parse_string_key_type(IsographLangTokenKind::Identifier)
             .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?
             .to_with_location(text_source);
-        tokens
-            .parse_token_of_kind(IsographLangTokenKind::Colon)
-            .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
+        parse_required_token(tokens, IsographLangTokenKind::Colon)?;
         let type_ = parse_type_annotation(tokens)?;
 
         let default_value = parse_optional_default_value(tokens, text_source)?;
@@ -614,6 +620,12 @@ fn parse_optional_default_value(
 fn parse_type_annotation(
     tokens: &mut PeekableLexer,
 ) -> ParseResultWithSpan<GraphQLTypeAnnotation<UnvalidatedTypeName>> {
+    if tokens.recovering_at_end_of_file() {
+        return Ok(GraphQLTypeAnnotation::Named(GraphQLNamedTypeAnnotation(
+            parse_required_identifier(tokens)?,
+        )));
+    }
+
     from_control_flow(|| {
         to_control_flow::<_, WithSpan<IsographLiteralParseError>>(|| {
             let type_ = tokens
@@ -641,9 +653,7 @@ fn parse_type_annotation(
                 .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
 
             let inner_type_annotation = parse_type_annotation(tokens)?;
-            tokens
-                .parse_token_of_kind(IsographLangTokenKind::CloseBracket)
-                .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
+            parse_required_token(tokens, IsographLangTokenKind::CloseBracket)?;
             let is_non_null = tokens
                 .parse_token_of_kind(IsographLangTokenKind::Exclamation)
                 .is_ok();
@@ -675,6 +685,32 @@ fn parse_type_annotation(
     })
 }
 
+/// When recovering at the end of the file, a missing token is treated as present.
+fn parse_required_token(
+    tokens: &mut PeekableLexer,
+    kind: IsographLangTokenKind,
+) -> ParseResultWithSpan<WithSpan<()>> {
+    if tokens.recovering_at_end_of_file() {
+        return Ok(WithSpan::new((), tokens.peek().span));
+    }
+    let token = tokens
+        .parse_token_of_kind(kind)
+        .map_err(|with_span| with_span.map(IsographLiteralParseError::from))?;
+    Ok(token.map(|_| ()))
+}
+
+/// When recovering at the end of the file, a missing identifier is treated as empty.
+fn parse_required_identifier<T: From<StringKey>>(
+    tokens: &mut PeekableLexer,
+) -> ParseResultWithSpan<WithSpan<T>> {
+    if tokens.recovering_at_end_of_file() {
+        return Ok(WithSpan::new("".intern().into(), tokens.peek().span));
+    }
+    tokens
+        .parse_string_key_type(IsographLangTokenKind::Identifier)
+        .map_err(|with_span| with_span.map(IsographLiteralParseError::from))
+}
+
 fn to_control_flow<T, E>(result: impl FnOnce() -> Result<T, E>) -> ControlFlow<T, E> {
     match result() {
         Ok(t) => ControlFlow::Break(t),
@@ -691,8 +727,10 @@ fn from_control_flow<T, E>(control_flow: impl FnOnce() -> ControlFlow<T, E>) ->
 
 #[cfg(test)]
 mod test {
+    use common_lang_types::TextSource;
+    use intern::string_key::Intern;
 
-    use crate::{IsographLangTokenKind, PeekableLexer};
+    use crate::{parse_iso_literal, IsographLangTokenKind, PeekableLexer};
 
     #[test]
     fn parse_literal_tests() {
@@ -706,4 +744,19 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn incomplete_literals_are_not_recovered() {
+        let text_source = TextSource {
+            path: "Foo.ts".intern().into(),
+            span: None,
+        };
+        assert!(parse_iso_literal(
+            "field Query.Foo {\n  pet(id: 1",
+            "Foo.ts".intern().into(),
+            Some("Foo"),
+            text_source,
+        )
+        .is_err());
+    }
 }
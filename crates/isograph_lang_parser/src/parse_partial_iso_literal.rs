@@ -0,0 +1,390 @@
+use common_lang_types::{
+    FieldArgumentName, SelectableFieldName, TextSource, UnvalidatedTypeName, VariableName, WithSpan,
+};
+use intern::{string_key::Intern, Lookup};
+use isograph_lang_types::{
+    EntrypointTypeAndField, NonConstantValue, SelectionFieldArgument, ServerFieldSelection,
+    UnvalidatedSelectionWithUnvalidatedDirectives, VariableDefinition,
+};
+
+use crate::{parse_iso_literal_inner, IsoLiteralExtractionResult, PeekableLexer};
+
+/// The result of parsing an iso literal that may be incomplete (e.g. because the
+/// user is typing it) up to a given position. This is used by the language server
+/// to provide completions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialIsoLiteral {
+    /// The parent type of the client field or entrypoint, if it has been written.
+    pub parent_type: Option<UnvalidatedTypeName>,
+    /// The variables declared by the client field, e.g. `id` for `field Query.Foo($id: ID!)`.
+    pub variable_names: Vec<VariableName>,
+    pub position: PartialIsoLiteralPosition,
+}
+
+/// What is being written at the position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PartialIsoLiteralPosition {
+    /// The parent type, e.g. `field |` or `entrypoint |`.
+    ParentType,
+    /// The client field of an entrypoint, e.g. `entrypoint Query.|`.
+    EntrypointClientFieldName,
+    /// The name of a selected field. `path` contains the names of the enclosing
+    /// linked fields, outermost first.
+    SelectionName { path: Vec<SelectableFieldName> },
+    /// The name of an argument of a selected field.
+    ArgumentName {
+        path: Vec<SelectableFieldName>,
+        field_name: SelectableFieldName,
+    },
+    /// The value of an argument of a selected field.
+    ArgumentValue {
+        path: Vec<SelectableFieldName>,
+        field_name: SelectableFieldName,
+        argument_name: Option<FieldArgumentName>,
+    },
+    /// A variable, e.g. `$|`.
+    Variable,
+    /// The name of a directive, e.g. `@|`.
+    DirectiveName { on_client_field_declaration: bool },
+    /// Anything else, e.g. the name of the client field being declared.
+    Other,
+}
+
+/// Replaces the identifier being written at the position (if any), so that whatever
+/// is being written there is this identifier. Since the placeholder does not
+/// depend on what has been written, only this one string is ever interned.
+const PLACEHOLDER: &str = "__isograph_partial_literal_placeholder";
+
+/// Parse `iso_literal_text` up to `offset`. Unlike `parse_iso_literal`, this does
+/// not fail on incomplete literals; instead, the parser recovers at `offset`, and the
+/// resulting partial AST is used to determine what is being written there.
+///
+/// If `offset` is within or immediately after an identifier, that identifier is
+/// treated as the (incomplete) thing being written.
+pub fn parse_partial_iso_literal(iso_literal_text: &str, offset: usize) -> PartialIsoLiteral {
+    let mut offset = offset.min(iso_literal_text.len());
+    while !iso_literal_text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let source = format!(
+        "{}{}",
+        &iso_literal_text[..start_of_identifier_before(iso_literal_text, offset)],
+        PLACEHOLDER
+    );
+
+    let mut partial_iso_literal = PartialIsoLiteral {
+        parent_type: None,
+        variable_names: vec![],
+        position: PartialIsoLiteralPosition::Other,
+    };
+
+    // The partial AST is only used to determine what is being written, so the
+    // file path and the name of the exported const do not matter.
+    let text_source = TextSource {
+        path: "".intern().into(),
+        span: None,
+    };
+    let Ok(extraction_result) = parse_iso_literal_inner(
+        &mut PeekableLexer::new_recovering_at_end_of_file(&source),
+        "".intern().into(),
+        Some(""),
+        text_source,
+    ) else {
+        return partial_iso_literal;
+    };
+
+    match extraction_result {
+        IsoLiteralExtractionResult::EntrypointDeclaration(entrypoint_declaration) => {
+            let EntrypointTypeAndField {
+                parent_type,
+                client_field_name,
+                ..
+            } = entrypoint_declaration.item;
+            if is_being_written(parent_type.item) {
+                partial_iso_literal.position = PartialIsoLiteralPosition::ParentType;
+                return partial_iso_literal;
+            }
+            partial_iso_literal.parent_type = Some(parent_type.item);
+            if is_being_written(client_field_name.item) {
+                partial_iso_literal.position = PartialIsoLiteralPosition::EntrypointClientFieldName;
+            }
+        }
+        IsoLiteralExtractionResult::ClientFieldDeclaration(client_field_declaration) => {
+            let client_field_declaration = client_field_declaration.item;
+            if is_being_written(client_field_declaration.parent_type.item) {
+                partial_iso_literal.position = PartialIsoLiteralPosition::ParentType;
+                return partial_iso_literal;
+            }
+            partial_iso_literal.parent_type = Some(client_field_declaration.parent_type.item);
+            partial_iso_literal.variable_names =
+                variable_names(&client_field_declaration.variable_definitions);
+            partial_iso_literal.position = if client_field_declaration
+                .directives
+                .iter()
+                .any(|directive| is_being_written(directive.item.name.item))
+            {
+                PartialIsoLiteralPosition::DirectiveName {
+                    on_client_field_declaration: true,
+                }
+            } else {
+                selection_set_position(&client_field_declaration.selection_set, &mut vec![])
+                    .unwrap_or(PartialIsoLiteralPosition::Other)
+            };
+        }
+        IsoLiteralExtractionResult::ClientPointerDeclaration(client_pointer_declaration) => {
+            let client_pointer_declaration = client_pointer_declaration.item;
+            if is_being_written(client_pointer_declaration.parent_type.item) {
+                partial_iso_literal.position = PartialIsoLiteralPosition::ParentType;
+                return partial_iso_literal;
+            }
+            partial_iso_literal.parent_type = Some(client_pointer_declaration.parent_type.item);
+            partial_iso_literal.variable_names =
+                variable_names(&client_pointer_declaration.variable_definitions);
+            partial_iso_literal.position =
+                selection_set_position(&client_pointer_declaration.selection_set, &mut vec![])
+                    .unwrap_or(PartialIsoLiteralPosition::Other);
+        }
+    }
+    partial_iso_literal
+}
+
+/// The start of the (possibly incomplete) identifier that ends at `offset`, or
+/// `offset` if there is none.
+fn start_of_identifier_before(text: &str, offset: usize) -> usize {
+    let before = &text[..offset];
+    let start = before
+        .char_indices()
+        .rev()
+        .find(|(_, char)| !(char.is_ascii_alphanumeric() || *char == '_'))
+        .map(|(index, char)| index + char.len_utf8())
+        .unwrap_or(0);
+    // Identifiers cannot start with a digit, so e.g. a number is not replaced.
+    start
+        + before[start..]
+            .find(|char: char| !char.is_ascii_digit())
+            .unwrap_or(offset - start)
+}
+
+fn is_being_written(name: impl Lookup) -> bool {
+    name.lookup() == PLACEHOLDER
+}
+
+/// The declared variables, excluding the one being written, if any.
+fn variable_names(
+    variable_definitions: &[WithSpan<VariableDefinition<UnvalidatedTypeName>>],
+) -> Vec<VariableName> {
+    variable_definitions
+        .iter()
+        .map(|variable_definition| variable_definition.item.name.item)
+        .filter(|variable_name| !is_being_written(*variable_name))
+        .collect()
+}
+
+/// `path` contains the names of the linked fields enclosing `selection_set`.
+fn selection_set_position(
+    selection_set: &[WithSpan<UnvalidatedSelectionWithUnvalidatedDirectives>],
+    path: &mut Vec<SelectableFieldName>,
+) -> Option<PartialIsoLiteralPosition> {
+    for selection in selection_set {
+        let (field_name, arguments, directives): (SelectableFieldName, _, _) = match &selection.item
+        {
+            ServerFieldSelection::ScalarField(scalar_field_selection) => (
+                scalar_field_selection.name.item.into(),
+                &scalar_field_selection.arguments,
+                &scalar_field_selection.directives,
+            ),
+            ServerFieldSelection::LinkedField(linked_field_selection) => (
+                linked_field_selection.name.item.into(),
+                &linked_field_selection.arguments,
+                &linked_field_selection.directives,
+            ),
+        };
+        if is_being_written(field_name) {
+            return Some(PartialIsoLiteralPosition::SelectionName { path: path.clone() });
+        }
+
+        for argument in arguments {
+            let SelectionFieldArgument { name, value } = &argument.item;
+            if is_being_written(name.item) {
+                return Some(PartialIsoLiteralPosition::ArgumentName {
+                    path: path.clone(),
+                    field_name,
+                });
+            }
+            match value.item {
+                NonConstantValue::Variable(variable_name) if is_being_written(variable_name) => {
+                    return Some(PartialIsoLiteralPosition::Variable);
+                }
+                NonConstantValue::Enum(enum_value) if is_being_written(enum_value) => {
+                    return Some(PartialIsoLiteralPosition::ArgumentValue {
+                        path: path.clone(),
+                        field_name,
+                        argument_name: Some(name.item),
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        for directive in directives {
+            if is_being_written(directive.item.name.item) {
+                return Some(PartialIsoLiteralPosition::DirectiveName {
+                    on_client_field_declaration: false,
+                });
+            }
+            if directive.item.arguments.iter().any(|argument| {
+                matches!(
+                    argument.item.value.item,
+                    NonConstantValue::Variable(variable_name) if is_being_written(variable_name)
+                )
+            }) {
+                return Some(PartialIsoLiteralPosition::Variable);
+            }
+        }
+
+        if let ServerFieldSelection::LinkedField(linked_field_selection) = &selection.item {
+            path.push(field_name);
+            let position = selection_set_position(&linked_field_selection.selection_set, path);
+            path.pop();
+            if position.is_some() {
+                return position;
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use common_lang_types::SelectableFieldName;
+    use intern::string_key::Intern;
+
+    use super::{parse_partial_iso_literal, start_of_identifier_before, PartialIsoLiteralPosition};
+
+    fn name(name: &str) -> SelectableFieldName {
+        name.intern().into()
+    }
+
+    fn position_at_end(text: &str) -> PartialIsoLiteralPosition {
+        parse_partial_iso_literal(text, text.len()).position
+    }
+
+    #[test]
+    fn only_the_identifier_being_written_is_replaced() {
+        assert_eq!(start_of_identifier_before("foo { ba", 8), 6);
+        assert_eq!(start_of_identifier_before("foo { ", 6), 6);
+        assert_eq!(start_of_identifier_before("foo(first: 12", 13), 13);
+        assert_eq!(start_of_identifier_before("é_1x", 5), 2);
+        assert_eq!(start_of_identifier_before("name", 4), 0);
+    }
+
+    #[test]
+    fn parent_type_and_entrypoint_positions() {
+        assert_eq!(
+            position_at_end("field "),
+            PartialIsoLiteralPosition::ParentType
+        );
+        assert_eq!(
+            position_at_end("entrypoint Que"),
+            PartialIsoLiteralPosition::ParentType
+        );
+        assert_eq!(
+            position_at_end("entrypoint Query.Pet"),
+            PartialIsoLiteralPosition::EntrypointClientFieldName
+        );
+    }
+
+    #[test]
+    fn selection_positions() {
+        let partial = parse_partial_iso_literal("field Query.Foo($id: ID!) {\n  na", 32);
+        assert_eq!(partial.parent_type, Some("Query".intern().into()));
+        assert_eq!(partial.variable_names, vec!["id".intern().into()]);
+        assert_eq!(
+            partial.position,
+            PartialIsoLiteralPosition::SelectionName { path: vec![] }
+        );
+
+        assert_eq!(
+            position_at_end("field Query.Foo {\n  alias: pet(id: 1) {\n    name\n    "),
+            PartialIsoLiteralPosition::SelectionName {
+                path: vec![name("pet")]
+            }
+        );
+        assert_eq!(
+            position_at_end("field Query.Foo {\n  pet(id: 1) {\n    name\n  }\n  "),
+            PartialIsoLiteralPosition::SelectionName { path: vec![] }
+        );
+        assert_eq!(
+            position_at_end("field Query.Foo {\n  name\n}\n"),
+            PartialIsoLiteralPosition::Other
+        );
+    }
+
+    #[test]
+    fn argument_and_directive_positions() {
+        assert_eq!(
+            position_at_end("field Query.Foo {\n  pet(i"),
+            PartialIsoLiteralPosition::ArgumentName {
+                path: vec![],
+                field_name: name("pet"),
+            }
+        );
+        assert_eq!(
+            position_at_end("field Query.Foo {\n  pet(id: "),
+            PartialIsoLiteralPosition::ArgumentValue {
+                path: vec![],
+                field_name: name("pet"),
+                argument_name: Some("id".intern().into()),
+            }
+        );
+        assert_eq!(
+            position_at_end("field Query.Foo($id: ID!) {\n  pet(id: $"),
+            PartialIsoLiteralPosition::Variable
+        );
+        assert_eq!(
+            position_at_end("field Query.Foo {\n  pet(id: 1, "),
+            PartialIsoLiteralPosition::ArgumentName {
+                path: vec![],
+                field_name: name("pet"),
+            }
+        );
+        assert_eq!(
+            position_at_end("field Query.Foo @"),
+            PartialIsoLiteralPosition::DirectiveName {
+                on_client_field_declaration: true
+            }
+        );
+        assert_eq!(
+            position_at_end("field Query.Foo {\n  pet @lo"),
+            PartialIsoLiteralPosition::DirectiveName {
+                on_client_field_declaration: false
+            }
+        );
+    }
+
+    #[test]
+    fn positions_within_incomplete_constructs() {
+        let partial = parse_partial_iso_literal("field Query.Foo($id: ID!, $fi", 29);
+        assert_eq!(partial.variable_names, vec!["id".intern().into()]);
+        assert_eq!(partial.position, PartialIsoLiteralPosition::Other);
+
+        assert_eq!(
+            position_at_end("field Query.Foo($id: [ID!]!) {\n  pet(sort: NA"),
+            PartialIsoLiteralPosition::ArgumentValue {
+                path: vec![],
+                field_name: name("pet"),
+                argument_name: Some("sort".intern().into()),
+            }
+        );
+        assert_eq!(
+            position_at_end("field Query.Foo($show: Boolean!) {\n  pet @include(if: $"),
+            PartialIsoLiteralPosition::Variable
+        );
+        assert_eq!(
+            position_at_end("pointer Query.Foo {\n  pet {\n    na"),
+            PartialIsoLiteralPosition::SelectionName {
+                path: vec![name("pet")]
+            }
+        );
+    }
+}
@@ -11,6 +11,7 @@ pub(crate) struct PeekableLexer<'source> {
     /// the byte offset of the *end* of the previous token
     end_index_of_last_parsed_token: u32,
     offset: u32,
+    recover_at_end_of_file: bool,
 }
 
 impl<'source> PeekableLexer<'source> {
@@ -30,6 +31,7 @@ impl<'source> PeekableLexer<'source> {
             source,
             end_index_of_last_parsed_token: 0,
             offset: 0,
+            recover_at_end_of_file: false,
         };
 
         // Advance to the first real token before doing any work
@@ -37,6 +39,15 @@ impl<'source> PeekableLexer<'source> {
         parser
     }
 
+    /// Like `new`, but the parser treats whatever it is parsing as complete when it
+    /// reaches the end of the source, instead of failing. This is used to parse
+    /// literals that are being written.
+    pub fn new_recovering_at_end_of_file(source: &'source str) -> Self {
+        let mut parser = Self::new(source);
+        parser.recover_at_end_of_file = true;
+        parser
+    }
+
     /// Get the next token (and advance)
     pub fn parse_token(&mut self) -> WithSpan<IsographLangTokenKind> {
         let kind = self
@@ -72,6 +83,10 @@ impl<'source> PeekableLexer<'source> {
         self.current.item == IsographLangTokenKind::EndOfFile
     }
 
+    pub fn recovering_at_end_of_file(&self) -> bool {
+        self.recover_at_end_of_file && self.reached_eof()
+    }
+
     /// A &str for the source of the given span
     pub fn source(&self, span: Span) -> &'source str {
         let (raw_start, raw_end) = span.as_usize();
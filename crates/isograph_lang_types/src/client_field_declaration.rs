@@ -66,6 +66,7 @@ pub type ClientFieldDeclarationWithValidatedDirectives =
 pub enum IsographSelectionVariant {
     Regular,
     Loadable(LoadableDirectiveParameters),
+    Paginated(PaginatedDirectiveParameters),
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy)]
@@ -77,6 +78,88 @@ pub struct LoadableDirectiveParameters {
     pub lazy_load_artifact: bool,
 }
 
+/// @paginated currently takes no arguments. Whether a connection is paginated
+/// forward or backward is determined by whether first or last is passed.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PaginatedDirectiveParameters {}
+
+/// @component currently takes no arguments.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ComponentDirectiveParameters {}
+
+/// @updatable currently takes no arguments.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UpdatableDirectiveParameters {}
+
+/// @defer currently takes no arguments. The label is generated from the
+/// deferred selection.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DeferDirectiveParameters {}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, Hash)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct StreamDirectiveParameters {
+    /// The number of items that are included in the initial response.
+    #[serde(default)]
+    pub initial_count: u32,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RequiredDirectiveParameters {
+    pub action: RequiredAction,
+}
+
+/// What the runtime does when a field selected with @required is null.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Copy, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RequiredAction {
+    /// Throw an error when reading.
+    Throw,
+    /// Log the null field, and make the parent object null instead.
+    Log,
+    /// Make the parent object null instead. This is written as NONE.
+    #[serde(rename = "NONE")]
+    Silent,
+}
+
+impl std::fmt::Display for RequiredAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequiredAction::Throw => write!(f, "THROW"),
+            RequiredAction::Log => write!(f, "LOG"),
+            RequiredAction::Silent => write!(f, "NONE"),
+        }
+    }
+}
+
+/// A selection with @skip(if: ...) or @include(if: ...) is only fetched and read if
+/// the condition holds.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SelectionCondition {
+    pub kind: SelectionConditionKind,
+    pub value: NonConstantValue,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SelectionConditionKind {
+    Skip,
+    Include,
+}
+
+impl SelectionConditionKind {
+    pub fn directive_name(&self) -> &'static str {
+        match self {
+            SelectionConditionKind::Skip => "skip",
+            SelectionConditionKind::Include => "include",
+        }
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
 pub enum ServerFieldSelection<TScalarField, TLinkedField> {
     ScalarField(ScalarFieldSelection<TScalarField>),
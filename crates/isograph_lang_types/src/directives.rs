@@ -14,6 +14,26 @@ pub struct IsographFieldDirective {
     pub arguments: Vec<WithLocation<SelectionFieldArgument>>,
 }
 
+/// The places in an iso literal where a directive can be written.
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
+pub enum IsographDirectiveLocation {
+    ClientFieldDeclaration,
+    ScalarFieldSelection,
+    LinkedFieldSelection,
+}
+
+impl std::fmt::Display for IsographDirectiveLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IsographDirectiveLocation::ClientFieldDeclaration => {
+                write!(f, "client field declarations")
+            }
+            IsographDirectiveLocation::ScalarFieldSelection => write!(f, "scalar field selections"),
+            IsographDirectiveLocation::LinkedFieldSelection => write!(f, "linked field selections"),
+        }
+    }
+}
+
 pub fn from_isograph_field_directive<'a, T: Deserialize<'a>>(
     directive: &'a IsographFieldDirective,
 ) -> Result<T, DeserializationError> {
@@ -156,7 +176,7 @@ impl<'de> Deserializer<'de> for NonConstantValueDeserializer<'de> {
             NonConstantValue::String(s) => visitor.visit_str(s.lookup()),
             NonConstantValue::Float(f) => visitor.visit_f64(f.as_float()),
             NonConstantValue::Null => visitor.visit_none(),
-            NonConstantValue::Enum(_) => panic!("Enums not supported when deserializing"),
+            NonConstantValue::Enum(e) => visitor.visit_str(e.lookup()),
             NonConstantValue::List(_) => {
                 panic!("Deserializing from lists is not yet supported here.")
             }
@@ -171,10 +191,25 @@ impl<'de> Deserializer<'de> for NonConstantValueDeserializer<'de> {
         visitor.visit_some(self)
     }
 
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        match self.value {
+            NonConstantValue::Enum(e) => visitor.visit_enum(e.lookup().into_deserializer()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
     serde::forward_to_deserialize_any! {
         bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
         bytes byte_buf unit unit_struct newtype_struct seq tuple
-        tuple_struct map struct enum ignored_any identifier
+        tuple_struct map struct ignored_any identifier
     }
 }
 
@@ -201,9 +236,24 @@ impl<'de> Deserializer<'de> for ValueDeserializer<'de> {
         deserializer.deserialize_option(visitor)
     }
 
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        let deserializer = NonConstantValueDeserializer {
+            value: &self.name_value_pair.value.item,
+        };
+        deserializer.deserialize_enum(name, variants, visitor)
+    }
+
     serde::forward_to_deserialize_any! {
         bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
         bytes byte_buf unit unit_struct newtype_struct seq tuple
-        tuple_struct map struct enum ignored_any identifier
+        tuple_struct map struct ignored_any identifier
     }
 }
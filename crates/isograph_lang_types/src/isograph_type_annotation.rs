@@ -65,6 +65,18 @@ impl<TInner: Ord + Copy + Debug> TypeAnnotation<TInner> {
             TypeAnnotation::Plural(type_annotation) => type_annotation.inner_non_null(),
         }
     }
+
+    /// Whether this is a list, ignoring nullability.
+    pub fn is_plural(&self) -> bool {
+        match self {
+            TypeAnnotation::Scalar(_) => false,
+            TypeAnnotation::Union(union_type_annotation) => union_type_annotation
+                .variants
+                .iter()
+                .any(|variant| matches!(variant, UnionVariant::Plural(_))),
+            TypeAnnotation::Plural(_) => true,
+        }
+    }
 }
 
 impl<TInner: Ord + Debug> TypeAnnotation<TInner> {
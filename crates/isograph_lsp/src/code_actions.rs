@@ -0,0 +1,539 @@
+use std::collections::HashMap;
+
+use common_lang_types::{
+    EmbeddedLocation, IsographObjectTypeName, ScalarFieldName, SelectableFieldName, Span,
+    TextSource, UnvalidatedTypeName,
+};
+use isograph_lang_parser::{IsoLiteralExtractionResult, IsographLiteralParseError};
+use isograph_lang_types::{ClientFieldDeclarationWithUnvalidatedDirectives, VariableDefinition};
+use isograph_schema::{UnvalidatedVariableDefinition, ValidateSchemaError};
+use lsp_types::{
+    request::{CodeActionRequest, Request},
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, Diagnostic, Range, TextEdit,
+    Url, WorkspaceEdit,
+};
+
+use crate::{
+    lsp_runtime_error::LSPRuntimeResult, lsp_state::LSPState, row_col_offset::span_to_range,
+};
+
+pub fn on_code_action(
+    state: &mut LSPState,
+    params: <CodeActionRequest as Request>::Params,
+) -> LSPRuntimeResult<<CodeActionRequest as Request>::Result> {
+    let CodeActionParams {
+        text_document,
+        range,
+        ..
+    } = params;
+
+    let code_actions = state
+        .quick_fixes
+        .get(&text_document.uri)
+        .into_iter()
+        .flatten()
+        .filter(|code_action| {
+            code_action
+                .diagnostics
+                .iter()
+                .flatten()
+                .any(|diagnostic| ranges_intersect(diagnostic.range, range))
+        })
+        .cloned()
+        .map(CodeActionOrCommand::CodeAction)
+        .collect::<Vec<_>>();
+
+    if code_actions.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(code_actions))
+}
+
+/// A fix for a compiler error, which can be offered as a code action.
+pub(crate) enum QuickFix {
+    ExportIsoLiteral {
+        suggested_const_export_name: ScalarFieldName,
+    },
+    AddParenthesesAroundIsoLiteral,
+    AddAssociatedJsFunction,
+    AddMissingArguments {
+        missing_arguments: Vec<UnvalidatedVariableDefinition>,
+    },
+    RemoveUnusedVariables {
+        /// The spans of the unused variable definitions, relative to the iso literal.
+        unused_variable_spans: Vec<Span>,
+    },
+    CreateClientField {
+        parent_type_name: IsographObjectTypeName,
+        field_name: SelectableFieldName,
+    },
+}
+
+pub(crate) fn parse_error_quick_fix(error: &IsographLiteralParseError) -> Option<QuickFix> {
+    match error {
+        IsographLiteralParseError::ExpectedLiteralToBeExported {
+            suggested_const_export_name,
+        } => Some(QuickFix::ExportIsoLiteral {
+            suggested_const_export_name: *suggested_const_export_name,
+        }),
+        IsographLiteralParseError::ExpectedParenthesesAroundIsoLiteral => {
+            Some(QuickFix::AddParenthesesAroundIsoLiteral)
+        }
+        IsographLiteralParseError::ExpectedAssociatedJsFunction => {
+            Some(QuickFix::AddAssociatedJsFunction)
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn validation_error_quick_fix(error: &ValidateSchemaError) -> Option<QuickFix> {
+    match error {
+        ValidateSchemaError::MissingArguments { missing_arguments } => {
+            Some(QuickFix::AddMissingArguments {
+                missing_arguments: missing_arguments.clone(),
+            })
+        }
+        ValidateSchemaError::UnusedVariables {
+            unused_variables, ..
+        } => Some(QuickFix::RemoveUnusedVariables {
+            unused_variable_spans: unused_variables
+                .iter()
+                .map(|variable| variable.span)
+                .collect(),
+        }),
+        ValidateSchemaError::ClientFieldSelectionFieldDoesNotExist {
+            field_parent_type_name,
+            field_name,
+            ..
+        } => Some(QuickFix::CreateClientField {
+            parent_type_name: *field_parent_type_name,
+            field_name: *field_name,
+        }),
+        _ => None,
+    }
+}
+
+/// Create a code action that applies a quick fix for `diagnostic`, which was
+/// reported at `location`. Returns None if the fix cannot be applied to the
+/// current contents of the file.
+pub(crate) fn quick_fix_code_action(
+    state: &LSPState,
+    uri: &Url,
+    location: EmbeddedLocation,
+    quick_fix: QuickFix,
+    diagnostic: Diagnostic,
+) -> Option<CodeAction> {
+    let file_text = state.text_for_or_read(uri)?;
+    let literal_span = location.text_source.span?;
+    let literal_start = literal_span.start as usize;
+    let literal_end = literal_span.end as usize;
+    // The location may be stale, if the file changed since it was compiled
+    if literal_end >= file_text.len() {
+        return None;
+    }
+
+    // Creating a client field is rarely what was intended, e.g. if the field
+    // name contains a typo.
+    let is_preferred = !matches!(quick_fix, QuickFix::CreateClientField { .. });
+    let (title, edits) = match quick_fix {
+        QuickFix::ExportIsoLiteral {
+            suggested_const_export_name,
+        } => {
+            let iso_start = file_text[..literal_start].rfind("iso")?;
+            let before_iso = file_text[..iso_start].trim_end();
+            let edit = match before_iso
+                .strip_suffix('=')
+                .map(|before_equals| before_equals.trim_end())
+                .and_then(|before_equals| {
+                    let name_start = before_equals
+                        .rfind(|char: char| !(char.is_alphanumeric() || char == '_'))?
+                        + 1;
+                    before_equals[..name_start]
+                        .strip_suffix("const ")
+                        .map(|before_const| before_const.len())
+                }) {
+                // The literal is assigned to a const, which is not exported
+                Some(const_start) => insertion(&file_text, const_start, "export ".to_string()),
+                None => insertion(
+                    &file_text,
+                    iso_start,
+                    format!("export const {} = ", suggested_const_export_name),
+                ),
+            };
+            (
+                format!(
+                    "Export the iso literal as `{}`",
+                    suggested_const_export_name
+                ),
+                vec![edit],
+            )
+        }
+        QuickFix::AddParenthesesAroundIsoLiteral => (
+            "Add parentheses around the iso literal".to_string(),
+            vec![
+                insertion(&file_text, literal_start.checked_sub(1)?, "(".to_string()),
+                insertion(&file_text, literal_end + 1, ")".to_string()),
+            ],
+        ),
+        QuickFix::AddAssociatedJsFunction => {
+            // The literal is followed by a backtick and a closing parenthesis
+            let call_end = literal_end + file_text[literal_end..].find(')')? + 1;
+            (
+                "Add a resolver function".to_string(),
+                vec![insertion(
+                    &file_text,
+                    call_end,
+                    "(({ data }) => data)".to_string(),
+                )],
+            )
+        }
+        QuickFix::AddMissingArguments { missing_arguments } => {
+            let iso_literals = state.iso_literals_for_or_parse(uri, &file_text);
+            let edits = add_missing_arguments(
+                &file_text,
+                client_field_declaration(&iso_literals, literal_start)?,
+                literal_start,
+                literal_start + location.span.end as usize,
+                &missing_arguments,
+            )?;
+            (
+                format!(
+                    "Add missing arguments: {}",
+                    missing_arguments
+                        .iter()
+                        .map(|argument| format!("${}", argument.name.item))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                edits,
+            )
+        }
+        QuickFix::RemoveUnusedVariables {
+            unused_variable_spans,
+        } => {
+            let iso_literals = state.iso_literals_for_or_parse(uri, &file_text);
+            let edit = remove_variable_definitions(
+                &file_text,
+                client_field_declaration(&iso_literals, literal_start)?,
+                literal_start,
+                &unused_variable_spans,
+            )?;
+            (
+                if unused_variable_spans.len() == 1 {
+                    "Remove unused variable".to_string()
+                } else {
+                    "Remove unused variables".to_string()
+                },
+                vec![edit],
+            )
+        }
+        QuickFix::CreateClientField {
+            parent_type_name,
+            field_name,
+        } => (
+            format!("Create client field `{}.{}`", parent_type_name, field_name),
+            vec![insertion(
+                &file_text,
+                file_text.len(),
+                format!(
+                    "\nexport const {field_name} = iso(`\n  field {parent_type_name}.{field_name} {{\n  }}\n`)(({{ data }}) => data);\n"
+                ),
+            )],
+        ),
+    };
+
+    Some(CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diagnostic]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), edits)])),
+            ..Default::default()
+        }),
+        is_preferred: Some(is_preferred),
+        ..Default::default()
+    })
+}
+
+/// The client field declared in the iso literal starting at `literal_start`.
+fn client_field_declaration(
+    iso_literals: &[(IsoLiteralExtractionResult, TextSource)],
+    literal_start: usize,
+) -> Option<&ClientFieldDeclarationWithUnvalidatedDirectives> {
+    iso_literals
+        .iter()
+        .find_map(|(extraction_result, text_source)| match extraction_result {
+            IsoLiteralExtractionResult::ClientFieldDeclaration(client_field_declaration)
+                if text_source.span?.start as usize == literal_start =>
+            {
+                Some(&client_field_declaration.item)
+            }
+            _ => None,
+        })
+}
+
+/// Pass each missing argument to the field whose name ends at `field_name_end`
+/// as a variable of the same name, and declare the variables that the client
+/// field does not already declare.
+fn add_missing_arguments(
+    file_text: &str,
+    client_field_declaration: &ClientFieldDeclarationWithUnvalidatedDirectives,
+    literal_start: usize,
+    field_name_end: usize,
+    missing_arguments: &[UnvalidatedVariableDefinition],
+) -> Option<Vec<TextEdit>> {
+    let arguments = missing_arguments
+        .iter()
+        .map(|argument| format!("{}: ${}", argument.name.item, argument.name.item))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let after_name = &file_text[field_name_end..];
+    let arguments_edit = if after_name.trim_start().starts_with('(') {
+        let open_paren = field_name_end + after_name.find('(')?;
+        let close_paren = open_paren + file_text[open_paren..].find(')')?;
+        let existing_arguments = file_text[open_paren + 1..close_paren].trim_end();
+        if existing_arguments.trim_start().is_empty() {
+            insertion(file_text, open_paren + 1, arguments)
+        } else {
+            insertion(
+                file_text,
+                open_paren + 1 + existing_arguments.len(),
+                format!(", {}", arguments),
+            )
+        }
+    } else {
+        insertion(file_text, field_name_end, format!("({})", arguments))
+    };
+
+    let variable_definitions = &client_field_declaration.variable_definitions;
+    let undeclared_variables = missing_arguments
+        .iter()
+        .filter(|argument| {
+            !variable_definitions
+                .iter()
+                .any(|variable_definition| variable_definition.item.name.item == argument.name.item)
+        })
+        .map(print_variable_definition)
+        .collect::<Vec<_>>();
+    if undeclared_variables.is_empty() {
+        return Some(vec![arguments_edit]);
+    }
+    let undeclared_variables = undeclared_variables.join(", ");
+    let variables_edit = match variable_definitions.last() {
+        Some(last_variable_definition) => insertion(
+            file_text,
+            literal_start + last_variable_definition.span.end as usize,
+            format!(", {}", undeclared_variables),
+        ),
+        None => insertion(
+            file_text,
+            literal_start + client_field_declaration.client_field_name.span.end as usize,
+            format!("({})", undeclared_variables),
+        ),
+    };
+
+    Some(vec![variables_edit, arguments_edit])
+}
+
+fn print_variable_definition(
+    variable_definition: &VariableDefinition<UnvalidatedTypeName>,
+) -> String {
+    format!(
+        "${}: {}",
+        variable_definition.name.item, variable_definition.type_
+    )
+}
+
+/// Replace the variable definitions of the client field declared in the iso
+/// literal starting at `literal_start` with the ones that are used. If none are
+/// used, the parentheses are removed as well.
+fn remove_variable_definitions(
+    file_text: &str,
+    client_field_declaration: &ClientFieldDeclarationWithUnvalidatedDirectives,
+    literal_start: usize,
+    unused_variable_spans: &[Span],
+) -> Option<TextEdit> {
+    let variable_spans = client_field_declaration
+        .variable_definitions
+        .iter()
+        .map(|variable_definition| variable_definition.span)
+        .collect::<Vec<_>>();
+    let first_start = literal_start + variable_spans.first()?.start as usize;
+    let last_end = literal_start + variable_spans.last()?.end as usize;
+
+    let used_variables = variable_spans
+        .iter()
+        .filter(|span| !unused_variable_spans.contains(span))
+        .map(|span| {
+            &file_text[literal_start + span.start as usize..literal_start + span.end as usize]
+        })
+        .collect::<Vec<_>>();
+
+    if used_variables.is_empty() {
+        let open_paren = file_text[..first_start].rfind('(')?;
+        let close_paren = last_end + file_text[last_end..].find(')')?;
+        Some(TextEdit {
+            range: span_to_range(
+                file_text,
+                Span::new(open_paren as u32, close_paren as u32 + 1),
+            ),
+            new_text: String::new(),
+        })
+    } else {
+        Some(TextEdit {
+            range: span_to_range(file_text, Span::new(first_start as u32, last_end as u32)),
+            new_text: used_variables.join(", "),
+        })
+    }
+}
+
+fn insertion(file_text: &str, index: usize, new_text: String) -> TextEdit {
+    let index = index as u32;
+    TextEdit {
+        range: span_to_range(file_text, Span::new(index, index)),
+        new_text,
+    }
+}
+
+fn ranges_intersect(a: Range, b: Range) -> bool {
+    a.start <= b.end && b.start <= a.end
+}
+
+#[cfg(test)]
+mod test {
+    use common_lang_types::Span;
+    use isograph_schema::UnvalidatedVariableDefinition;
+    use lsp_types::{TextDocumentContentChangeEvent, TextEdit};
+
+    use super::{add_missing_arguments, client_field_declaration, remove_variable_definitions};
+    use crate::{iso_literals::parse_iso_literals_in_file, row_col_offset::apply_content_change};
+
+    fn apply_edits(file_text: &str, edits: Vec<TextEdit>) -> String {
+        let mut file_text = file_text.to_string();
+        // Edits do not overlap, so applying them back to front keeps the ranges
+        // of the remaining edits valid.
+        for edit in edits.into_iter().rev() {
+            apply_content_change(
+                &mut file_text,
+                &TextDocumentContentChangeEvent {
+                    range: Some(edit.range),
+                    range_length: None,
+                    text: edit.new_text,
+                },
+            );
+        }
+        file_text
+    }
+
+    fn literal_start(file_text: &str) -> usize {
+        file_text.find("field").unwrap()
+    }
+
+    /// The arguments of the `Query.node` field, i.e. `$id: ID!`, and of
+    /// `User.friends`, i.e. `$first: Int`.
+    fn missing_arguments(names: &[&str]) -> Vec<UnvalidatedVariableDefinition> {
+        let file_text = "export const Arguments = iso(`field Query.Arguments($id: ID!, $first: Int) {}`)(() => {});";
+        let iso_literals =
+            parse_iso_literals_in_file("Arguments.ts", file_text).collect::<Vec<_>>();
+        let client_field_declaration =
+            client_field_declaration(&iso_literals, literal_start(file_text)).unwrap();
+        client_field_declaration
+            .variable_definitions
+            .iter()
+            .map(|variable_definition| variable_definition.item.clone())
+            .filter(|variable_definition| {
+                names.contains(&variable_definition.name.item.to_string().as_str())
+            })
+            .collect()
+    }
+
+    fn add_arguments(file_text: &str, field_name: &str, names: &[&str]) -> String {
+        let iso_literals =
+            parse_iso_literals_in_file("Component.ts", file_text).collect::<Vec<_>>();
+        let literal_start = literal_start(file_text);
+        let field_name_end = file_text.find(field_name).unwrap() + field_name.len();
+        let edits = add_missing_arguments(
+            file_text,
+            client_field_declaration(&iso_literals, literal_start).unwrap(),
+            literal_start,
+            field_name_end,
+            &missing_arguments(names),
+        )
+        .unwrap();
+        apply_edits(file_text, edits)
+    }
+
+    #[test]
+    fn missing_arguments_are_added_and_declared() {
+        assert_eq!(
+            add_arguments(
+                "export const Component = iso(`field Query.Component { node { id } }`)(() => {});",
+                "node",
+                &["id"]
+            ),
+            "export const Component = iso(`field Query.Component($id: ID!) { node(id: $id) { id } }`)(() => {});"
+        );
+    }
+
+    #[test]
+    fn missing_arguments_are_appended_to_existing_arguments_and_variables() {
+        assert_eq!(
+            add_arguments(
+                "export const Component = iso(`field User.Component($id: ID!) { friends(after: $id) { id } }`)(() => {});",
+                "friends",
+                &["first"]
+            ),
+            "export const Component = iso(`field User.Component($id: ID!, $first: Int) { \
+            friends(after: $id, first: $first) { id } }`)(() => {});"
+        );
+    }
+
+    #[test]
+    fn declared_variables_are_not_declared_again() {
+        assert_eq!(
+            add_arguments(
+                "export const Component = iso(`field Query.Component($id: ID!) { node() { id } }`)(() => {});",
+                "node",
+                &["id"]
+            ),
+            "export const Component = iso(`field Query.Component($id: ID!) { node(id: $id) { id } }`)(() => {});"
+        );
+    }
+
+    #[test]
+    fn unused_variables_are_removed() {
+        let file_text =
+            "export const Component = iso(`field Query.Component($id: ID!, $first: Int) { node(id: $id) { id } }`)(() => {});";
+        let iso_literals =
+            parse_iso_literals_in_file("Component.ts", file_text).collect::<Vec<_>>();
+        let literal_start = literal_start(file_text);
+        let client_field_declaration =
+            client_field_declaration(&iso_literals, literal_start).unwrap();
+        let variable_span =
+            |index: usize| -> Span { client_field_declaration.variable_definitions[index].span };
+
+        let edit = remove_variable_definitions(
+            file_text,
+            client_field_declaration,
+            literal_start,
+            &[variable_span(1)],
+        )
+        .unwrap();
+        assert_eq!(
+            apply_edits(file_text, vec![edit]),
+            "export const Component = iso(`field Query.Component($id: ID!) { node(id: $id) { id } }`)(() => {});"
+        );
+
+        let edit = remove_variable_definitions(
+            file_text,
+            client_field_declaration,
+            literal_start,
+            &[variable_span(0), variable_span(1)],
+        )
+        .unwrap();
+        assert_eq!(
+            apply_edits(file_text, vec![edit]),
+            "export const Component = iso(`field Query.Component { node(id: $id) { id } }`)(() => {});"
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use isograph_lang_parser::IsoLiteralExtractionResult;
+use isograph_lang_types::ClientFieldId;
+use isograph_schema::{
+    create_merged_selection_map_for_field_and_insert_into_global_map, ClientFieldVariant,
+    ClientType, FieldType, ValidatedSchema,
+};
+use lsp_types::{
+    request::{CodeLensRequest, Request},
+    CodeLens, CodeLensParams, Command,
+};
+
+use crate::{
+    iso_literals::find_client_field, lsp_runtime_error::LSPRuntimeResult, lsp_state::LSPState,
+    row_col_offset::span_to_range,
+};
+
+/// Show how each client field declared in a document is used, e.g. "used by 3
+/// client fields · reachable from 2 entrypoints", and how many client fields
+/// each entrypoint reaches.
+pub fn on_code_lens(
+    state: &mut LSPState,
+    params: <CodeLensRequest as Request>::Params,
+) -> LSPRuntimeResult<<CodeLensRequest as Request>::Result> {
+    let CodeLensParams { text_document, .. } = params;
+
+    let schema = match &state.validated_schema {
+        Some(schema) => schema,
+        None => return Ok(None),
+    };
+    let file_text = match state.text_for_or_read(&text_document.uri) {
+        Some(file_text) => file_text,
+        None => return Ok(None),
+    };
+    let usages = match state.client_field_usages() {
+        Some(usages) => usages,
+        None => return Ok(None),
+    };
+
+    let mut code_lenses = vec![];
+    for (extraction_result, text_source) in state
+        .iso_literals_for_or_parse(&text_document.uri, &file_text)
+        .iter()
+    {
+        let literal_start = match text_source.span {
+            Some(span) => span.start,
+            None => continue,
+        };
+        let (parent_type, client_field_name, is_entrypoint) = match extraction_result {
+            IsoLiteralExtractionResult::ClientFieldDeclaration(client_field_declaration) => (
+                client_field_declaration.item.parent_type.item,
+                client_field_declaration.item.client_field_name,
+                false,
+            ),
+            IsoLiteralExtractionResult::EntrypointDeclaration(entrypoint_declaration) => (
+                entrypoint_declaration.item.parent_type.item,
+                entrypoint_declaration.item.client_field_name,
+                true,
+            ),
+            IsoLiteralExtractionResult::ClientPointerDeclaration(_) => continue,
+        };
+        let client_field_id =
+            match find_client_field(schema, parent_type, client_field_name.item.into()) {
+                Some(client_field_id) => client_field_id,
+                None => continue,
+            };
+
+        let title = if is_entrypoint {
+            pluralize(
+                "reaches",
+                usages.reached_by_entrypoint(client_field_id),
+                "client field",
+            )
+        } else {
+            format!(
+                "{} · {}",
+                pluralize("used by", usages.used_by(client_field_id), "client field"),
+                pluralize(
+                    "reachable from",
+                    usages.reachable_from(client_field_id),
+                    "entrypoint"
+                ),
+            )
+        };
+        code_lenses.push(CodeLens {
+            range: span_to_range(
+                &file_text,
+                client_field_name.span.with_offset(literal_start),
+            ),
+            command: Some(Command::new(title, String::new(), None)),
+            data: None,
+        });
+    }
+
+    Ok(Some(code_lenses))
+}
+
+/// For each client field, the client fields that select it, and the entrypoints
+/// from which it can be reached.
+#[derive(Default)]
+pub(crate) struct ClientFieldUsages {
+    used_by: HashMap<ClientFieldId, BTreeSet<ClientFieldId>>,
+    reachable_from: HashMap<ClientFieldId, BTreeSet<ClientFieldId>>,
+}
+
+impl ClientFieldUsages {
+    fn used_by(&self, client_field_id: ClientFieldId) -> usize {
+        self.used_by
+            .get(&client_field_id)
+            .map(|used_by| used_by.len())
+            .unwrap_or(0)
+    }
+
+    fn reachable_from(&self, client_field_id: ClientFieldId) -> usize {
+        self.reachable_from
+            .get(&client_field_id)
+            .map(|reachable_from| reachable_from.len())
+            .unwrap_or(0)
+    }
+
+    /// The number of client fields (other than the entrypoint itself) that can
+    /// be reached from an entrypoint.
+    fn reached_by_entrypoint(&self, entrypoint_id: ClientFieldId) -> usize {
+        self.reachable_from
+            .iter()
+            .filter(|(client_field_id, entrypoint_ids)| {
+                **client_field_id != entrypoint_id && entrypoint_ids.contains(&entrypoint_id)
+            })
+            .count()
+    }
+}
+
+pub(crate) fn client_field_usages(schema: &ValidatedSchema) -> ClientFieldUsages {
+    let mut encountered_client_field_map = BTreeMap::new();
+
+    let mut entrypoints = schema.entrypoints.iter().copied().collect::<Vec<_>>();
+    entrypoints.sort();
+
+    // Entrypoints are traversed first, as they are when generating artifacts.
+    // Then, every other user-written client field is traversed, so that client
+    // fields that are only selected by unreachable client fields are counted as
+    // well. Client fields that were already encountered are not traversed again.
+    let user_written_client_fields =
+        schema
+            .client_fields
+            .iter()
+            .filter_map(|client_field| match client_field {
+                ClientType::ClientField(client_field) => match client_field.variant {
+                    ClientFieldVariant::UserWritten(_) => Some(client_field.id),
+                    ClientFieldVariant::ImperativelyLoadedField(_) => None,
+                },
+            });
+    for client_field_id in entrypoints
+        .iter()
+        .copied()
+        .chain(user_written_client_fields)
+    {
+        let client_field = schema.client_field(client_field_id);
+        create_merged_selection_map_for_field_and_insert_into_global_map(
+            schema,
+            schema
+                .server_field_data
+                .object(client_field.parent_object_id),
+            client_field.selection_set_for_parent_query(),
+            &mut encountered_client_field_map,
+            FieldType::ClientField(client_field_id),
+            &client_field.initial_variable_context(),
+        );
+    }
+
+    let mut usages = ClientFieldUsages::default();
+    for (field_id, traversal_result) in encountered_client_field_map.iter() {
+        if let FieldType::ClientField(client_field_id) = field_id {
+            for accessed_client_field_id in traversal_result
+                .traversal_state
+                .accessible_client_fields
+                .iter()
+            {
+                usages
+                    .used_by
+                    .entry(*accessed_client_field_id)
+                    .or_default()
+                    .insert(*client_field_id);
+            }
+        }
+    }
+
+    for entrypoint_id in entrypoints {
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![entrypoint_id];
+        while let Some(client_field_id) = to_visit.pop() {
+            if !visited.insert(client_field_id) {
+                continue;
+            }
+            usages
+                .reachable_from
+                .entry(client_field_id)
+                .or_default()
+                .insert(entrypoint_id);
+            if let Some(traversal_result) =
+                encountered_client_field_map.get(&FieldType::ClientField(client_field_id))
+            {
+                to_visit.extend(
+                    traversal_result
+                        .traversal_state
+                        .accessible_client_fields
+                        .iter()
+                        .copied(),
+                );
+            }
+        }
+    }
+
+    usages
+}
+
+fn pluralize(prefix: &str, count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{} 1 {}", prefix, noun)
+    } else {
+        format!("{} {} {}s", prefix, count, noun)
+    }
+}
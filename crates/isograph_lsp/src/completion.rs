@@ -0,0 +1,354 @@
+use common_lang_types::{SelectableFieldName, UnvalidatedTypeName, VariableName};
+use intern::Lookup;
+use isograph_compiler::{
+    extract_iso_literals_from_file_content, known_directive_names, IsoLiteralExtraction,
+};
+use isograph_lang_parser::{
+    parse_partial_iso_literal, PartialIsoLiteral, PartialIsoLiteralPosition,
+};
+use isograph_lang_types::{
+    ClientFieldId, IsographDirectiveLocation, SelectableServerFieldId, SelectionType,
+    ServerObjectId, VariableDefinition,
+};
+use isograph_schema::{get_name, ClientType, FieldType, ValidatedSchema};
+use lsp_types::{
+    request::{Completion, Request},
+    CompletionItem, CompletionItemKind, CompletionParams, CompletionResponse, Documentation,
+    TextDocumentPositionParams,
+};
+
+use crate::{
+    lsp_runtime_error::LSPRuntimeResult,
+    lsp_state::LSPState,
+    row_col_offset::get_index_from_row_col,
+    schema_text::{print_server_field_type, print_variable_definitions},
+};
+
+pub fn on_completion(
+    state: &mut LSPState,
+    params: <Completion as Request>::Params,
+) -> LSPRuntimeResult<<Completion as Request>::Result> {
+    let CompletionParams {
+        text_document_position:
+            TextDocumentPositionParams {
+                text_document,
+                position,
+            },
+        ..
+    } = params;
+
+    let schema = match &state.validated_schema {
+        Some(schema) => schema,
+        None => return Ok(None),
+    };
+    let file_text = match state.text_for(&text_document.uri) {
+        Some(file_text) => file_text,
+        None => return Ok(None),
+    };
+    let index = get_index_from_row_col(file_text, position.line, position.character);
+
+    // The iso literal is likely incomplete, so we parse it up to the cursor,
+    // recovering at the cursor.
+    let partial_iso_literal =
+        match extract_iso_literals_from_file_content(file_text).find_map(|literal_extraction| {
+            let IsoLiteralExtraction {
+                iso_literal_text,
+                iso_literal_start_index,
+                ..
+            } = literal_extraction;
+            let iso_literal_end_index = iso_literal_start_index + iso_literal_text.len();
+            if iso_literal_start_index <= index && index <= iso_literal_end_index {
+                Some(parse_partial_iso_literal(
+                    iso_literal_text,
+                    index - iso_literal_start_index,
+                ))
+            } else {
+                None
+            }
+        }) {
+            Some(partial_iso_literal) => partial_iso_literal,
+            None => return Ok(None),
+        };
+
+    let completion_items = completion_items(schema, partial_iso_literal);
+    if completion_items.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(CompletionResponse::Array(completion_items)))
+}
+
+fn completion_items(
+    schema: &ValidatedSchema,
+    partial_iso_literal: PartialIsoLiteral,
+) -> Vec<CompletionItem> {
+    let PartialIsoLiteral {
+        parent_type,
+        variable_names,
+        position,
+    } = partial_iso_literal;
+
+    match position {
+        PartialIsoLiteralPosition::ParentType => schema
+            .server_field_data
+            .server_objects
+            .iter()
+            .map(|object| CompletionItem {
+                label: object.name.to_string(),
+                kind: Some(CompletionItemKind::CLASS),
+                documentation: object
+                    .description
+                    .map(|description| Documentation::String(description.to_string())),
+                ..Default::default()
+            })
+            .collect(),
+        PartialIsoLiteralPosition::EntrypointClientFieldName => {
+            let object_id = match parent_type.and_then(|parent_type| object_id(schema, parent_type))
+            {
+                Some(object_id) => object_id,
+                None => return vec![],
+            };
+            schema
+                .server_field_data
+                .object(object_id)
+                .encountered_fields
+                .iter()
+                .filter_map(|(name, field)| match field {
+                    FieldType::ClientField(ClientType::ClientField(client_field_id)) => Some(
+                        client_field_completion_item(schema, *name, *client_field_id),
+                    ),
+                    FieldType::ServerField(_) => None,
+                })
+                .collect()
+        }
+        PartialIsoLiteralPosition::SelectionName { path } => {
+            let object_id = match resolve_path(schema, parent_type, &path) {
+                Some(object_id) => object_id,
+                None => return vec![],
+            };
+            schema
+                .server_field_data
+                .object(object_id)
+                .encountered_fields
+                .iter()
+                .map(|(name, field)| match field {
+                    FieldType::ServerField(server_field_id) => {
+                        let server_field = schema.server_field(*server_field_id);
+                        CompletionItem {
+                            label: name.to_string(),
+                            kind: Some(CompletionItemKind::FIELD),
+                            detail: Some(print_server_field_type(schema, server_field)),
+                            documentation: server_field
+                                .description
+                                .map(|description| Documentation::String(description.to_string())),
+                            deprecated: server_field.deprecation_reason.map(|_| true),
+                            ..Default::default()
+                        }
+                    }
+                    FieldType::ClientField(ClientType::ClientField(client_field_id)) => {
+                        client_field_completion_item(schema, *name, *client_field_id)
+                    }
+                })
+                .collect()
+        }
+        PartialIsoLiteralPosition::ArgumentName { path, field_name } => {
+            arguments(schema, parent_type, &path, field_name)
+                .into_iter()
+                .map(|argument| CompletionItem {
+                    label: argument.name.item.to_string(),
+                    kind: Some(CompletionItemKind::PROPERTY),
+                    detail: Some(
+                        argument
+                            .type_
+                            .clone()
+                            .map(|type_id| {
+                                schema.server_field_data.lookup_unvalidated_type(type_id)
+                            })
+                            .map(get_name)
+                            .to_string(),
+                    ),
+                    ..Default::default()
+                })
+                .collect()
+        }
+        PartialIsoLiteralPosition::ArgumentValue {
+            path,
+            field_name,
+            argument_name,
+        } => {
+            let mut completion_items = variable_completion_items(&variable_names, true);
+            let argument = argument_name.and_then(|argument_name| {
+                arguments(schema, parent_type, &path, field_name)
+                    .into_iter()
+                    .find(|argument| argument.name.item.lookup() == argument_name.lookup())
+            });
+            if let Some(argument) = argument {
+                if let SelectableServerFieldId::Scalar(scalar_id) = argument.type_.inner() {
+                    if *scalar_id == schema.boolean_type_id {
+                        completion_items.extend(["true", "false"].into_iter().map(|value| {
+                            CompletionItem {
+                                label: value.to_string(),
+                                kind: Some(CompletionItemKind::VALUE),
+                                ..Default::default()
+                            }
+                        }));
+                    }
+                    let scalar = schema.server_field_data.scalar(*scalar_id);
+                    completion_items.extend(scalar.enum_values.iter().map(|enum_value| {
+                        CompletionItem {
+                            label: enum_value.to_string(),
+                            kind: Some(CompletionItemKind::ENUM_MEMBER),
+                            deprecated: scalar.deprecated_enum_values.get(enum_value).map(|_| true),
+                            ..Default::default()
+                        }
+                    }));
+                }
+            }
+            completion_items
+        }
+        PartialIsoLiteralPosition::Variable => variable_completion_items(&variable_names, false),
+        PartialIsoLiteralPosition::DirectiveName {
+            on_client_field_declaration,
+        } => {
+            // When the directive name is being written, we cannot know whether the
+            // selection is a scalar or linked field.
+            let locations: &[IsographDirectiveLocation] = if on_client_field_declaration {
+                &[IsographDirectiveLocation::ClientFieldDeclaration]
+            } else {
+                &[
+                    IsographDirectiveLocation::ScalarFieldSelection,
+                    IsographDirectiveLocation::LinkedFieldSelection,
+                ]
+            };
+            let mut directive_names = locations
+                .iter()
+                .flat_map(|location| known_directive_names(*location))
+                .collect::<Vec<_>>();
+            directive_names.sort();
+            directive_names.dedup();
+            directive_names
+                .into_iter()
+                .map(|directive_name| CompletionItem {
+                    label: directive_name.to_string(),
+                    kind: Some(CompletionItemKind::KEYWORD),
+                    ..Default::default()
+                })
+                .collect()
+        }
+        PartialIsoLiteralPosition::Other => vec![],
+    }
+}
+
+fn client_field_completion_item(
+    schema: &ValidatedSchema,
+    name: SelectableFieldName,
+    client_field_id: ClientFieldId,
+) -> CompletionItem {
+    let client_field = schema.client_field(client_field_id);
+    CompletionItem {
+        label: name.to_string(),
+        kind: Some(CompletionItemKind::FUNCTION),
+        detail: Some(format!(
+            "Client field{}",
+            print_variable_definitions(
+                schema,
+                client_field
+                    .variable_definitions
+                    .iter()
+                    .map(|variable_definition| &variable_definition.item),
+            )
+        )),
+        documentation: client_field
+            .description
+            .map(|description| Documentation::String(description.to_string())),
+        ..Default::default()
+    }
+}
+
+/// Completion items for the variables declared by the client field. If
+/// `include_dollar` is false, the `$` has already been written.
+fn variable_completion_items(
+    variable_names: &[VariableName],
+    include_dollar: bool,
+) -> Vec<CompletionItem> {
+    variable_names
+        .iter()
+        .map(|variable_name| CompletionItem {
+            label: format!("${}", variable_name),
+            kind: Some(CompletionItemKind::VARIABLE),
+            insert_text: Some(if include_dollar {
+                format!("${}", variable_name)
+            } else {
+                variable_name.to_string()
+            }),
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn object_id(schema: &ValidatedSchema, type_name: UnvalidatedTypeName) -> Option<ServerObjectId> {
+    match schema.server_field_data.defined_types.get(&type_name)? {
+        SelectionType::Object(object_id) => Some(*object_id),
+        SelectionType::Scalar(_) => None,
+    }
+}
+
+/// Find the object selected by the linked fields in `path`, starting from the
+/// parent type of the client field.
+fn resolve_path(
+    schema: &ValidatedSchema,
+    parent_type: Option<UnvalidatedTypeName>,
+    path: &[SelectableFieldName],
+) -> Option<ServerObjectId> {
+    let mut object_id = object_id(schema, parent_type?)?;
+    for field_name in path {
+        object_id = match schema
+            .server_field_data
+            .object(object_id)
+            .encountered_fields
+            .get(field_name)?
+        {
+            FieldType::ServerField(server_field_id) => {
+                match &schema.server_field(*server_field_id).associated_data {
+                    SelectionType::Object(associated_data) => associated_data.type_name.inner(),
+                    SelectionType::Scalar(_) => return None,
+                }
+            }
+            FieldType::ClientField(_) => return None,
+        };
+    }
+    Some(object_id)
+}
+
+/// The arguments accepted by a selected field. For client fields, these are
+/// its variable definitions.
+fn arguments<'a>(
+    schema: &'a ValidatedSchema,
+    parent_type: Option<UnvalidatedTypeName>,
+    path: &[SelectableFieldName],
+    field_name: SelectableFieldName,
+) -> Vec<&'a VariableDefinition<SelectableServerFieldId>> {
+    let object_id = match resolve_path(schema, parent_type, path) {
+        Some(object_id) => object_id,
+        None => return vec![],
+    };
+    match schema
+        .server_field_data
+        .object(object_id)
+        .encountered_fields
+        .get(&field_name)
+    {
+        Some(FieldType::ServerField(server_field_id)) => schema
+            .server_field(*server_field_id)
+            .arguments
+            .iter()
+            .map(|argument| &argument.item)
+            .collect(),
+        Some(FieldType::ClientField(ClientType::ClientField(client_field_id))) => schema
+            .client_field(*client_field_id)
+            .variable_definitions
+            .iter()
+            .map(|variable_definition| &variable_definition.item)
+            .collect(),
+        None => vec![],
+    }
+}
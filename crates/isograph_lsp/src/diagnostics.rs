@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use common_lang_types::{EmbeddedLocation, Location};
+use isograph_compiler::batch_compile::BatchCompileError;
+use lsp_server::Message;
+use lsp_types::{
+    notification::{Notification, PublishDiagnostics},
+    CodeAction, Diagnostic, DiagnosticSeverity, PublishDiagnosticsParams, Range, Url,
+};
+
+use crate::{
+    code_actions::{
+        parse_error_quick_fix, quick_fix_code_action, validation_error_quick_fix, QuickFix,
+    },
+    location_utils::embedded_location_to_lsp_location,
+    lsp_state::LSPState,
+};
+
+/// Re-validate the project (using the contents of open documents instead of the
+/// contents on disk), and publish the resulting diagnostics. Diagnostics for
+/// documents that no longer have errors are cleared. Quick fixes for the errors
+/// are stored, so that they can be offered as code actions.
+pub(crate) fn recompile_and_publish_diagnostics(state: &mut LSPState) {
+    state.recompile_scheduled = false;
+    let mut warnings = vec![];
+    let mut errors = match state
+        .compiler_state
+        .validate_overlaid_source_files(&state.source_files, &mut warnings)
+    {
+        Ok(validated_schema) => {
+            state.set_validated_schema(validated_schema);
+            vec![]
+        }
+        Err(err) => flatten_batch_compile_error(err),
+    };
+    if let Some(config_error) = &state.config_error {
+        errors.push(FlattenedError {
+            location: None,
+            message: config_error.to_string(),
+            severity: DiagnosticSeverity::ERROR,
+            quick_fix: None,
+        });
+    }
+    errors.extend(warnings.into_iter().map(|warning| FlattenedError {
+        severity: DiagnosticSeverity::WARNING,
+        ..located_message(warning.location, warning.item)
+    }));
+
+    let mut diagnostics_by_uri: HashMap<Url, Vec<Diagnostic>> = HashMap::new();
+    let mut quick_fixes: HashMap<Url, Vec<CodeAction>> = HashMap::new();
+    for error in errors {
+        let FlattenedError {
+            location,
+            message,
+            severity,
+            quick_fix,
+        } = error;
+        let (uri, range) = match location {
+            Some(location) => match embedded_location_to_lsp_location(state, location) {
+                Some(location) => (location.uri, location.range),
+                None => continue,
+            },
+            // Errors without a location (e.g. a missing schema file) are reported
+            // on the config file, so that they are visible somewhere.
+            None => match Url::from_file_path(&state.compiler_state.config.config_location) {
+                Ok(uri) => (uri, Range::default()),
+                Err(_) => continue,
+            },
+        };
+        let diagnostic = Diagnostic {
+            range,
+            severity: Some(severity),
+            source: Some("isograph".to_string()),
+            message,
+            ..Default::default()
+        };
+        if let (Some(location), Some(quick_fix)) = (location, quick_fix) {
+            if let Some(code_action) =
+                quick_fix_code_action(state, &uri, location, quick_fix, diagnostic.clone())
+            {
+                quick_fixes
+                    .entry(uri.clone())
+                    .or_default()
+                    .push(code_action);
+            }
+        }
+        diagnostics_by_uri.entry(uri).or_default().push(diagnostic);
+    }
+    state.quick_fixes = quick_fixes;
+
+    let previous_documents_with_diagnostics = std::mem::take(&mut state.documents_with_diagnostics);
+    for uri in previous_documents_with_diagnostics {
+        if !diagnostics_by_uri.contains_key(&uri) {
+            publish_diagnostics(state, uri, vec![]);
+        }
+    }
+    for (uri, diagnostics) in diagnostics_by_uri {
+        state.documents_with_diagnostics.insert(uri.clone());
+        publish_diagnostics(state, uri, diagnostics);
+    }
+}
+
+fn publish_diagnostics(state: &LSPState, uri: Url, diagnostics: Vec<Diagnostic>) {
+    let params = PublishDiagnosticsParams {
+        uri,
+        diagnostics,
+        version: None,
+    };
+    state.send_message(Message::Notification(lsp_server::Notification::new(
+        PublishDiagnostics::METHOD.to_string(),
+        params,
+    )));
+}
+
+/// An error or warning, along with its location (if any) and a fix for it (if
+/// any).
+struct FlattenedError {
+    location: Option<EmbeddedLocation>,
+    message: String,
+    severity: DiagnosticSeverity,
+    quick_fix: Option<QuickFix>,
+}
+
+/// Flatten a BatchCompileError into a list of messages, along with their
+/// locations and quick fixes, if any.
+fn flatten_batch_compile_error(err: BatchCompileError) -> Vec<FlattenedError> {
+    match err {
+        BatchCompileError::UnableToParseSchema(e) => vec![located_message(e.location, e.item)],
+        BatchCompileError::UnableToParseIsographLiterals { messages } => messages
+            .into_iter()
+            .map(|e| {
+                let quick_fix = parse_error_quick_fix(&e.item);
+                FlattenedError {
+                    quick_fix,
+                    ..located_message(e.location, e.item)
+                }
+            })
+            .collect(),
+        BatchCompileError::UnableToCreateSchema(e) => vec![located_message(e.location, e.item)],
+        BatchCompileError::ErrorWhenProcessingClientFieldDeclaration { messages } => messages
+            .into_iter()
+            .map(|e| located_message(e.location, e.item))
+            .collect(),
+        BatchCompileError::ErrorWhenProcessingEntrypointDeclaration(e) => {
+            vec![located_message(e.location, e.item)]
+        }
+        BatchCompileError::UnableToValidateSchema { messages } => messages
+            .into_iter()
+            .map(|e| {
+                let quick_fix = validation_error_quick_fix(&e.item);
+                FlattenedError {
+                    quick_fix,
+                    ..located_message(e.location, e.item)
+                }
+            })
+            .collect(),
+        BatchCompileError::MultipleErrors { messages } => messages
+            .into_iter()
+            .flat_map(flatten_batch_compile_error)
+            .collect(),
+        err => vec![FlattenedError {
+            location: None,
+            message: err.to_string(),
+            severity: DiagnosticSeverity::ERROR,
+            quick_fix: None,
+        }],
+    }
+}
+
+fn located_message(location: Location, item: impl std::fmt::Display) -> FlattenedError {
+    let location = match location {
+        Location::Embedded(embedded_location) => Some(embedded_location),
+        Location::Generated => None,
+    };
+    FlattenedError {
+        location,
+        message: item.to_string(),
+        severity: DiagnosticSeverity::ERROR,
+        quick_fix: None,
+    }
+}
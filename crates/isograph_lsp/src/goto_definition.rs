@@ -0,0 +1,96 @@
+use isograph_lang_parser::IsoLiteralExtractionResult;
+use isograph_lang_types::SelectionType;
+use isograph_schema::FieldType;
+use lsp_types::{
+    request::{GotoDefinition, Request},
+    GotoDefinitionParams, GotoDefinitionResponse, TextDocumentPositionParams,
+};
+
+use crate::{
+    iso_literals::{
+        find_client_field, find_iso_literal_at_position, find_selected_field_at_offset,
+        span_contains,
+    },
+    location_utils::{
+        client_field_declaration_location, server_field_definition_location,
+        server_object_definition_location,
+    },
+    lsp_runtime_error::LSPRuntimeResult,
+    lsp_state::LSPState,
+};
+
+pub fn on_goto_definition(
+    state: &mut LSPState,
+    params: <GotoDefinition as Request>::Params,
+) -> LSPRuntimeResult<<GotoDefinition as Request>::Result> {
+    let GotoDefinitionParams {
+        text_document_position_params:
+            TextDocumentPositionParams {
+                text_document,
+                position,
+            },
+        work_done_progress_params: _,
+        partial_result_params: _,
+    } = params;
+
+    let schema = match &state.validated_schema {
+        Some(schema) => schema,
+        None => return Ok(None),
+    };
+    let iso_literal = match find_iso_literal_at_position(state, &text_document.uri, position) {
+        Some(iso_literal) => iso_literal,
+        None => return Ok(None),
+    };
+    let offset = iso_literal.offset;
+
+    let (parent_type, client_field_name) = match &iso_literal.extraction_result {
+        IsoLiteralExtractionResult::ClientFieldDeclaration(client_field_declaration) => {
+            let client_field_declaration = &client_field_declaration.item;
+            if !span_contains(client_field_declaration.parent_type.span, offset)
+                && !span_contains(client_field_declaration.client_field_name.span, offset)
+            {
+                let location =
+                    find_selected_field_at_offset(schema, client_field_declaration, offset)
+                        .and_then(|selected_field| match selected_field.field {
+                            FieldType::ServerField(server_field_id) => {
+                                server_field_definition_location(state, schema, server_field_id)
+                            }
+                            FieldType::ClientField(client_field_id) => {
+                                client_field_declaration_location(state, schema, client_field_id)
+                            }
+                        });
+                return Ok(location.map(GotoDefinitionResponse::Scalar));
+            }
+            (
+                client_field_declaration.parent_type,
+                client_field_declaration.client_field_name,
+            )
+        }
+        IsoLiteralExtractionResult::EntrypointDeclaration(entrypoint_declaration) => (
+            entrypoint_declaration.item.parent_type,
+            entrypoint_declaration.item.client_field_name,
+        ),
+        IsoLiteralExtractionResult::ClientPointerDeclaration(_) => return Ok(None),
+    };
+
+    let location = if span_contains(parent_type.span, offset) {
+        match schema
+            .server_field_data
+            .defined_types
+            .get(&parent_type.item)
+        {
+            Some(SelectionType::Object(object_id)) => {
+                server_object_definition_location(state, schema, *object_id)
+            }
+            _ => None,
+        }
+    } else if span_contains(client_field_name.span, offset) {
+        find_client_field(schema, parent_type.item, client_field_name.item.into()).and_then(
+            |client_field_id| client_field_declaration_location(state, schema, client_field_id),
+        )
+    } else {
+        None
+    };
+
+    Ok(location.map(GotoDefinitionResponse::Scalar))
+}
@@ -0,0 +1,164 @@
+use isograph_lang_parser::IsoLiteralExtractionResult;
+use isograph_lang_types::{ClientFieldId, ServerFieldId};
+use isograph_schema::{ClientFieldVariant, FieldType, UserWrittenClientFieldInfo, ValidatedSchema};
+use lsp_types::{
+    request::{HoverRequest, Request},
+    Hover, HoverContents, HoverParams, MarkupContent, MarkupKind, TextDocumentPositionParams,
+};
+
+use crate::{
+    iso_literals::{
+        find_client_field, find_iso_literal_at_position, find_selected_field_at_offset,
+        span_contains,
+    },
+    lsp_runtime_error::LSPRuntimeResult,
+    lsp_state::LSPState,
+    row_col_offset::span_to_range,
+    schema_text::{print_server_field_type, print_variable_definitions},
+};
+
+pub fn on_hover(
+    state: &mut LSPState,
+    params: <HoverRequest as Request>::Params,
+) -> LSPRuntimeResult<<HoverRequest as Request>::Result> {
+    let HoverParams {
+        text_document_position_params:
+            TextDocumentPositionParams {
+                text_document,
+                position,
+            },
+        work_done_progress_params: _,
+    } = params;
+
+    let schema = match &state.validated_schema {
+        Some(schema) => schema,
+        None => return Ok(None),
+    };
+    let iso_literal = match find_iso_literal_at_position(state, &text_document.uri, position) {
+        Some(iso_literal) => iso_literal,
+        None => return Ok(None),
+    };
+    let offset = iso_literal.offset;
+
+    let (field, span) = match &iso_literal.extraction_result {
+        IsoLiteralExtractionResult::ClientFieldDeclaration(client_field_declaration) => {
+            let client_field_declaration = &client_field_declaration.item;
+            let name_span = client_field_declaration.client_field_name.span;
+            if span_contains(name_span, offset) {
+                match find_client_field(
+                    schema,
+                    client_field_declaration.parent_type.item,
+                    client_field_declaration.client_field_name.item.into(),
+                ) {
+                    Some(client_field_id) => (FieldType::ClientField(client_field_id), name_span),
+                    None => return Ok(None),
+                }
+            } else {
+                match find_selected_field_at_offset(schema, client_field_declaration, offset) {
+                    Some(selected_field) => (selected_field.field, selected_field.name_span),
+                    None => return Ok(None),
+                }
+            }
+        }
+        IsoLiteralExtractionResult::EntrypointDeclaration(entrypoint_declaration) => {
+            let entrypoint_declaration = &entrypoint_declaration.item;
+            let name_span = entrypoint_declaration.client_field_name.span;
+            if !span_contains(name_span, offset) {
+                return Ok(None);
+            }
+            match find_client_field(
+                schema,
+                entrypoint_declaration.parent_type.item,
+                entrypoint_declaration.client_field_name.item.into(),
+            ) {
+                Some(client_field_id) => (FieldType::ClientField(client_field_id), name_span),
+                None => return Ok(None),
+            }
+        }
+        IsoLiteralExtractionResult::ClientPointerDeclaration(_) => return Ok(None),
+    };
+
+    let value = match field {
+        FieldType::ServerField(server_field_id) => server_field_hover_text(schema, server_field_id),
+        FieldType::ClientField(client_field_id) => client_field_hover_text(schema, client_field_id),
+    };
+
+    let literal_start = iso_literal
+        .text_source
+        .span
+        .map(|span| span.start)
+        .unwrap_or(0);
+    let range = state
+        .text_for(&text_document.uri)
+        .map(|file_text| span_to_range(file_text, span.with_offset(literal_start)));
+
+    Ok(Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        }),
+        range,
+    }))
+}
+
+fn server_field_hover_text(schema: &ValidatedSchema, server_field_id: ServerFieldId) -> String {
+    let server_field = schema.server_field(server_field_id);
+    let parent_object = schema.server_field_data.object(server_field.parent_type_id);
+    let arguments = print_variable_definitions(
+        schema,
+        server_field.arguments.iter().map(|argument| &argument.item),
+    );
+
+    let mut hover_text = format!(
+        "```graphql\n{}.{}{}: {}\n```",
+        parent_object.name,
+        server_field.name.item,
+        arguments,
+        print_server_field_type(schema, server_field)
+    );
+    if server_field.is_client_only {
+        hover_text.push_str("\n\nClient-only field.");
+    }
+    if let Some(description) = server_field.description {
+        hover_text.push_str(&format!("\n\n{}", description));
+    }
+    if let Some(deprecation_reason) = server_field.deprecation_reason {
+        hover_text.push_str(&format!("\n\n**Deprecated**: {}", deprecation_reason));
+    }
+    hover_text
+}
+
+fn client_field_hover_text(schema: &ValidatedSchema, client_field_id: ClientFieldId) -> String {
+    let client_field = schema.client_field(client_field_id);
+    let variable_definitions = print_variable_definitions(
+        schema,
+        client_field
+            .variable_definitions
+            .iter()
+            .map(|variable_definition| &variable_definition.item),
+    );
+
+    let mut hover_text = format!(
+        "```graphql\n{}.{}{}\n```",
+        client_field.type_and_field.type_name, client_field.name, variable_definitions
+    );
+    match &client_field.variant {
+        ClientFieldVariant::UserWritten(UserWrittenClientFieldInfo {
+            const_export_name,
+            file_path,
+            ..
+        }) => {
+            hover_text.push_str(&format!(
+                "\n\nClient field defined in `{}` (export `{}`).",
+                file_path, const_export_name
+            ));
+        }
+        ClientFieldVariant::ImperativelyLoadedField(_) => {
+            hover_text.push_str("\n\nClient field generated by Isograph.");
+        }
+    }
+    if let Some(description) = client_field.description {
+        hover_text.push_str(&format!("\n\n{}", description));
+    }
+    hover_text
+}
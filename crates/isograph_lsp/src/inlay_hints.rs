@@ -0,0 +1,98 @@
+use isograph_lang_parser::IsoLiteralExtractionResult;
+use isograph_schema::FieldType;
+use lsp_types::{
+    request::{InlayHintRequest, Request},
+    InlayHint, InlayHintKind, InlayHintLabel, InlayHintParams, Position, Range,
+};
+
+use crate::{
+    iso_literals::selected_fields, lsp_runtime_error::LSPRuntimeResult, lsp_state::LSPState,
+    row_col_offset::get_row_col_from_index, schema_text::print_server_field_type,
+};
+
+/// Show the type of each selected server field, e.g. `: String!` or
+/// `: [Pet!]!`, after its name and arguments.
+pub fn on_inlay_hint(
+    state: &mut LSPState,
+    params: <InlayHintRequest as Request>::Params,
+) -> LSPRuntimeResult<<InlayHintRequest as Request>::Result> {
+    let InlayHintParams {
+        text_document,
+        range,
+        ..
+    } = params;
+
+    let schema = match &state.validated_schema {
+        Some(schema) => schema,
+        None => return Ok(None),
+    };
+    let file_text = match state.text_for_or_read(&text_document.uri) {
+        Some(file_text) => file_text,
+        None => return Ok(None),
+    };
+
+    let mut inlay_hints = vec![];
+    for (extraction_result, text_source) in state
+        .iso_literals_for_or_parse(&text_document.uri, &file_text)
+        .iter()
+    {
+        let client_field_declaration = match extraction_result {
+            IsoLiteralExtractionResult::ClientFieldDeclaration(client_field_declaration) => {
+                &client_field_declaration.item
+            }
+            _ => continue,
+        };
+        let literal_start = match text_source.span {
+            Some(span) => span.start as usize,
+            None => continue,
+        };
+        for selected_field in selected_fields(schema, client_field_declaration) {
+            let server_field_id = match selected_field.field {
+                FieldType::ServerField(server_field_id) => server_field_id,
+                FieldType::ClientField(_) => continue,
+            };
+            let index = match end_of_name_and_arguments(
+                &file_text,
+                literal_start + selected_field.name_span.end as usize,
+            ) {
+                Some(index) => index,
+                None => continue,
+            };
+            let (line, character) = get_row_col_from_index(&file_text, index);
+            let position = Position::new(line, character);
+            if !range_contains(range, position) {
+                continue;
+            }
+            inlay_hints.push(InlayHint {
+                position,
+                label: InlayHintLabel::String(format!(
+                    ": {}",
+                    print_server_field_type(schema, schema.server_field(server_field_id))
+                )),
+                kind: Some(InlayHintKind::TYPE),
+                text_edits: None,
+                tooltip: None,
+                padding_left: None,
+                padding_right: None,
+                data: None,
+            });
+        }
+    }
+
+    Ok(Some(inlay_hints))
+}
+
+/// The index just after the arguments of the selection whose name ends at
+/// `name_end`, or `name_end` if the selection has no arguments.
+fn end_of_name_and_arguments(file_text: &str, name_end: usize) -> Option<usize> {
+    let after_name = file_text.get(name_end..)?;
+    if after_name.trim_start().starts_with('(') {
+        Some(name_end + after_name.find(')')? + 1)
+    } else {
+        Some(name_end)
+    }
+}
+
+fn range_contains(range: Range, position: Position) -> bool {
+    range.start <= position && position <= range.end
+}
@@ -0,0 +1,283 @@
+use std::collections::BTreeSet;
+
+use common_lang_types::{
+    FieldNameOrAlias, SelectableFieldName, Span, TextSource, UnvalidatedTypeName, WithSpan,
+};
+use intern::string_key::Intern;
+use isograph_compiler::{extract_iso_literals_from_file_content, IsoLiteralExtraction};
+use isograph_lang_parser::{parse_iso_literal, IsoLiteralExtractionResult};
+use isograph_lang_types::{
+    ClientFieldDeclarationWithUnvalidatedDirectives, ClientFieldId, SelectionType,
+    ServerFieldSelection, ServerObjectId, UnvalidatedSelectionWithUnvalidatedDirectives,
+};
+use isograph_schema::{ClientType, FieldType, ValidatedFieldDefinitionLocation, ValidatedSchema};
+use lsp_types::{Position, Url};
+
+use crate::{lsp_state::LSPState, row_col_offset::get_index_from_row_col};
+
+/// A parsed iso literal, along with the offset of a position within it.
+pub(crate) struct IsoLiteralAtPosition {
+    pub extraction_result: IsoLiteralExtractionResult,
+    pub text_source: TextSource,
+    /// The offset of the position, relative to the start of the iso literal.
+    pub offset: u32,
+}
+
+/// Find and parse the iso literal in the open document `uri` that contains
+/// `position`. Returns None if the position is not within an iso literal, or
+/// if that iso literal cannot be parsed.
+pub(crate) fn find_iso_literal_at_position(
+    state: &LSPState,
+    uri: &Url,
+    position: Position,
+) -> Option<IsoLiteralAtPosition> {
+    let file_text = state.text_for(uri)?;
+    let index = get_index_from_row_col(file_text, position.line, position.character) as u32;
+
+    state
+        .iso_literals_for(uri)?
+        .iter()
+        .find_map(|(extraction_result, text_source)| {
+            let iso_literal_span = text_source.span?;
+            if !span_contains(iso_literal_span, index) {
+                return None;
+            }
+            Some(IsoLiteralAtPosition {
+                extraction_result: extraction_result.clone(),
+                text_source: *text_source,
+                offset: index - iso_literal_span.start,
+            })
+        })
+}
+
+/// Extract and parse the iso literals in a file. Iso literals that cannot be
+/// parsed are skipped.
+pub(crate) fn parse_iso_literals_in_file<'a>(
+    file_path: &str,
+    file_text: &'a str,
+) -> impl Iterator<Item = (IsoLiteralExtractionResult, TextSource)> + 'a {
+    let file_path = file_path.intern();
+    extract_iso_literals_from_file_content(file_text).filter_map(move |literal_extraction| {
+        let IsoLiteralExtraction {
+            iso_literal_text,
+            iso_literal_start_index,
+            const_export_name,
+            ..
+        } = literal_extraction;
+        let text_source = TextSource {
+            path: file_path.into(),
+            span: Some(Span::new(
+                iso_literal_start_index as u32,
+                (iso_literal_start_index + iso_literal_text.len()) as u32,
+            )),
+        };
+        let extraction_result = parse_iso_literal(
+            iso_literal_text,
+            file_path.into(),
+            const_export_name,
+            text_source,
+        )
+        .ok()?;
+        Some((extraction_result, text_source))
+    })
+}
+
+/// A field selected in a client field declaration.
+pub(crate) struct SelectedField {
+    pub field: ValidatedFieldDefinitionLocation,
+    /// The span of the selected field's name, relative to the iso literal.
+    pub name_span: Span,
+    /// The alias of the selection, if any, and its span relative to the iso literal.
+    pub alias: Option<WithSpan<FieldNameOrAlias>>,
+    /// The names (or aliases) of the other selections in the same selection set.
+    pub sibling_names: Vec<FieldNameOrAlias>,
+}
+
+/// Find the selection whose name contains `offset`, and resolve the field it
+/// selects. We walk the selections of the freshly parsed declaration (rather
+/// than the validated client field), so that spans match the current contents
+/// of the document, even if the last successful compilation is stale.
+pub(crate) fn find_selected_field_at_offset(
+    schema: &ValidatedSchema,
+    client_field_declaration: &ClientFieldDeclarationWithUnvalidatedDirectives,
+    offset: u32,
+) -> Option<SelectedField> {
+    selected_fields(schema, client_field_declaration)
+        .into_iter()
+        .find(|selected_field| span_contains(selected_field.name_span, offset))
+}
+
+/// Resolve every selection (including nested selections) of a freshly parsed
+/// client field declaration. Selections of fields that do not exist are skipped.
+pub(crate) fn selected_fields(
+    schema: &ValidatedSchema,
+    client_field_declaration: &ClientFieldDeclarationWithUnvalidatedDirectives,
+) -> Vec<SelectedField> {
+    let mut selected_fields = vec![];
+    if let Some(SelectionType::Object(parent_object_id)) = schema
+        .server_field_data
+        .defined_types
+        .get(&client_field_declaration.parent_type.item)
+    {
+        collect_selected_fields(
+            schema,
+            &client_field_declaration.selection_set,
+            *parent_object_id,
+            &mut selected_fields,
+        );
+    }
+    selected_fields
+}
+
+fn collect_selected_fields(
+    schema: &ValidatedSchema,
+    selection_set: &[WithSpan<UnvalidatedSelectionWithUnvalidatedDirectives>],
+    parent_object_id: ServerObjectId,
+    selected_fields: &mut Vec<SelectedField>,
+) {
+    let parent_object = schema.server_field_data.object(parent_object_id);
+    let names = selection_set
+        .iter()
+        .map(|selection| selection.item.name_or_alias().item)
+        .collect::<Vec<_>>();
+    for (index, selection) in selection_set.iter().enumerate() {
+        let (name, name_span, alias) = match &selection.item {
+            ServerFieldSelection::ScalarField(scalar_field) => (
+                scalar_field.name.item.into(),
+                scalar_field.name.location.span(),
+                scalar_field.reader_alias.and_then(|alias| {
+                    Some(WithSpan::new(alias.item.into(), alias.location.span()?))
+                }),
+            ),
+            ServerFieldSelection::LinkedField(linked_field) => (
+                linked_field.name.item.into(),
+                linked_field.name.location.span(),
+                linked_field.reader_alias.and_then(|alias| {
+                    Some(WithSpan::new(alias.item.into(), alias.location.span()?))
+                }),
+            ),
+        };
+        let field = match parent_object.encountered_fields.get(&name) {
+            Some(FieldType::ServerField(server_field_id)) => {
+                FieldType::ServerField(*server_field_id)
+            }
+            Some(FieldType::ClientField(ClientType::ClientField(client_field_id))) => {
+                FieldType::ClientField(*client_field_id)
+            }
+            None => continue,
+        };
+
+        if let Some(name_span) = name_span {
+            selected_fields.push(SelectedField {
+                field,
+                name_span,
+                alias,
+                sibling_names: names
+                    .iter()
+                    .enumerate()
+                    .filter(|(sibling_index, _)| *sibling_index != index)
+                    .map(|(_, name)| *name)
+                    .collect(),
+            });
+        }
+
+        if let (
+            ServerFieldSelection::LinkedField(linked_field),
+            FieldType::ServerField(server_field_id),
+        ) = (&selection.item, field)
+        {
+            if let SelectionType::Object(associated_data) =
+                &schema.server_field(server_field_id).associated_data
+            {
+                collect_selected_fields(
+                    schema,
+                    &linked_field.selection_set,
+                    associated_data.type_name.inner(),
+                    selected_fields,
+                );
+            }
+        }
+    }
+}
+
+/// Find the client field whose name contains `offset`. This is either the
+/// client field being declared, a selected client field, or the client field
+/// of an entrypoint. Also returns the span of the name, relative to the iso
+/// literal.
+pub(crate) fn find_client_field_at_offset(
+    schema: &ValidatedSchema,
+    extraction_result: &IsoLiteralExtractionResult,
+    offset: u32,
+) -> Option<(ClientFieldId, Span)> {
+    let (parent_type, client_field_name) = match extraction_result {
+        IsoLiteralExtractionResult::ClientFieldDeclaration(client_field_declaration) => {
+            let client_field_declaration = &client_field_declaration.item;
+            if !span_contains(client_field_declaration.client_field_name.span, offset) {
+                let selected_field =
+                    find_selected_field_at_offset(schema, client_field_declaration, offset)?;
+                return match selected_field.field {
+                    FieldType::ClientField(client_field_id) => {
+                        Some((client_field_id, selected_field.name_span))
+                    }
+                    FieldType::ServerField(_) => None,
+                };
+            }
+            (
+                client_field_declaration.parent_type.item,
+                client_field_declaration.client_field_name,
+            )
+        }
+        IsoLiteralExtractionResult::EntrypointDeclaration(entrypoint_declaration) => {
+            let entrypoint_declaration = &entrypoint_declaration.item;
+            if !span_contains(entrypoint_declaration.client_field_name.span, offset) {
+                return None;
+            }
+            (
+                entrypoint_declaration.parent_type.item,
+                entrypoint_declaration.client_field_name,
+            )
+        }
+        IsoLiteralExtractionResult::ClientPointerDeclaration(_) => return None,
+    };
+    let client_field_id = find_client_field(schema, parent_type, client_field_name.item.into())?;
+    Some((client_field_id, client_field_name.span))
+}
+
+/// Find a client field by the name of its parent type and its own name.
+pub(crate) fn find_client_field(
+    schema: &ValidatedSchema,
+    parent_type_name: UnvalidatedTypeName,
+    client_field_name: SelectableFieldName,
+) -> Option<ClientFieldId> {
+    let parent_object_id = match schema
+        .server_field_data
+        .defined_types
+        .get(&parent_type_name)?
+    {
+        SelectionType::Object(object_id) => *object_id,
+        SelectionType::Scalar(_) => return None,
+    };
+    match schema
+        .server_field_data
+        .object(parent_object_id)
+        .encountered_fields
+        .get(&client_field_name)?
+    {
+        FieldType::ClientField(ClientType::ClientField(client_field_id)) => Some(*client_field_id),
+        FieldType::ServerField(_) => None,
+    }
+}
+
+pub(crate) fn span_contains(span: Span, offset: u32) -> bool {
+    span.start <= offset && offset <= span.end
+}
+
+/// The files that contain iso literals, taking the contents of open documents
+/// into account.
+pub(crate) fn files_with_iso_literals(state: &LSPState) -> BTreeSet<Url> {
+    state
+        .source_files
+        .paths_with_iso_literals()
+        .filter_map(|path| Url::from_file_path(path).ok())
+        .collect()
+}
@@ -2,15 +2,29 @@ use isograph_config::CompilerConfig;
 use lsp_process_error::LSPProcessResult;
 use lsp_server::Connection;
 
+mod code_actions;
+mod code_lens;
+mod completion;
+mod diagnostics;
+mod goto_definition;
+mod hover;
+mod inlay_hints;
+mod iso_literals;
+mod location_utils;
 pub mod lsp_notification_dispatch;
 pub mod lsp_process_error;
 mod lsp_request_dispatch;
 pub mod lsp_runtime_error;
 mod lsp_state;
+mod references;
+mod rename;
 mod row_col_offset;
+mod schema_text;
 mod semantic_tokens;
 pub mod server;
+mod symbols;
 pub mod text_document;
+mod watched_files;
 
 pub async fn start_language_server(config: CompilerConfig) -> LSPProcessResult<()> {
     let (connection, io_handles) = Connection::stdio();
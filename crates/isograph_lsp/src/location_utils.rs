@@ -0,0 +1,110 @@
+use common_lang_types::{EmbeddedLocation, Location};
+use intern::Lookup;
+use isograph_lang_parser::IsoLiteralExtractionResult;
+use isograph_lang_types::{ClientFieldId, SelectionType, ServerFieldId, ServerObjectId};
+use isograph_schema::{ClientFieldVariant, ValidatedSchema};
+use lsp_types::Url;
+
+use crate::{lsp_state::LSPState, row_col_offset::span_to_range};
+
+/// Convert a location into an LSP location, i.e. the uri of the containing file
+/// and the range within that file. Locations are relative to their text source
+/// (e.g. an iso literal), so we offset them by the start of the text source.
+pub(crate) fn embedded_location_to_lsp_location(
+    state: &LSPState,
+    location: EmbeddedLocation,
+) -> Option<lsp_types::Location> {
+    let uri = Url::from_file_path(location.text_source.path.lookup()).ok()?;
+    let file_text = state.text_for_or_read(&uri)?;
+
+    let text_source_start = location
+        .text_source
+        .span
+        .map(|span| span.start)
+        .unwrap_or(0);
+    let range = span_to_range(&file_text, location.span.with_offset(text_source_start));
+
+    Some(lsp_types::Location { uri, range })
+}
+
+/// The location of the name of a client field in the iso literal that defines it.
+/// Returns None for client fields that are generated by Isograph.
+pub(crate) fn client_field_declaration_location(
+    state: &LSPState,
+    schema: &ValidatedSchema,
+    client_field_id: ClientFieldId,
+) -> Option<lsp_types::Location> {
+    let client_field = schema.client_field(client_field_id);
+    let file_path = match &client_field.variant {
+        ClientFieldVariant::UserWritten(info) => info.file_path,
+        ClientFieldVariant::ImperativelyLoadedField(_) => return None,
+    };
+    let absolute_path = state
+        .compiler_state
+        .config
+        .project_root
+        .join(file_path.lookup());
+    let uri = Url::from_file_path(&absolute_path).ok()?;
+    let file_text = state.text_for_or_read(&uri)?;
+
+    let location = state
+        .iso_literals_for_or_parse(&uri, &file_text)
+        .iter()
+        .find_map(|(extraction_result, text_source)| match extraction_result {
+            IsoLiteralExtractionResult::ClientFieldDeclaration(client_field_declaration) => {
+                let client_field_declaration = &client_field_declaration.item;
+                let is_match = client_field_declaration.parent_type.item.lookup()
+                    == client_field.type_and_field.type_name.lookup()
+                    && client_field_declaration.client_field_name.item.lookup()
+                        == client_field.name.lookup();
+                if !is_match {
+                    return None;
+                }
+                let literal_start = text_source.span.map(|span| span.start).unwrap_or(0);
+                Some(lsp_types::Location {
+                    uri: uri.clone(),
+                    range: span_to_range(
+                        &file_text,
+                        client_field_declaration
+                            .client_field_name
+                            .span
+                            .with_offset(literal_start),
+                    ),
+                })
+            }
+            _ => None,
+        });
+    location
+}
+
+/// The location of the definition of a server field in the schema or a schema
+/// extension. For fields that are generated by Isograph (e.g. `asConcreteType`
+/// fields), we fall back to the location of the type they refer to.
+pub(crate) fn server_field_definition_location(
+    state: &LSPState,
+    schema: &ValidatedSchema,
+    server_field_id: ServerFieldId,
+) -> Option<lsp_types::Location> {
+    let server_field = schema.server_field(server_field_id);
+    match server_field.name.location {
+        Location::Embedded(location) => embedded_location_to_lsp_location(state, location),
+        Location::Generated => match &server_field.associated_data {
+            SelectionType::Object(associated_data) => {
+                server_object_definition_location(state, schema, associated_data.type_name.inner())
+            }
+            SelectionType::Scalar(_) => None,
+        },
+    }
+}
+
+/// The location of the name of an object type in the schema or a schema extension.
+pub(crate) fn server_object_definition_location(
+    state: &LSPState,
+    schema: &ValidatedSchema,
+    object_id: ServerObjectId,
+) -> Option<lsp_types::Location> {
+    match schema.server_field_data.object(object_id).name_location {
+        Location::Embedded(location) => embedded_location_to_lsp_location(state, location),
+        Location::Generated => None,
+    }
+}
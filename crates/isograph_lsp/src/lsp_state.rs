@@ -1,45 +1,266 @@
-use std::collections::HashMap;
+use std::{
+    borrow::Cow,
+    cell::OnceCell,
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
 
+use common_lang_types::TextSource;
 use crossbeam::channel::Sender;
-use lsp_server::Message;
-use lsp_types::Url;
+use isograph_compiler::{CompilerState, OverlaidSourceFiles};
+use isograph_config::{create_config, CompilerConfig, ConfigError};
+use isograph_lang_parser::IsoLiteralExtractionResult;
+use isograph_schema::ValidatedSchema;
+use lsp_server::{Message, RequestId};
+use lsp_types::{
+    request::Request, CodeAction, SemanticTokens, TextDocumentContentChangeEvent, Url,
+};
 
-use crate::lsp_runtime_error::LSPRuntimeResult;
+use crate::{
+    code_lens::{client_field_usages, ClientFieldUsages},
+    iso_literals::parse_iso_literals_in_file,
+    lsp_runtime_error::{LSPRuntimeError, LSPRuntimeResult},
+    row_col_offset::apply_content_change,
+};
 
-#[derive(Debug)]
 pub struct LSPState {
-    open_docs: HashMap<Url, String>,
+    open_docs: HashMap<Url, OpenDocument>,
     sender: Sender<Message>,
+    pub compiler_state: CompilerState,
+    /// The source files of the project, with the contents of open documents
+    /// overlaid on top of the contents on disk. This is kept up to date as
+    /// documents are edited and as files change on disk.
+    pub source_files: OverlaidSourceFiles,
+    /// The most recently successfully validated schema. This is kept when a
+    /// subsequent compilation fails, so that e.g. hover keeps working while
+    /// the user is typing.
+    pub validated_schema: Option<ValidatedSchema>,
+    /// The error encountered when the config was last reloaded, if it could not
+    /// be loaded. This is reported on the config file until the config is fixed.
+    pub config_error: Option<ConfigError>,
+    /// How the client fields of validated_schema are used, which is shown in code
+    /// lenses. This is computed when it is first needed, and reset whenever a new
+    /// schema is validated.
+    client_field_usages: OnceCell<ClientFieldUsages>,
+    /// The documents for which we have most recently published a non-empty
+    /// set of diagnostics. We need to track these so that we can clear their
+    /// diagnostics once the errors are fixed.
+    pub documents_with_diagnostics: HashSet<Url>,
+    /// Fixes for the most recently published diagnostics, by document.
+    pub quick_fixes: HashMap<Url, Vec<CodeAction>>,
+    /// The semantic tokens most recently sent for each open document, from which
+    /// we compute deltas.
+    pub semantic_tokens: HashMap<Url, SemanticTokens>,
+    /// Whether documents or files have changed since the project was last
+    /// validated. The server loop re-validates once no further messages arrive
+    /// for a short while, so that a burst of changes (e.g. while the user is
+    /// typing) is only validated once.
+    pub recompile_scheduled: bool,
+    next_request_id: i32,
+}
+
+/// A document that is open in the editor.
+struct OpenDocument {
+    version: i32,
+    text: String,
+    /// The iso literals in the document. These are parsed when they are first
+    /// needed, and reused until the document changes.
+    iso_literals: OnceCell<Vec<(IsoLiteralExtractionResult, TextSource)>>,
 }
 
 impl LSPState {
-    pub fn new(sender: Sender<Message>) -> Self {
+    pub fn new(sender: Sender<Message>, config: CompilerConfig) -> Self {
         LSPState {
             open_docs: HashMap::new(),
             sender,
+            source_files: OverlaidSourceFiles::read_and_parse_all_files(&config),
+            compiler_state: CompilerState::from_config(config),
+            validated_schema: None,
+            config_error: None,
+            client_field_usages: OnceCell::new(),
+            documents_with_diagnostics: HashSet::new(),
+            quick_fixes: HashMap::new(),
+            semantic_tokens: HashMap::new(),
+            recompile_scheduled: false,
+            next_request_id: 0,
         }
     }
 
-    pub fn document_opened(&mut self, uri: &Url, text: &str) -> LSPRuntimeResult<()> {
-        self.open_docs.insert(uri.to_owned(), text.to_owned());
+    pub fn document_opened(&mut self, uri: &Url, version: i32, text: &str) -> LSPRuntimeResult<()> {
+        self.open_docs.insert(
+            uri.to_owned(),
+            OpenDocument {
+                version,
+                text: text.to_owned(),
+                iso_literals: OnceCell::new(),
+            },
+        );
+        if let Some(path) = file_path(uri) {
+            self.source_files
+                .open_document(&self.compiler_state.config, &path, text.to_owned());
+        }
         Ok(())
     }
 
-    pub fn document_changed(&mut self, uri: &Url, text: &str) -> LSPRuntimeResult<()> {
-        self.open_docs.insert(uri.to_owned(), text.to_owned());
+    /// Apply the changes to the stored text of the document, in order. Changes
+    /// without a range replace the entire document.
+    pub fn document_changed(
+        &mut self,
+        uri: &Url,
+        version: i32,
+        content_changes: &[TextDocumentContentChangeEvent],
+    ) -> LSPRuntimeResult<()> {
+        let document = self.open_docs.get_mut(uri).ok_or_else(|| {
+            LSPRuntimeError::UnexpectedError(format!(
+                "Received changes to document {}, which has not been opened.",
+                uri
+            ))
+        })?;
+        for content_change in content_changes {
+            apply_content_change(&mut document.text, content_change);
+        }
+        document.version = version;
+        document.iso_literals = OnceCell::new();
+        if let Some(path) = file_path(uri) {
+            self.source_files.open_document(
+                &self.compiler_state.config,
+                &path,
+                document.text.clone(),
+            );
+        }
         Ok(())
     }
 
     pub fn document_closed(&mut self, uri: &Url) -> LSPRuntimeResult<()> {
         self.open_docs.remove(uri);
+        self.semantic_tokens.remove(uri);
+        if let Some(path) = file_path(uri) {
+            self.source_files
+                .close_document(&self.compiler_state.config, &path);
+        }
         Ok(())
     }
 
+    /// Re-read a file or folder that changed on disk.
+    pub fn path_changed_on_disk(&mut self, uri: &Url) {
+        if let Some(path) = file_path(uri) {
+            self.source_files
+                .path_changed_on_disk(&self.compiler_state.config, &path);
+        }
+    }
+
+    /// Re-read the config, and then every file in the project. The contents of
+    /// open documents are kept. If the config cannot be loaded, the previous
+    /// config and source files are kept.
+    pub fn reload_config(&mut self) -> Result<(), ConfigError> {
+        let config = create_config(self.compiler_state.config.config_location.clone())?;
+        self.compiler_state = CompilerState::from_config(config);
+        self.source_files =
+            OverlaidSourceFiles::read_and_parse_all_files(&self.compiler_state.config);
+        for (uri, document) in self.open_docs.iter() {
+            if let Some(path) = file_path(uri) {
+                self.source_files.open_document(
+                    &self.compiler_state.config,
+                    &path,
+                    document.text.clone(),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    pub fn set_validated_schema(&mut self, validated_schema: ValidatedSchema) {
+        self.validated_schema = Some(validated_schema);
+        self.client_field_usages = OnceCell::new();
+    }
+
+    /// How the client fields of the most recently validated schema are used.
+    pub(crate) fn client_field_usages(&self) -> Option<&ClientFieldUsages> {
+        let schema = self.validated_schema.as_ref()?;
+        Some(
+            self.client_field_usages
+                .get_or_init(|| client_field_usages(schema)),
+        )
+    }
+
     pub fn text_for(&self, uri: &Url) -> Option<&str> {
-        self.open_docs.get(uri).map(|s| s.as_str())
+        self.open_docs
+            .get(uri)
+            .map(|document| document.text.as_str())
+    }
+
+    pub fn version_for(&self, uri: &Url) -> Option<i32> {
+        self.open_docs.get(uri).map(|document| document.version)
+    }
+
+    /// The iso literals in the open document that can be parsed. These are cached
+    /// until the document changes.
+    pub fn iso_literals_for(
+        &self,
+        uri: &Url,
+    ) -> Option<&[(IsoLiteralExtractionResult, TextSource)]> {
+        let document = self.open_docs.get(uri)?;
+        Some(
+            document
+                .iso_literals
+                .get_or_init(|| parse_iso_literals_in_file(uri.path(), &document.text).collect()),
+        )
+    }
+
+    /// The iso literals in the document that can be parsed, taken from the open
+    /// document if there is one, and parsed from `file_text` otherwise.
+    pub fn iso_literals_for_or_parse(
+        &self,
+        uri: &Url,
+        file_text: &str,
+    ) -> Cow<'_, [(IsoLiteralExtractionResult, TextSource)]> {
+        match self.iso_literals_for(uri) {
+            Some(iso_literals) => Cow::Borrowed(iso_literals),
+            None => Cow::Owned(parse_iso_literals_in_file(uri.path(), file_text).collect()),
+        }
+    }
+
+    /// The text of the document, taken from the open document if there is one,
+    /// and read from disk otherwise.
+    pub fn text_for_or_read(&self, uri: &Url) -> Option<Cow<'_, str>> {
+        match self.text_for(uri) {
+            Some(text) => Some(Cow::Borrowed(text)),
+            None => {
+                let path = uri.to_file_path().ok()?;
+                std::fs::read_to_string(path).ok().map(Cow::Owned)
+            }
+        }
     }
 
     pub fn send_message(&self, message: Message) {
         self.sender.send(message).unwrap();
     }
+
+    /// Send a request to the client. We do not wait for the response.
+    pub fn send_request<R: Request>(&mut self, params: R::Params) {
+        let id = RequestId::from(self.next_request_id);
+        self.next_request_id += 1;
+        self.send_message(Message::Request(lsp_server::Request::new(
+            id,
+            R::METHOD.to_string(),
+            params,
+        )));
+    }
+}
+
+/// The absolute path of the file that a document corresponds to. Paths in the
+/// config are canonicalized, so we canonicalize this path as well. If the file
+/// no longer exists, we canonicalize its parent folder instead.
+pub(crate) fn file_path(uri: &Url) -> Option<PathBuf> {
+    let path = uri.to_file_path().ok()?;
+    if let Ok(canonicalized_path) = path.canonicalize() {
+        return Some(canonicalized_path);
+    }
+    match (
+        path.parent().and_then(|parent| parent.canonicalize().ok()),
+        path.file_name(),
+    ) {
+        (Some(parent), Some(file_name)) => Some(parent.join(file_name)),
+        _ => Some(path),
+    }
 }
@@ -0,0 +1,160 @@
+use common_lang_types::{FieldNameOrAlias, Span};
+use isograph_lang_parser::IsoLiteralExtractionResult;
+use isograph_lang_types::ClientFieldId;
+use isograph_schema::{FieldType, ValidatedSchema};
+use lsp_types::{
+    request::{References, Request},
+    Location, ReferenceContext, ReferenceParams, TextDocumentPositionParams,
+};
+
+use crate::{
+    iso_literals::{
+        files_with_iso_literals, find_client_field, find_client_field_at_offset,
+        find_iso_literal_at_position, selected_fields,
+    },
+    lsp_runtime_error::LSPRuntimeResult,
+    lsp_state::LSPState,
+    row_col_offset::span_to_range,
+};
+
+pub fn on_references(
+    state: &mut LSPState,
+    params: <References as Request>::Params,
+) -> LSPRuntimeResult<<References as Request>::Result> {
+    let ReferenceParams {
+        text_document_position:
+            TextDocumentPositionParams {
+                text_document,
+                position,
+            },
+        context: ReferenceContext {
+            include_declaration,
+        },
+        ..
+    } = params;
+
+    let schema = match &state.validated_schema {
+        Some(schema) => schema,
+        None => return Ok(None),
+    };
+    let iso_literal = match find_iso_literal_at_position(state, &text_document.uri, position) {
+        Some(iso_literal) => iso_literal,
+        None => return Ok(None),
+    };
+    let client_field_id = match find_client_field_at_offset(
+        schema,
+        &iso_literal.extraction_result,
+        iso_literal.offset,
+    ) {
+        Some((client_field_id, _)) => client_field_id,
+        None => return Ok(None),
+    };
+
+    let locations = client_field_references(state, schema, client_field_id)
+        .into_iter()
+        .filter(|reference| {
+            include_declaration || !matches!(reference.kind, ClientFieldReferenceKind::Declaration)
+        })
+        .map(|reference| reference.location)
+        .collect();
+    Ok(Some(locations))
+}
+
+/// A place in which a client field is referred to by name.
+pub(crate) struct ClientFieldReference {
+    /// The location of the client field's name.
+    pub location: Location,
+    pub kind: ClientFieldReferenceKind,
+}
+
+pub(crate) enum ClientFieldReferenceKind {
+    /// The iso literal that declares the client field.
+    Declaration,
+    /// A selection of the client field in another client field.
+    Selection {
+        /// The alias of the selection, if any.
+        alias: Option<(FieldNameOrAlias, Location)>,
+        /// The names (or aliases) of the other selections in the same selection set.
+        sibling_names: Vec<FieldNameOrAlias>,
+    },
+    /// An entrypoint declaration for the client field.
+    Entrypoint,
+}
+
+/// Find every reference to a client field in the project. We re-parse each file
+/// that contains iso literals, so that the locations match the current contents
+/// of open documents, even if the last successful compilation is stale.
+pub(crate) fn client_field_references(
+    state: &LSPState,
+    schema: &ValidatedSchema,
+    client_field_id: ClientFieldId,
+) -> Vec<ClientFieldReference> {
+    let mut references = vec![];
+    for uri in files_with_iso_literals(state) {
+        let file_text = match state.text_for_or_read(&uri) {
+            Some(file_text) => file_text,
+            None => continue,
+        };
+        let to_location = |literal_start: u32, span: Span| Location {
+            uri: uri.clone(),
+            range: span_to_range(&file_text, span.with_offset(literal_start)),
+        };
+
+        for (extraction_result, text_source) in
+            state.iso_literals_for_or_parse(&uri, &file_text).iter()
+        {
+            let literal_start = text_source.span.map(|span| span.start).unwrap_or(0);
+            match extraction_result {
+                IsoLiteralExtractionResult::ClientFieldDeclaration(client_field_declaration) => {
+                    let client_field_declaration = &client_field_declaration.item;
+                    if find_client_field(
+                        schema,
+                        client_field_declaration.parent_type.item,
+                        client_field_declaration.client_field_name.item.into(),
+                    ) == Some(client_field_id)
+                    {
+                        references.push(ClientFieldReference {
+                            location: to_location(
+                                literal_start,
+                                client_field_declaration.client_field_name.span,
+                            ),
+                            kind: ClientFieldReferenceKind::Declaration,
+                        });
+                    }
+                    for selected_field in selected_fields(schema, client_field_declaration) {
+                        if selected_field.field == FieldType::ClientField(client_field_id) {
+                            references.push(ClientFieldReference {
+                                location: to_location(literal_start, selected_field.name_span),
+                                kind: ClientFieldReferenceKind::Selection {
+                                    alias: selected_field.alias.map(|alias| {
+                                        (alias.item, to_location(literal_start, alias.span))
+                                    }),
+                                    sibling_names: selected_field.sibling_names,
+                                },
+                            });
+                        }
+                    }
+                }
+                IsoLiteralExtractionResult::EntrypointDeclaration(entrypoint_declaration) => {
+                    let entrypoint_declaration = &entrypoint_declaration.item;
+                    if find_client_field(
+                        schema,
+                        entrypoint_declaration.parent_type.item,
+                        entrypoint_declaration.client_field_name.item.into(),
+                    ) == Some(client_field_id)
+                    {
+                        references.push(ClientFieldReference {
+                            location: to_location(
+                                literal_start,
+                                entrypoint_declaration.client_field_name.span,
+                            ),
+                            kind: ClientFieldReferenceKind::Entrypoint,
+                        });
+                    }
+                }
+                IsoLiteralExtractionResult::ClientPointerDeclaration(_) => {}
+            }
+        }
+    }
+    references
+}
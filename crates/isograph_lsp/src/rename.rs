@@ -0,0 +1,357 @@
+use std::collections::HashMap;
+
+use common_lang_types::{SelectableFieldName, Span};
+use intern::{string_key::Intern, Lookup};
+use isograph_schema::ClientFieldVariant;
+use lsp_types::{
+    request::{PrepareRenameRequest, Rename, Request},
+    PrepareRenameResponse, RenameParams, TextDocumentPositionParams, TextEdit, Url, WorkspaceEdit,
+};
+
+use crate::{
+    iso_literals::{find_client_field_at_offset, find_iso_literal_at_position},
+    lsp_runtime_error::{LSPRuntimeError, LSPRuntimeResult},
+    lsp_state::LSPState,
+    references::{client_field_references, ClientFieldReference, ClientFieldReferenceKind},
+    row_col_offset::span_to_range,
+};
+
+pub fn on_prepare_rename(
+    state: &mut LSPState,
+    params: <PrepareRenameRequest as Request>::Params,
+) -> LSPRuntimeResult<<PrepareRenameRequest as Request>::Result> {
+    let TextDocumentPositionParams {
+        text_document,
+        position,
+    } = params;
+
+    let schema = match &state.validated_schema {
+        Some(schema) => schema,
+        None => return Ok(None),
+    };
+    let iso_literal = match find_iso_literal_at_position(state, &text_document.uri, position) {
+        Some(iso_literal) => iso_literal,
+        None => return Ok(None),
+    };
+    let (client_field_id, name_span) = match find_client_field_at_offset(
+        schema,
+        &iso_literal.extraction_result,
+        iso_literal.offset,
+    ) {
+        Some(client_field) => client_field,
+        None => return Ok(None),
+    };
+    if let ClientFieldVariant::ImperativelyLoadedField(_) =
+        schema.client_field(client_field_id).variant
+    {
+        return Err(LSPRuntimeError::UnexpectedError(
+            "Client fields generated by Isograph cannot be renamed.".to_string(),
+        ));
+    }
+
+    let literal_start = iso_literal
+        .text_source
+        .span
+        .map(|span| span.start)
+        .unwrap_or(0);
+    Ok(state.text_for(&text_document.uri).map(|file_text| {
+        PrepareRenameResponse::Range(span_to_range(
+            file_text,
+            name_span.with_offset(literal_start),
+        ))
+    }))
+}
+
+/// Rename a client field. This renames the client field in the iso literal that
+/// declares it, in every selection and entrypoint, and in aliases that match the
+/// old name (e.g. `OldName: OldName`). Imports of its artifacts and the names of
+/// its generated types are renamed too, since the artifacts will be regenerated
+/// under the new name. The exported const is not renamed, since it may be
+/// imported elsewhere.
+pub fn on_rename(
+    state: &mut LSPState,
+    params: <Rename as Request>::Params,
+) -> LSPRuntimeResult<<Rename as Request>::Result> {
+    let RenameParams {
+        text_document_position:
+            TextDocumentPositionParams {
+                text_document,
+                position,
+            },
+        new_name,
+        ..
+    } = params;
+
+    let schema = match &state.validated_schema {
+        Some(schema) => schema,
+        None => return Ok(None),
+    };
+    let iso_literal = match find_iso_literal_at_position(state, &text_document.uri, position) {
+        Some(iso_literal) => iso_literal,
+        None => return Ok(None),
+    };
+    let client_field_id = match find_client_field_at_offset(
+        schema,
+        &iso_literal.extraction_result,
+        iso_literal.offset,
+    ) {
+        Some((client_field_id, _)) => client_field_id,
+        None => return Ok(None),
+    };
+    let client_field = schema.client_field(client_field_id);
+    if let ClientFieldVariant::ImperativelyLoadedField(_) = client_field.variant {
+        return Err(LSPRuntimeError::UnexpectedError(
+            "Client fields generated by Isograph cannot be renamed.".to_string(),
+        ));
+    }
+    if !is_valid_field_name(&new_name) {
+        return Err(LSPRuntimeError::UnexpectedError(format!(
+            "`{}` is not a valid client field name.",
+            new_name
+        )));
+    }
+    let old_name = client_field.name;
+    let new_field_name: SelectableFieldName = new_name.as_str().intern().into();
+    if new_field_name == old_name {
+        return Ok(None);
+    }
+    let parent_object = schema
+        .server_field_data
+        .object(client_field.parent_object_id);
+    if parent_object
+        .encountered_fields
+        .contains_key(&new_field_name)
+    {
+        return Err(LSPRuntimeError::UnexpectedError(format!(
+            "`{}` already has a field named `{}`.",
+            parent_object.name, new_name
+        )));
+    }
+
+    let mut changes = rename_edits(
+        client_field_references(state, schema, client_field_id),
+        old_name,
+        &new_name,
+    )?;
+    for (path, file_text) in state
+        .source_files
+        .read_all_source_files(&state.compiler_state.config)
+    {
+        let edits: Vec<_> =
+            artifact_reference_spans(&file_text, parent_object.name.lookup(), old_name.lookup())
+                .into_iter()
+                .map(|span| TextEdit {
+                    range: span_to_range(&file_text, span),
+                    new_text: new_name.clone(),
+                })
+                .collect();
+        if edits.is_empty() {
+            continue;
+        }
+        if let Ok(uri) = Url::from_file_path(&path) {
+            changes.entry(uri).or_default().extend(edits);
+        }
+    }
+
+    Ok(Some(WorkspaceEdit {
+        changes: Some(changes),
+        ..Default::default()
+    }))
+}
+
+/// The edits that rename every reference to a client field from `old_name` to
+/// `new_name`. Fails if a selection of the client field would then have the
+/// same name as another selection in its selection set.
+fn rename_edits(
+    references: Vec<ClientFieldReference>,
+    old_name: SelectableFieldName,
+    new_name: &str,
+) -> LSPRuntimeResult<HashMap<Url, Vec<TextEdit>>> {
+    let mut changes: HashMap<_, Vec<TextEdit>> = HashMap::new();
+    let mut add_edit = |location: lsp_types::Location| {
+        changes.entry(location.uri).or_default().push(TextEdit {
+            range: location.range,
+            new_text: new_name.to_string(),
+        });
+    };
+    for reference in references {
+        add_edit(reference.location);
+        if let ClientFieldReferenceKind::Selection {
+            alias,
+            sibling_names,
+        } = reference.kind
+        {
+            if let Some((alias, alias_location)) = alias {
+                // A selection with any other alias keeps its name
+                if alias.lookup() != old_name.lookup() {
+                    continue;
+                }
+                add_edit(alias_location);
+            }
+            if sibling_names
+                .iter()
+                .any(|sibling_name| sibling_name.lookup() == new_name)
+            {
+                return Err(LSPRuntimeError::UnexpectedError(format!(
+                    "`{}` cannot be renamed to `{}`, since it is selected alongside \
+                    another selection named `{}`.",
+                    old_name, new_name, new_name
+                )));
+            }
+        }
+    }
+    Ok(changes)
+}
+
+/// The spans of the client field's name in references to its artifacts, i.e. in
+/// import paths such as `@iso/Type/OldName/entrypoint` and
+/// `./__isograph/Type/OldName/param_type`, and in the names of generated types
+/// such as `Type__OldName__param`.
+fn artifact_reference_spans(file_text: &str, type_name: &str, old_name: &str) -> Vec<Span> {
+    let span_at = |start: usize| Span::new(start as u32, (start + old_name.len()) as u32);
+    let mut spans = vec![];
+    for artifact_directory in ["@iso/", "__isograph/"] {
+        let path = format!("{artifact_directory}{type_name}/{old_name}/");
+        for (index, _) in file_text.match_indices(&path) {
+            spans.push(span_at(
+                index + artifact_directory.len() + type_name.len() + 1,
+            ));
+        }
+    }
+    let type_name_prefix = format!("{type_name}__{old_name}__");
+    for (index, _) in file_text.match_indices(&type_name_prefix) {
+        let is_start_of_identifier = !file_text[..index]
+            .chars()
+            .next_back()
+            .is_some_and(|char| char.is_ascii_alphanumeric() || char == '_' || char == '$');
+        if is_start_of_identifier {
+            spans.push(span_at(index + type_name.len() + 2));
+        }
+    }
+    spans.sort_by_key(|span| span.start);
+    spans
+}
+
+fn is_valid_field_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() || first == '_' => {}
+        _ => return false,
+    }
+    chars.all(|char| char.is_ascii_alphanumeric() || char == '_')
+}
+
+#[cfg(test)]
+mod test {
+    use common_lang_types::FieldNameOrAlias;
+    use intern::string_key::Intern;
+    use lsp_types::{Location, Position, Range, Url};
+
+    use super::{artifact_reference_spans, rename_edits};
+    use crate::lsp_runtime_error::LSPRuntimeError;
+    use crate::references::{ClientFieldReference, ClientFieldReferenceKind};
+
+    fn location(line: u32, start: u32, end: u32) -> Location {
+        Location {
+            uri: Url::parse("file:///src/Component.ts").unwrap(),
+            range: Range::new(Position::new(line, start), Position::new(line, end)),
+        }
+    }
+
+    fn name(name: &str) -> FieldNameOrAlias {
+        name.intern().into()
+    }
+
+    fn selection(line: u32, alias: Option<&str>, sibling_names: &[&str]) -> ClientFieldReference {
+        ClientFieldReference {
+            location: location(line, 20, 27),
+            kind: ClientFieldReferenceKind::Selection {
+                alias: alias
+                    .map(|alias| (name(alias), location(line, 10, 10 + alias.len() as u32))),
+                sibling_names: sibling_names.iter().map(|sibling| name(sibling)).collect(),
+            },
+        }
+    }
+
+    fn edited_ranges(references: Vec<ClientFieldReference>) -> Vec<Range> {
+        let changes = rename_edits(references, "OldName".intern().into(), "NewName")
+            .expect("Expected rename to succeed");
+        changes
+            .into_values()
+            .flatten()
+            .map(|edit| {
+                assert_eq!(edit.new_text, "NewName");
+                edit.range
+            })
+            .collect()
+    }
+
+    #[test]
+    fn renames_declarations_selections_and_matching_aliases() {
+        let ranges = edited_ranges(vec![
+            ClientFieldReference {
+                location: location(0, 6, 13),
+                kind: ClientFieldReferenceKind::Declaration,
+            },
+            ClientFieldReference {
+                location: location(1, 11, 18),
+                kind: ClientFieldReferenceKind::Entrypoint,
+            },
+            selection(2, None, &["id"]),
+            selection(3, Some("OldName"), &[]),
+            selection(4, Some("Other"), &["NewName"]),
+        ]);
+        assert_eq!(
+            ranges,
+            vec![
+                location(0, 6, 13).range,
+                location(1, 11, 18).range,
+                location(2, 20, 27).range,
+                location(3, 20, 27).range,
+                location(3, 10, 17).range,
+                location(4, 20, 27).range,
+            ]
+        );
+    }
+
+    #[test]
+    fn selections_cannot_conflict_with_siblings() {
+        for reference in [
+            selection(0, None, &["id", "NewName"]),
+            selection(0, Some("OldName"), &["NewName"]),
+        ] {
+            let message = match rename_edits(vec![reference], "OldName".intern().into(), "NewName")
+            {
+                Err(LSPRuntimeError::UnexpectedError(message)) => message,
+                _ => panic!("Expected rename to fail"),
+            };
+            assert_eq!(
+                message,
+                "`OldName` cannot be renamed to `NewName`, since it is selected \
+                alongside another selection named `NewName`."
+            );
+        }
+    }
+
+    #[test]
+    fn renames_artifact_imports_and_generated_type_names() {
+        let file_text = "import HomeRoute from '@iso/Query/HomeRoute/entrypoint';\n\
+            import { type Query__HomeRoute__param } from './__isograph/Query/HomeRoute/param_type';\n\
+            import Other from '@iso/Query/HomeRouteOther/entrypoint';\n\
+            type T = MyQuery__HomeRoute__param | Pet__HomeRoute__param;\n";
+        // Each renamed span is the old name, on the line of the reference.
+        let renamed: Vec<_> = artifact_reference_spans(file_text, "Query", "HomeRoute")
+            .into_iter()
+            .map(|span| {
+                (
+                    file_text[..span.start as usize].matches('\n').count(),
+                    &file_text[span.start as usize..span.end as usize],
+                )
+            })
+            .collect();
+        assert_eq!(
+            renamed,
+            vec![(0, "HomeRoute"), (1, "HomeRoute"), (1, "HomeRoute")]
+        );
+    }
+}
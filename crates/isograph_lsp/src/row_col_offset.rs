@@ -1,5 +1,8 @@
 use std::ops::Add;
 
+use common_lang_types::Span;
+use lsp_types::{Position, Range, TextDocumentContentChangeEvent};
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum RowColDiff {
     SameRow(ColOffset),
@@ -66,46 +69,198 @@ impl Add for RowColDiff {
     }
 }
 
+/// The line and character offset from the start of `source_str` to its end.
+/// Characters are counted in UTF-16 code units, as they are in LSP positions.
 pub(crate) fn diff_to_end_of_slice(source_str: &str) -> RowColDiff {
-    let mut row_count = 0;
-    let mut index_of_start_of_last_row = 0;
-    for (index, char) in source_str.chars().enumerate() {
-        // TODO we need to handle other line breaks
-        if char == '\n' {
-            row_count += 1;
-            index_of_start_of_last_row = index;
+    // TODO we need to handle other line breaks
+    match source_str.rfind('\n') {
+        None => RowColDiff::SameRow(ColOffset {
+            col_offset: utf16_len(source_str),
+        }),
+        Some(index_of_last_line_break) => RowColDiff::DifferentRow(RowAndColOffset {
+            row_offset: source_str.matches('\n').count(),
+            new_col: utf16_len(&source_str[index_of_last_line_break + 1..]),
+        }),
+    }
+}
+
+/// The byte index in `source_str` that is `diff` away from its start. This is the
+/// inverse of [diff_to_end_of_slice]. Characters past the end of a line are
+/// clamped to the end of that line, and lines past the end of the string are
+/// clamped to the end of the string.
+pub(crate) fn get_index_from_diff(source_str: &str, diff: RowColDiff) -> usize {
+    let mut start_of_line = 0;
+    for _ in 0..diff.delta_line() {
+        match source_str[start_of_line..].find('\n') {
+            Some(line_break) => start_of_line += line_break + 1,
+            None => return source_str.len(),
         }
     }
 
-    if row_count == 0 {
-        RowColDiff::SameRow(ColOffset {
-            col_offset: source_str.len(),
-        })
-    } else {
-        RowColDiff::DifferentRow(RowAndColOffset {
-            row_offset: row_count,
-            // TODO why -1 here?
-            new_col: source_str.len() - index_of_start_of_last_row - 1,
-        })
+    let mut remaining_code_units = diff.delta_start() as usize;
+    for (offset, char) in source_str[start_of_line..].char_indices() {
+        if remaining_code_units == 0 || char == '\n' {
+            return start_of_line + offset;
+        }
+        // A position within a surrogate pair is rounded up to the end of the character
+        remaining_code_units = remaining_code_units.saturating_sub(char.len_utf16());
     }
+    source_str.len()
 }
 
-pub(crate) fn get_index_from_diff(source_str: &str, diff: RowColDiff) -> usize {
-    let mut remaining_cols = diff.delta_line();
-    let mut index = 0;
-    if remaining_cols > 0 {
-        for char in source_str.chars() {
-            index += 1;
-            if char == '\n' {
-                remaining_cols -= 1;
-                if remaining_cols == 0 {
-                    break;
-                }
-            }
+/// The length of `text` in UTF-16 code units.
+pub(crate) fn utf16_len(text: &str) -> usize {
+    text.chars().map(char::len_utf16).sum()
+}
+
+/// Returns the (line, character) position of the byte at `index` in `source_str`,
+/// where the character is counted in UTF-16 code units.
+/// Indexes past the end of the string are clamped to the end of the string.
+pub(crate) fn get_row_col_from_index(source_str: &str, index: usize) -> (u32, u32) {
+    let mut index = index.min(source_str.len());
+    while !source_str.is_char_boundary(index) {
+        index -= 1;
+    }
+    let diff = diff_to_end_of_slice(&source_str[..index]);
+    (diff.delta_line(), diff.delta_start())
+}
+
+/// Converts a span (relative to the start of `source_str`) into an LSP range.
+pub(crate) fn span_to_range(source_str: &str, span: Span) -> Range {
+    let (start_line, start_character) = get_row_col_from_index(source_str, span.start as usize);
+    let (end_line, end_character) = get_row_col_from_index(source_str, span.end as usize);
+    Range::new(
+        Position::new(start_line, start_character),
+        Position::new(end_line, end_character),
+    )
+}
+
+/// Returns the byte index of the (line, character) position in `source_str`, where
+/// the character is counted in UTF-16 code units.
+pub(crate) fn get_index_from_row_col(source_str: &str, line: u32, character: u32) -> usize {
+    let diff = RowColDiff::DifferentRow(RowAndColOffset {
+        row_offset: line as usize,
+        new_col: character as usize,
+    });
+    get_index_from_diff(source_str, diff)
+}
+
+/// Apply a change, sent by the client during incremental text document syncing,
+/// to the text of a document.
+pub(crate) fn apply_content_change(
+    text: &mut String,
+    content_change: &TextDocumentContentChangeEvent,
+) {
+    match content_change.range {
+        Some(range) => {
+            let start = get_index_from_row_col(text, range.start.line, range.start.character);
+            let end = get_index_from_row_col(text, range.end.line, range.end.character).max(start);
+            text.replace_range(start..end, &content_change.text);
+        }
+        None => *text = content_change.text.clone(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use lsp_types::{Position, Range, TextDocumentContentChangeEvent};
+
+    use super::{apply_content_change, get_index_from_row_col, get_row_col_from_index};
+
+    #[test]
+    fn row_col_from_index() {
+        let source = "field Pet.Name {\n  name\n}";
+        assert_eq!(get_row_col_from_index(source, 0), (0, 0));
+        assert_eq!(get_row_col_from_index(source, 6), (0, 6));
+        assert_eq!(get_row_col_from_index(source, 19), (1, 2));
+        assert_eq!(get_row_col_from_index(source, 1000), (2, 1));
+    }
+
+    #[test]
+    fn index_from_row_col() {
+        let source = "field Pet.Name {\n  name\n}";
+        assert_eq!(get_index_from_row_col(source, 0, 6), 6);
+        assert_eq!(get_index_from_row_col(source, 1, 2), 19);
+        assert_eq!(get_index_from_row_col(source, 2, 0), 24);
+    }
+
+    #[test]
+    fn content_changes() {
+        let mut text = "field Pet.Name {\n  name\n}".to_string();
+        let change =
+            |start: (u32, u32), end: (u32, u32), new_text: &str| TextDocumentContentChangeEvent {
+                range: Some(Range::new(
+                    Position::new(start.0, start.1),
+                    Position::new(end.0, end.1),
+                )),
+                range_length: None,
+                text: new_text.to_string(),
+            };
+        apply_content_change(&mut text, &change((1, 2), (1, 6), "nickname"));
+        assert_eq!(text, "field Pet.Name {\n  nickname\n}");
+        apply_content_change(&mut text, &change((1, 10), (1, 10), "\n  age"));
+        assert_eq!(text, "field Pet.Name {\n  nickname\n  age\n}");
+        apply_content_change(&mut text, &change((0, 16), (2, 5), ""));
+        assert_eq!(text, "field Pet.Name {\n}");
+        apply_content_change(
+            &mut text,
+            &TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: "field Pet.Age {}".to_string(),
+            },
+        );
+        assert_eq!(text, "field Pet.Age {}");
+    }
+
+    #[test]
+    fn multi_byte_characters() {
+        // é is two bytes and one UTF-16 code unit; 😀 is four bytes and two UTF-16
+        // code units.
+        let source = "éé\nx😀y\nz";
+        assert_eq!(get_index_from_row_col(source, 0, 1), 2);
+        assert_eq!(get_index_from_row_col(source, 0, 2), 4);
+        assert_eq!(get_index_from_row_col(source, 0, 10), 4);
+        assert_eq!(get_index_from_row_col(source, 1, 0), 5);
+        assert_eq!(get_index_from_row_col(source, 1, 1), 6);
+        assert_eq!(get_index_from_row_col(source, 1, 3), 10);
+        assert_eq!(get_index_from_row_col(source, 1, 4), 11);
+        assert_eq!(get_index_from_row_col(source, 2, 1), 13);
+
+        assert_eq!(get_row_col_from_index(source, 2), (0, 1));
+        assert_eq!(get_row_col_from_index(source, 5), (1, 0));
+        assert_eq!(get_row_col_from_index(source, 10), (1, 3));
+        // Indexes within a character are rounded down to the start of it
+        assert_eq!(get_row_col_from_index(source, 8), (1, 1));
+
+        for index in [0, 2, 4, 5, 6, 10, 11, 12, 13] {
+            let (line, character) = get_row_col_from_index(source, index);
+            assert_eq!(get_index_from_row_col(source, line, character), index);
         }
     }
 
-    let remaining_rows = diff.delta_start();
+    #[test]
+    fn content_changes_with_multi_byte_characters() {
+        let change =
+            |start: (u32, u32), end: (u32, u32), new_text: &str| TextDocumentContentChangeEvent {
+                range: Some(Range::new(
+                    Position::new(start.0, start.1),
+                    Position::new(end.0, end.1),
+                )),
+                range_length: None,
+                text: new_text.to_string(),
+            };
 
-    index + remaining_rows as usize
+        let mut text = "éé\nx".to_string();
+        apply_content_change(&mut text, &change((1, 0), (1, 0), "y"));
+        assert_eq!(text, "éé\nyx");
+
+        let mut text = "// 😀 é\nfield Pet.Name {}".to_string();
+        apply_content_change(&mut text, &change((0, 6), (0, 7), "e"));
+        assert_eq!(text, "// 😀 e\nfield Pet.Name {}");
+        apply_content_change(&mut text, &change((0, 3), (0, 5), "🎉🎉"));
+        assert_eq!(text, "// 🎉🎉 e\nfield Pet.Name {}");
+        apply_content_change(&mut text, &change((1, 10), (1, 14), "Age"));
+        assert_eq!(text, "// 🎉🎉 e\nfield Pet.Age {}");
+    }
 }
@@ -0,0 +1,82 @@
+use std::fmt::Debug;
+
+use isograph_lang_types::{
+    SelectableServerFieldId, SelectionType, TypeAnnotation, UnionVariant, VariableDefinition,
+};
+use isograph_schema::{get_name, ValidatedSchema, ValidatedSchemaServerField};
+
+/// Print the GraphQL type of a server field, e.g. `[Pet!]!`.
+pub(crate) fn print_server_field_type(
+    schema: &ValidatedSchema,
+    server_field: &ValidatedSchemaServerField,
+) -> String {
+    match &server_field.associated_data {
+        SelectionType::Object(associated_data) => {
+            print_type_annotation(&associated_data.type_name, &|object_id| {
+                schema.server_field_data.object(object_id).name.to_string()
+            })
+        }
+        SelectionType::Scalar(type_annotation) => {
+            print_type_annotation(type_annotation, &|scalar_id| {
+                schema
+                    .server_field_data
+                    .scalar(scalar_id)
+                    .name
+                    .item
+                    .to_string()
+            })
+        }
+    }
+}
+
+fn print_type_annotation<T: Ord + Copy + Debug>(
+    type_annotation: &TypeAnnotation<T>,
+    print_name: &impl Fn(T) -> String,
+) -> String {
+    match type_annotation {
+        TypeAnnotation::Scalar(inner) => format!("{}!", print_name(*inner)),
+        TypeAnnotation::Plural(inner) => {
+            format!("[{}]!", print_type_annotation(inner, print_name))
+        }
+        TypeAnnotation::Union(union_type_annotation) => {
+            let variants = union_type_annotation
+                .variants
+                .iter()
+                .map(|variant| match variant {
+                    UnionVariant::Scalar(inner) => print_name(*inner),
+                    UnionVariant::Plural(inner) => {
+                        format!("[{}]", print_type_annotation(inner, print_name))
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" | ");
+            if union_type_annotation.nullable {
+                variants
+            } else {
+                format!("{}!", variants)
+            }
+        }
+    }
+}
+
+/// Print a list of variable definitions as GraphQL arguments, e.g.
+/// `(first: Int!, after: String)`. Returns an empty string if there are none.
+pub(crate) fn print_variable_definitions<'a>(
+    schema: &ValidatedSchema,
+    variable_definitions: impl Iterator<Item = &'a VariableDefinition<SelectableServerFieldId>>,
+) -> String {
+    let variable_definitions = variable_definitions
+        .map(|variable_definition| {
+            let type_ = variable_definition
+                .type_
+                .clone()
+                .map(|type_id| get_name(schema.server_field_data.lookup_unvalidated_type(type_id)));
+            format!("{}: {}", variable_definition.name.item, type_)
+        })
+        .collect::<Vec<_>>();
+    if variable_definitions.is_empty() {
+        String::new()
+    } else {
+        format!("({})", variable_definitions.join(", "))
+    }
+}
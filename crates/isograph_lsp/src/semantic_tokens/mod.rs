@@ -6,17 +6,21 @@ pub(crate) mod semantic_token_legend;
 use crate::{
     lsp_runtime_error::LSPRuntimeResult,
     lsp_state::LSPState,
-    row_col_offset::{diff_to_end_of_slice, get_index_from_diff, RowColDiff},
+    row_col_offset::{
+        diff_to_end_of_slice, get_index_from_diff, get_index_from_row_col, RowColDiff,
+    },
 };
 use client_field::client_field_declaration_to_tokens;
-use common_lang_types::{Span, TextSource};
 use entrypoint::entrypoint_declaration_to_tokens;
-use intern::string_key::Intern;
-use isograph_compiler::{extract_iso_literals_from_file_content, IsoLiteralExtraction};
-use isograph_lang_parser::{parse_iso_literal, IsoLiteralExtractionResult};
+use isograph_lang_parser::IsoLiteralExtractionResult;
 use lsp_types::{
-    request::{Request, SemanticTokensFullRequest},
-    SemanticToken, SemanticTokens, SemanticTokensParams, SemanticTokensResult,
+    request::{
+        Request, SemanticTokensFullDeltaRequest, SemanticTokensFullRequest,
+        SemanticTokensRangeRequest,
+    },
+    Range, SemanticToken, SemanticTokens, SemanticTokensDelta, SemanticTokensDeltaParams,
+    SemanticTokensEdit, SemanticTokensFullDeltaResult, SemanticTokensParams,
+    SemanticTokensRangeParams, SemanticTokensRangeResult, SemanticTokensResult, Url,
 };
 
 pub fn on_semantic_token_full_request(
@@ -29,13 +33,82 @@ pub fn on_semantic_token_full_request(
         partial_result_params: _,
     } = params;
 
-    let file_text = state.text_for(&text_document.uri).unwrap_or_else(|| {
+    let semantic_tokens = semantic_tokens(state, &text_document.uri, None);
+    state
+        .semantic_tokens
+        .insert(text_document.uri, semantic_tokens.clone());
+    Ok(Some(SemanticTokensResult::Tokens(semantic_tokens)))
+}
+
+/// Respond with the edits that turn the previously sent semantic tokens into the
+/// current ones. If we no longer have the previous tokens, respond with all of
+/// the current tokens instead.
+pub fn on_semantic_token_full_delta_request(
+    state: &mut LSPState,
+    params: <SemanticTokensFullDeltaRequest as Request>::Params,
+) -> LSPRuntimeResult<<SemanticTokensFullDeltaRequest as Request>::Result> {
+    let SemanticTokensDeltaParams {
+        text_document,
+        previous_result_id,
+        work_done_progress_params: _,
+        partial_result_params: _,
+    } = params;
+
+    let semantic_tokens = semantic_tokens(state, &text_document.uri, None);
+    let result = match state.semantic_tokens.get(&text_document.uri) {
+        Some(previous_semantic_tokens)
+            if previous_semantic_tokens.result_id.as_ref() == Some(&previous_result_id) =>
+        {
+            SemanticTokensFullDeltaResult::TokensDelta(SemanticTokensDelta {
+                result_id: semantic_tokens.result_id.clone(),
+                edits: semantic_tokens_edits(&previous_semantic_tokens.data, &semantic_tokens.data),
+            })
+        }
+        _ => SemanticTokensFullDeltaResult::Tokens(semantic_tokens.clone()),
+    };
+    state
+        .semantic_tokens
+        .insert(text_document.uri, semantic_tokens);
+    Ok(Some(result))
+}
+
+pub fn on_semantic_token_range_request(
+    state: &mut LSPState,
+    params: <SemanticTokensRangeRequest as Request>::Params,
+) -> LSPRuntimeResult<<SemanticTokensRangeRequest as Request>::Result> {
+    let SemanticTokensRangeParams {
+        text_document,
+        range,
+        work_done_progress_params: _,
+        partial_result_params: _,
+    } = params;
+
+    let SemanticTokens { data, .. } = semantic_tokens(state, &text_document.uri, Some(range));
+    Ok(Some(SemanticTokensRangeResult::Tokens(SemanticTokens {
+        result_id: None,
+        data,
+    })))
+}
+
+/// The semantic tokens for the iso literals in an open document. If a range is
+/// provided, only iso literals that intersect it are included. The result id is
+/// the version of the document, since the tokens depend only on its contents.
+fn semantic_tokens(state: &LSPState, uri: &Url, range: Option<Range>) -> SemanticTokens {
+    let file_text = state.text_for(uri).unwrap_or_else(|| {
         panic!(
             "Retrieving semantic tokens for document {}, which has not been opened before.",
-            text_document.uri
+            uri
+        )
+    });
+    let iso_literals = state
+        .iso_literals_for(uri)
+        .expect("Expected document to be open");
+    let range = range.map(|range| {
+        (
+            get_index_from_row_col(file_text, range.start.line, range.start.character),
+            get_index_from_row_col(file_text, range.end.line, range.end.character),
         )
     });
-    let literal_extractions = extract_iso_literals_from_file_content(file_text);
     let mut semantic_tokens = vec![];
 
     // SemanticTokens are all relative to the start of the previous one, so we have to
@@ -43,51 +116,71 @@ pub fn on_semantic_token_full_request(
     // semantic_tokens
     let mut index_of_last_token = 0;
 
-    // N.B. we are relying on the literal extractions being in order on the page.
-    for literal_extraction in literal_extractions {
-        let IsoLiteralExtraction {
-            iso_literal_text,
-            iso_literal_start_index,
-            const_export_name,
-            ..
-        } = literal_extraction;
+    // N.B. we are relying on the iso literals being in order on the page.
+    for (iso_literal_extraction_result, text_source) in iso_literals {
+        let iso_literal_span = match text_source.span {
+            Some(span) => span,
+            None => continue,
+        };
+        let iso_literal_start_index = iso_literal_span.start as usize;
+        let iso_literal_end_index = iso_literal_span.end as usize;
+        if let Some((range_start, range_end)) = range {
+            if iso_literal_end_index < range_start || range_end < iso_literal_start_index {
+                continue;
+            }
+        }
+        let iso_literal_text = &file_text[iso_literal_start_index..iso_literal_end_index];
 
         let initial_diff =
             diff_to_end_of_slice(&file_text[index_of_last_token..iso_literal_start_index]);
 
-        let file_path = text_document.uri.path().intern();
-        let text_source = TextSource {
-            path: file_path.into(),
-            span: Some(Span::new(
-                iso_literal_start_index as u32,
-                (iso_literal_start_index + iso_literal_text.len()) as u32,
-            )),
-        };
-        let iso_literal_extraction_result = parse_iso_literal(
+        // token_diff is from the start of the previous last token to the
+        // start of the current last token
+        let (new_tokens, token_diff) = iso_literal_parse_result_to_tokens(
+            iso_literal_extraction_result.clone(),
             iso_literal_text,
-            file_path.into(),
-            const_export_name,
-            text_source,
+            initial_diff,
         );
-        if let Ok(iso_literal_extraction_result) = iso_literal_extraction_result {
-            // token_diff is from the start of the previous last token to the
-            // start of the current last token
-            let (new_tokens, token_diff) = iso_literal_parse_result_to_tokens(
-                iso_literal_extraction_result,
-                iso_literal_text,
-                initial_diff,
-            );
-            semantic_tokens.extend(new_tokens);
-            let additional_index =
-                get_index_from_diff(&file_text[index_of_last_token..], token_diff);
-            index_of_last_token += additional_index;
-        }
+        semantic_tokens.extend(new_tokens);
+        let additional_index = get_index_from_diff(&file_text[index_of_last_token..], token_diff);
+        index_of_last_token += additional_index;
     }
-    let result = SemanticTokensResult::Tokens(SemanticTokens {
+    SemanticTokens {
+        result_id: state.version_for(uri).map(|version| version.to_string()),
         data: semantic_tokens,
-        result_id: None,
-    });
-    Ok(Some(result))
+    }
+}
+
+/// A single edit that replaces the tokens between the common prefix and the
+/// common suffix of the previous and current tokens. Edits refer to positions in
+/// the flattened array of integers, in which each token takes up five integers.
+fn semantic_tokens_edits(
+    previous: &[SemanticToken],
+    current: &[SemanticToken],
+) -> Vec<SemanticTokensEdit> {
+    const INTEGERS_PER_TOKEN: usize = 5;
+
+    let prefix_length = previous
+        .iter()
+        .zip(current)
+        .take_while(|(previous_token, current_token)| previous_token == current_token)
+        .count();
+    if prefix_length == previous.len() && prefix_length == current.len() {
+        return vec![];
+    }
+    let suffix_length = previous[prefix_length..]
+        .iter()
+        .rev()
+        .zip(current[prefix_length..].iter().rev())
+        .take_while(|(previous_token, current_token)| previous_token == current_token)
+        .count();
+
+    vec![SemanticTokensEdit {
+        start: (prefix_length * INTEGERS_PER_TOKEN) as u32,
+        delete_count: ((previous.len() - prefix_length - suffix_length) * INTEGERS_PER_TOKEN)
+            as u32,
+        data: Some(current[prefix_length..current.len() - suffix_length].to_vec()),
+    }]
 }
 
 fn iso_literal_parse_result_to_tokens(
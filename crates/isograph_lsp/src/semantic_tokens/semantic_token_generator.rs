@@ -1,7 +1,7 @@
 use common_lang_types::Span;
 use lsp_types::SemanticToken;
 
-use crate::row_col_offset::{diff_to_end_of_slice, RowColDiff};
+use crate::row_col_offset::{diff_to_end_of_slice, utf16_len, RowColDiff};
 
 #[derive(Debug)]
 pub(crate) enum SemanticTokenGeneratorState {
@@ -28,7 +28,7 @@ impl<'a> SemanticTokenGenerator<'a> {
                 SemanticToken {
                     delta_line: diff.delta_line(),
                     delta_start: diff.delta_start(),
-                    length: span.len(),
+                    length: self.utf16_len(span),
                     token_type,
                     token_modifiers_bitset: 0,
                 }
@@ -42,7 +42,7 @@ impl<'a> SemanticTokenGenerator<'a> {
                 SemanticToken {
                     delta_line: diff.delta_line(),
                     delta_start: diff.delta_start(),
-                    length: span.len(),
+                    length: self.utf16_len(span),
                     token_type,
                     token_modifiers_bitset: 0,
                 }
@@ -51,6 +51,10 @@ impl<'a> SemanticTokenGenerator<'a> {
         self.tokens.push(token);
     }
 
+    fn utf16_len(&self, span: Span) -> u32 {
+        utf16_len(&self.text[span.start as usize..span.end as usize]) as u32
+    }
+
     pub(crate) fn new(text: &'a str, initial_diff: RowColDiff) -> Self {
         Self {
             state: SemanticTokenGeneratorState::InitialDiff(initial_diff),
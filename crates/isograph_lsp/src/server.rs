@@ -1,26 +1,52 @@
-use std::ops::ControlFlow;
+use std::{ops::ControlFlow, time::Duration};
 
 use crate::{
+    code_actions::on_code_action,
+    code_lens::on_code_lens,
+    completion::on_completion,
+    diagnostics::recompile_and_publish_diagnostics,
+    goto_definition::on_goto_definition,
+    hover::on_hover,
+    inlay_hints::on_inlay_hint,
     lsp_notification_dispatch::LSPNotificationDispatch,
     lsp_process_error::LSPProcessResult,
     lsp_request_dispatch::LSPRequestDispatch,
     lsp_runtime_error::LSPRuntimeError,
     lsp_state::LSPState,
+    references::on_references,
+    rename::{on_prepare_rename, on_rename},
     semantic_tokens::{
-        on_semantic_token_full_request, semantic_token_legend::semantic_token_legend,
+        on_semantic_token_full_delta_request, on_semantic_token_full_request,
+        on_semantic_token_range_request, semantic_token_legend::semantic_token_legend,
     },
+    symbols::{on_document_symbol, on_workspace_symbol},
     text_document::{
         on_did_change_text_document, on_did_close_text_document, on_did_open_text_document,
+        on_did_save_text_document,
+    },
+    watched_files::{
+        client_supports_watched_files, on_did_change_watched_files, register_watched_files,
     },
 };
+use crossbeam::channel::RecvTimeoutError;
 use isograph_config::CompilerConfig as Config;
 use lsp_server::{Connection, ErrorCode, Response, ResponseError};
-use lsp_types::request::SemanticTokensFullRequest;
+use lsp_types::request::{
+    CodeActionRequest, CodeLensRequest, Completion, DocumentSymbolRequest, GotoDefinition,
+    HoverRequest, InlayHintRequest, PrepareRenameRequest, References, Rename,
+    SemanticTokensFullDeltaRequest, SemanticTokensFullRequest, SemanticTokensRangeRequest,
+    WorkspaceSymbolRequest,
+};
 use lsp_types::{
-    notification::{DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument},
-    InitializeParams, SemanticTokensFullOptions, SemanticTokensOptions,
-    SemanticTokensServerCapabilities, ServerCapabilities, TextDocumentSyncCapability,
-    TextDocumentSyncKind, WorkDoneProgressOptions,
+    notification::{
+        DidChangeTextDocument, DidChangeWatchedFiles, DidCloseTextDocument, DidOpenTextDocument,
+        DidSaveTextDocument,
+    },
+    CodeActionKind, CodeActionOptions, CodeActionProviderCapability, CodeLensOptions,
+    CompletionOptions, HoverProviderCapability, InitializeParams, OneOf, RenameOptions,
+    SemanticTokensFullOptions, SemanticTokensOptions, SemanticTokensServerCapabilities,
+    ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions,
+    TextDocumentSyncSaveOptions, WorkDoneProgressOptions,
 };
 
 /// Initializes an LSP connection, handling the `initialize` message and `initialized` notification
@@ -28,15 +54,48 @@ use lsp_types::{
 pub fn initialize(connection: &Connection) -> LSPProcessResult<InitializeParams> {
     let server_capabilities = ServerCapabilities {
         // Enable text document syncing so we can know when files are opened/changed/saved/closed
-        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        text_document_sync: Some(TextDocumentSyncCapability::Options(
+            TextDocumentSyncOptions {
+                open_close: Some(true),
+                change: Some(TextDocumentSyncKind::INCREMENTAL),
+                save: Some(TextDocumentSyncSaveOptions::Supported(true)),
+                ..Default::default()
+            },
+        )),
         semantic_tokens_provider: Some(SemanticTokensServerCapabilities::SemanticTokensOptions(
             SemanticTokensOptions {
                 work_done_progress_options: WorkDoneProgressOptions::default(),
                 legend: semantic_token_legend(),
-                range: None,
-                full: Some(SemanticTokensFullOptions::Bool(true)),
+                range: Some(true),
+                full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
             },
         )),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        references_provider: Some(OneOf::Left(true)),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        workspace_symbol_provider: Some(OneOf::Left(true)),
+        code_lens_provider: Some(CodeLensOptions {
+            resolve_provider: Some(false),
+        }),
+        inlay_hint_provider: Some(OneOf::Left(true)),
+        code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
+            code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+            ..Default::default()
+        })),
+        rename_provider: Some(OneOf::Right(RenameOptions {
+            prepare_provider: Some(true),
+            work_done_progress_options: WorkDoneProgressOptions::default(),
+        })),
+        completion_provider: Some(CompletionOptions {
+            trigger_characters: Some(
+                [".", "$", "@", "{", "("]
+                    .into_iter()
+                    .map(|trigger_character| trigger_character.to_string())
+                    .collect(),
+            ),
+            ..Default::default()
+        }),
         ..Default::default()
     };
     let server_capabilities = serde_json::to_value(server_capabilities)?;
@@ -45,15 +104,40 @@ pub fn initialize(connection: &Connection) -> LSPProcessResult<InitializeParams>
     Ok(params)
 }
 
+/// How long the server waits for further changes before re-validating the project.
+const RECOMPILE_DEBOUNCE: Duration = Duration::from_millis(150);
+
 /// Run the main server loop
 pub async fn run(
     connection: Connection,
-    _config: Config,
-    _params: InitializeParams,
+    config: Config,
+    params: InitializeParams,
 ) -> LSPProcessResult<()> {
     eprintln!("Running server loop");
-    let mut state = LSPState::new(connection.sender.clone());
-    while let Ok(message) = connection.receiver.recv() {
+    let mut state = LSPState::new(connection.sender.clone(), config);
+    if client_supports_watched_files(&params) {
+        register_watched_files(&mut state);
+    }
+    recompile_and_publish_diagnostics(&mut state);
+    loop {
+        // Changes are not validated immediately. Instead, we wait until no further
+        // messages arrive for RECOMPILE_DEBOUNCE, so that we do not re-validate the
+        // whole project after every keystroke.
+        let message = if state.recompile_scheduled {
+            match connection.receiver.recv_timeout(RECOMPILE_DEBOUNCE) {
+                Ok(message) => message,
+                Err(RecvTimeoutError::Timeout) => {
+                    recompile_and_publish_diagnostics(&mut state);
+                    continue;
+                }
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+        } else {
+            match connection.receiver.recv() {
+                Ok(message) => message,
+                Err(_) => break,
+            }
+        };
         match message {
             lsp_server::Message::Request(request) => {
                 eprintln!("Received request: {:?}", request);
@@ -62,7 +146,11 @@ pub async fn run(
                 state.send_message(response.into());
             }
             lsp_server::Message::Notification(notification) => {
-                dispatch_notification(notification, &mut state);
+                if let ControlFlow::Break(Some(LSPRuntimeError::UnexpectedError(message))) =
+                    dispatch_notification(notification, &mut state)
+                {
+                    eprintln!("Error handling notification: {}", message);
+                }
             }
             lsp_server::Message::Response(response) => {
                 eprintln!("Received response: {:?}", response);
@@ -81,6 +169,8 @@ fn dispatch_notification(
         .on_notification_sync::<DidOpenTextDocument>(on_did_open_text_document)?
         .on_notification_sync::<DidCloseTextDocument>(on_did_close_text_document)?
         .on_notification_sync::<DidChangeTextDocument>(on_did_change_text_document)?
+        .on_notification_sync::<DidSaveTextDocument>(on_did_save_text_document)?
+        .on_notification_sync::<DidChangeWatchedFiles>(on_did_change_watched_files)?
         .notification();
 
     ControlFlow::Continue(())
@@ -91,6 +181,21 @@ fn dispatch_request(request: lsp_server::Request, lsp_state: &mut LSPState) -> R
     let get_response = || {
         let request = LSPRequestDispatch::new(request, lsp_state)
             .on_request_sync::<SemanticTokensFullRequest>(on_semantic_token_full_request)?
+            .on_request_sync::<SemanticTokensFullDeltaRequest>(
+                on_semantic_token_full_delta_request,
+            )?
+            .on_request_sync::<SemanticTokensRangeRequest>(on_semantic_token_range_request)?
+            .on_request_sync::<HoverRequest>(on_hover)?
+            .on_request_sync::<GotoDefinition>(on_goto_definition)?
+            .on_request_sync::<Completion>(on_completion)?
+            .on_request_sync::<References>(on_references)?
+            .on_request_sync::<PrepareRenameRequest>(on_prepare_rename)?
+            .on_request_sync::<Rename>(on_rename)?
+            .on_request_sync::<CodeActionRequest>(on_code_action)?
+            .on_request_sync::<DocumentSymbolRequest>(on_document_symbol)?
+            .on_request_sync::<WorkspaceSymbolRequest>(on_workspace_symbol)?
+            .on_request_sync::<CodeLensRequest>(on_code_lens)?
+            .on_request_sync::<InlayHintRequest>(on_inlay_hint)?
             .request();
 
         // If we have gotten here, we have not handled the request
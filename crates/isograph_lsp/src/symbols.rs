@@ -0,0 +1,235 @@
+use common_lang_types::{Span, TextSource, WithSpan};
+use isograph_lang_parser::IsoLiteralExtractionResult;
+use isograph_lang_types::{ServerFieldSelection, UnvalidatedSelectionWithUnvalidatedDirectives};
+use lsp_types::{
+    request::{DocumentSymbolRequest, Request, WorkspaceSymbolRequest},
+    DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse, Location, SymbolInformation,
+    SymbolKind, WorkspaceSymbolParams, WorkspaceSymbolResponse,
+};
+
+use crate::{
+    iso_literals::files_with_iso_literals, lsp_runtime_error::LSPRuntimeResult,
+    lsp_state::LSPState, row_col_offset::span_to_range,
+};
+
+/// List the iso literals in a document (client fields, client pointers and
+/// entrypoints), along with their (nested) selections.
+pub fn on_document_symbol(
+    state: &mut LSPState,
+    params: <DocumentSymbolRequest as Request>::Params,
+) -> LSPRuntimeResult<<DocumentSymbolRequest as Request>::Result> {
+    let DocumentSymbolParams { text_document, .. } = params;
+
+    let file_text = match state.text_for_or_read(&text_document.uri) {
+        Some(file_text) => file_text,
+        None => return Ok(None),
+    };
+    let symbols = state
+        .iso_literals_for_or_parse(&text_document.uri, &file_text)
+        .iter()
+        .filter_map(|(extraction_result, text_source)| {
+            iso_literal_symbol(&file_text, extraction_result, text_source)
+        })
+        .collect();
+
+    Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+}
+
+/// Find the client fields, client pointers and entrypoints in the project whose
+/// name (e.g. `User.Avatar`) fuzzily matches the query.
+pub fn on_workspace_symbol(
+    state: &mut LSPState,
+    params: <WorkspaceSymbolRequest as Request>::Params,
+) -> LSPRuntimeResult<<WorkspaceSymbolRequest as Request>::Result> {
+    let WorkspaceSymbolParams { query, .. } = params;
+
+    let mut symbols = vec![];
+    for uri in files_with_iso_literals(state) {
+        let file_text = match state.text_for_or_read(&uri) {
+            Some(file_text) => file_text,
+            None => continue,
+        };
+        for (extraction_result, text_source) in
+            state.iso_literals_for_or_parse(&uri, &file_text).iter()
+        {
+            let literal_start = match text_source.span {
+                Some(span) => span.start,
+                None => continue,
+            };
+            let (name, kind, name_span) = iso_literal_name(extraction_result);
+            if !fuzzy_matches(&query, &name) {
+                continue;
+            }
+            #[allow(deprecated)]
+            symbols.push(SymbolInformation {
+                name,
+                kind,
+                tags: None,
+                deprecated: None,
+                location: Location {
+                    uri: uri.clone(),
+                    range: span_to_range(&file_text, name_span.with_offset(literal_start)),
+                },
+                container_name: None,
+            });
+        }
+    }
+    symbols.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(Some(WorkspaceSymbolResponse::Flat(symbols)))
+}
+
+/// The name of the client field, client pointer or entrypoint declared in an
+/// iso literal (e.g. `User.Avatar`), its kind, and the span of the field name
+/// relative to the iso literal.
+fn iso_literal_name(extraction_result: &IsoLiteralExtractionResult) -> (String, SymbolKind, Span) {
+    match extraction_result {
+        IsoLiteralExtractionResult::ClientFieldDeclaration(client_field_declaration) => {
+            let client_field_declaration = &client_field_declaration.item;
+            (
+                format!(
+                    "{}.{}",
+                    client_field_declaration.parent_type.item,
+                    client_field_declaration.client_field_name.item
+                ),
+                SymbolKind::METHOD,
+                client_field_declaration.client_field_name.span,
+            )
+        }
+        IsoLiteralExtractionResult::ClientPointerDeclaration(client_pointer_declaration) => {
+            let client_pointer_declaration = &client_pointer_declaration.item;
+            (
+                format!(
+                    "{}.{}",
+                    client_pointer_declaration.parent_type.item,
+                    client_pointer_declaration.client_pointer_name.item
+                ),
+                SymbolKind::PROPERTY,
+                client_pointer_declaration.client_pointer_name.span,
+            )
+        }
+        IsoLiteralExtractionResult::EntrypointDeclaration(entrypoint_declaration) => {
+            let entrypoint_declaration = &entrypoint_declaration.item;
+            (
+                format!(
+                    "{}.{}",
+                    entrypoint_declaration.parent_type.item,
+                    entrypoint_declaration.client_field_name.item
+                ),
+                SymbolKind::EVENT,
+                entrypoint_declaration.client_field_name.span,
+            )
+        }
+    }
+}
+
+fn iso_literal_symbol(
+    file_text: &str,
+    extraction_result: &IsoLiteralExtractionResult,
+    text_source: &TextSource,
+) -> Option<DocumentSymbol> {
+    let literal_span = text_source.span?;
+    let literal_start = literal_span.start;
+    let (name, kind, name_span) = iso_literal_name(extraction_result);
+    let (detail, selection_set) = match extraction_result {
+        IsoLiteralExtractionResult::ClientFieldDeclaration(client_field_declaration) => (
+            "client field",
+            Some(&client_field_declaration.item.selection_set),
+        ),
+        IsoLiteralExtractionResult::ClientPointerDeclaration(client_pointer_declaration) => (
+            "client pointer",
+            Some(&client_pointer_declaration.item.selection_set),
+        ),
+        IsoLiteralExtractionResult::EntrypointDeclaration(_) => ("entrypoint", None),
+    };
+
+    #[allow(deprecated)]
+    Some(DocumentSymbol {
+        name,
+        detail: Some(detail.to_string()),
+        kind,
+        tags: None,
+        deprecated: None,
+        range: span_to_range(file_text, literal_span),
+        selection_range: span_to_range(file_text, name_span.with_offset(literal_start)),
+        children: selection_set
+            .map(|selection_set| selection_symbols(file_text, literal_start, selection_set)),
+    })
+}
+
+fn selection_symbols(
+    file_text: &str,
+    literal_start: u32,
+    selection_set: &[WithSpan<UnvalidatedSelectionWithUnvalidatedDirectives>],
+) -> Vec<DocumentSymbol> {
+    selection_set
+        .iter()
+        .map(|selection| {
+            let (name, alias, name_span, children) = match &selection.item {
+                ServerFieldSelection::ScalarField(scalar_field) => (
+                    scalar_field.name.item.to_string(),
+                    scalar_field
+                        .reader_alias
+                        .map(|alias| alias.item.to_string()),
+                    scalar_field.name.location.span(),
+                    None,
+                ),
+                ServerFieldSelection::LinkedField(linked_field) => (
+                    linked_field.name.item.to_string(),
+                    linked_field
+                        .reader_alias
+                        .map(|alias| alias.item.to_string()),
+                    linked_field.name.location.span(),
+                    Some(selection_symbols(
+                        file_text,
+                        literal_start,
+                        &linked_field.selection_set,
+                    )),
+                ),
+            };
+            let range = span_to_range(file_text, selection.span.with_offset(literal_start));
+
+            #[allow(deprecated)]
+            DocumentSymbol {
+                name: match alias {
+                    Some(alias) => format!("{}: {}", alias, name),
+                    None => name,
+                },
+                detail: None,
+                kind: SymbolKind::FIELD,
+                tags: None,
+                deprecated: None,
+                range,
+                selection_range: name_span
+                    .map(|name_span| span_to_range(file_text, name_span.with_offset(literal_start)))
+                    .unwrap_or(range),
+                children,
+            }
+        })
+        .collect()
+}
+
+/// Whether the characters of `query` appear in `name`, in order, ignoring case.
+/// For example, `usav` matches `User.Avatar`.
+fn fuzzy_matches(query: &str, name: &str) -> bool {
+    let mut name_chars = name.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|query_char| name_chars.any(|name_char| name_char == query_char))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fuzzy_matches;
+
+    #[test]
+    fn fuzzy_matching() {
+        assert!(fuzzy_matches("", "User.Avatar"));
+        assert!(fuzzy_matches("User.Avatar", "User.Avatar"));
+        assert!(fuzzy_matches("usav", "User.Avatar"));
+        assert!(fuzzy_matches("u.a", "User.Avatar"));
+        assert!(!fuzzy_matches("avus", "User.Avatar"));
+        assert!(!fuzzy_matches("User.Avatars", "User.Avatar"));
+    }
+}
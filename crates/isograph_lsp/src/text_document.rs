@@ -1,6 +1,7 @@
 use lsp_types::{
     notification::{
-        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification,
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
+        Notification,
     },
     DidChangeTextDocumentParams, DidOpenTextDocumentParams, TextDocumentItem,
 };
@@ -12,9 +13,13 @@ pub fn on_did_open_text_document(
     params: <DidOpenTextDocument as Notification>::Params,
 ) -> LSPRuntimeResult<()> {
     let DidOpenTextDocumentParams { text_document } = params;
-    let TextDocumentItem { text, uri, .. } = text_document;
+    let TextDocumentItem {
+        text, uri, version, ..
+    } = text_document;
 
-    lsp_state.document_opened(&uri, &text)
+    lsp_state.document_opened(&uri, version, &text)?;
+    lsp_state.recompile_scheduled = true;
+    Ok(())
 }
 
 #[allow(clippy::unnecessary_wraps)]
@@ -23,7 +28,9 @@ pub fn on_did_close_text_document(
     params: <DidCloseTextDocument as Notification>::Params,
 ) -> LSPRuntimeResult<()> {
     let uri = params.text_document.uri;
-    lsp_state.document_closed(&uri)
+    lsp_state.document_closed(&uri)?;
+    lsp_state.recompile_scheduled = true;
+    Ok(())
 }
 
 pub fn on_did_change_text_document(
@@ -34,12 +41,20 @@ pub fn on_did_change_text_document(
         content_changes,
         text_document,
     } = params;
-    let uri = text_document.uri;
 
-    // We do full text document syncing, so the new text will be in the first content change event.
-    let content_change = content_changes
-        .first()
-        .expect("content_changes should always be non-empty");
+    // We do incremental text document syncing, so the content changes must be
+    // applied, in order, to the text we have.
+    lsp_state.document_changed(&text_document.uri, text_document.version, &content_changes)?;
+    lsp_state.recompile_scheduled = true;
+    Ok(())
+}
 
-    lsp_state.document_changed(&uri, &content_change.text)
+#[allow(clippy::unnecessary_wraps)]
+pub fn on_did_save_text_document(
+    _lsp_state: &mut LSPState,
+    _params: <DidSaveTextDocument as Notification>::Params,
+) -> LSPRuntimeResult<()> {
+    // The saved contents are the contents of the open document, which we already
+    // have. Changes to other files are handled in on_did_change_watched_files.
+    Ok(())
 }
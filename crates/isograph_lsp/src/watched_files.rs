@@ -0,0 +1,108 @@
+use isograph_config::CompilerConfig;
+use lsp_types::{
+    notification::{DidChangeWatchedFiles, Notification},
+    request::{RegisterCapability, UnregisterCapability},
+    DidChangeWatchedFilesParams, DidChangeWatchedFilesRegistrationOptions, FileSystemWatcher,
+    GlobPattern, InitializeParams, Registration, RegistrationParams, Unregistration,
+    UnregistrationParams,
+};
+
+use crate::{
+    lsp_runtime_error::LSPRuntimeResult,
+    lsp_state::{file_path, LSPState},
+};
+
+const WATCHED_FILES_REGISTRATION_ID: &str = "isograph-watched-files";
+
+/// Whether the client can notify us of changes to files on disk, such as
+/// changes made by git or by code generation.
+pub(crate) fn client_supports_watched_files(params: &InitializeParams) -> bool {
+    params
+        .capabilities
+        .workspace
+        .as_ref()
+        .and_then(|workspace| workspace.did_change_watched_files)
+        .and_then(|did_change_watched_files| did_change_watched_files.dynamic_registration)
+        .unwrap_or(false)
+}
+
+/// Ask the client to notify us when the config, the schema, the schema extensions
+/// or any file in the project root changes on disk.
+pub(crate) fn register_watched_files(state: &mut LSPState) {
+    let register_options = DidChangeWatchedFilesRegistrationOptions {
+        watchers: watched_file_patterns(&state.compiler_state.config)
+            .into_iter()
+            .map(|pattern| FileSystemWatcher {
+                glob_pattern: GlobPattern::String(pattern),
+                kind: None,
+            })
+            .collect(),
+    };
+    state.send_request::<RegisterCapability>(RegistrationParams {
+        registrations: vec![Registration {
+            id: WATCHED_FILES_REGISTRATION_ID.to_string(),
+            method: DidChangeWatchedFiles::METHOD.to_string(),
+            register_options: Some(
+                serde_json::to_value(register_options)
+                    .expect("Expected registration options to be serializable"),
+            ),
+        }],
+    });
+}
+
+fn unregister_watched_files(state: &mut LSPState) {
+    state.send_request::<UnregisterCapability>(UnregistrationParams {
+        unregisterations: vec![Unregistration {
+            id: WATCHED_FILES_REGISTRATION_ID.to_string(),
+            method: DidChangeWatchedFiles::METHOD.to_string(),
+        }],
+    });
+}
+
+fn watched_file_patterns(config: &CompilerConfig) -> Vec<String> {
+    // We watch every file in the project root, not just JavaScript files, so
+    // that we are notified when folders are moved or removed.
+    let mut patterns = vec![
+        format!("{}/**", config.project_root.display()),
+        config.schema.display().to_string(),
+        config.config_location.display().to_string(),
+    ];
+    patterns.extend(
+        config
+            .schema_extensions
+            .iter()
+            .map(|schema_extension| schema_extension.display().to_string()),
+    );
+    patterns
+}
+
+pub fn on_did_change_watched_files(
+    lsp_state: &mut LSPState,
+    params: <DidChangeWatchedFiles as Notification>::Params,
+) -> LSPRuntimeResult<()> {
+    let DidChangeWatchedFilesParams { changes } = params;
+
+    let config_location = lsp_state.compiler_state.config.config_location.clone();
+    if changes
+        .iter()
+        .any(|change| file_path(&change.uri).as_ref() == Some(&config_location))
+    {
+        // The project root, schema or schema extensions may have changed, so we
+        // re-read everything and watch the new set of files. If the config is
+        // invalid, we keep the previous project until it is fixed.
+        match lsp_state.reload_config() {
+            Ok(()) => {
+                lsp_state.config_error = None;
+                unregister_watched_files(lsp_state);
+                register_watched_files(lsp_state);
+            }
+            Err(e) => lsp_state.config_error = Some(e),
+        }
+    } else {
+        for change in changes {
+            lsp_state.path_changed_on_disk(&change.uri);
+        }
+    }
+    lsp_state.recompile_scheduled = true;
+    Ok(())
+}
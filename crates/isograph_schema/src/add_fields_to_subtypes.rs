@@ -7,7 +7,8 @@ use crate::{
 
 impl UnvalidatedSchema {
     /// For each supertype (e.g. Node), add the fields defined on it (e.g. Node.MyComponent)
-    /// to subtypes (e.g. creating User.MyComponent).
+    /// to subtypes (e.g. creating User.MyComponent). Subtypes include all descendants,
+    /// e.g. objects implementing an interface that implements Node.
     ///
     /// We do not transfer server fields (because that makes no sense in GraphQL, but does
     /// it make sense otherwise??) and refetch fields (which are already defined on all valid
@@ -36,10 +37,12 @@ impl UnvalidatedSchema {
                         crate::FieldType::ClientField(_) => {
                             let subtype = self.server_field_data.object_mut(*subtype_id);
 
+                            // Since interfaces can implement interfaces, a subtype can
+                            // receive the same client field from several supertypes.
                             if subtype
                                 .encountered_fields
                                 .insert(*supertype_field_name, *defined_field)
-                                .is_some()
+                                .is_some_and(|existing_field| existing_field != *defined_field)
                             {
                                 return Err(WithLocation::new(
                                     ProcessTypeDefinitionError::FieldExistsOnType {
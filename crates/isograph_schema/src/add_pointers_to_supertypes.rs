@@ -45,6 +45,10 @@ impl UnvalidatedSchema {
                                     .expect("Expected __typename to be server field"),
                             ),
                             selection_variant: ValidatedIsographSelectionVariant::Regular,
+                            conditions: vec![],
+                            required: None,
+                            is_updatable: false,
+                            is_deferred: false,
                         },
                         directives: vec![],
                         name: WithLocation::new(
@@ -59,6 +63,7 @@ impl UnvalidatedSchema {
                 let condition_selection_set = vec![typename_selection];
 
                 let server_field = SchemaServerField {
+                    deprecation_reason: None,
                     description: Some(
                         format!("A client pointer for the {} type.", subtype.name)
                             .intern()
@@ -79,6 +84,8 @@ impl UnvalidatedSchema {
                         ),
                     },
                     is_discriminator: false,
+                    is_client_only: false,
+                    is_semantic_non_null: false,
                 };
 
                 self.server_fields.push(server_field);
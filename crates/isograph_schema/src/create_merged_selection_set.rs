@@ -10,19 +10,22 @@ use graphql_lang_types::{
 use intern::{string_key::Intern, Lookup};
 use isograph_lang_types::{
     ArgumentKeyAndValue, ClientFieldId, IsographSelectionVariant, NonConstantValue,
-    RefetchQueryIndex, SelectableServerFieldId, SelectionFieldArgument, SelectionType,
-    ServerFieldId, ServerFieldSelection, ServerObjectId, VariableDefinition,
+    PaginatedDirectiveParameters, RefetchQueryIndex, SelectableServerFieldId, SelectionCondition,
+    SelectionFieldArgument, SelectionType, ServerFieldId, ServerFieldSelection, ServerObjectId,
+    VariableDefinition,
 };
 use lazy_static::lazy_static;
 
 use crate::{
     categorize_field_loadability, create_transformed_name_and_arguments,
     expose_field_directive::RequiresRefinement, transform_arguments_with_child_context,
-    transform_name_and_arguments_with_child_variable_context, FieldType,
-    ImperativelyLoadedFieldVariant, Loadability, NameAndArguments, PathToRefetchField,
-    RootOperationName, SchemaObject, SchemaServerFieldVariant, UnvalidatedVariableDefinition,
-    ValidatedClientField, ValidatedIsographSelectionVariant, ValidatedScalarFieldSelection,
-    ValidatedSchema, ValidatedSchemaIdField, ValidatedSelection, VariableContext,
+    transform_conditions_with_child_context,
+    transform_name_and_arguments_with_child_variable_context, ClientType, FieldType,
+    ImperativelyLoadedFieldVariant, Loadability, NameAndArguments, PaginationDirection,
+    PathToRefetchField, RootOperationName, SchemaObject, SchemaServerFieldVariant,
+    UnvalidatedVariableDefinition, ValidatedClientField, ValidatedIsographSelectionVariant,
+    ValidatedScalarFieldSelection, ValidatedSchema, ValidatedSchemaIdField, ValidatedSelection,
+    VariableContext, CURSOR_FIELD_NAME, EDGES_FIELD_NAME, PAGE_INFO_FIELD_NAME,
 };
 
 pub type MergedSelectionMap = BTreeMap<NormalizationKey, MergedServerSelection>;
@@ -49,7 +52,13 @@ lazy_static! {
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct RootRefetchedPath {
     pub field_name: SelectableFieldName,
-    pub path_to_refetch_field_info: PathToRefetchFieldInfo,
+    pub refetched_path_info: RefetchedPathInfo,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RefetchedPathInfo {
+    ImperativelyLoadedField(PathToRefetchFieldInfo),
+    PaginatedField(PaginatedFieldInfo),
 }
 
 // TODO add id and typename variants, impl Ord, and get rid of the NormalizationKey enum
@@ -59,13 +68,17 @@ pub enum MergedServerSelection {
     LinkedField(MergedLinkedFieldSelection),
     // TODO does this belong? This is very GraphQL specific.
     InlineFragment(MergedInlineFragmentSelection),
+    DeferredFragment(MergedDeferredFragmentSelection),
 }
 
 impl MergedServerSelection {
     pub fn reachable_variables(&self) -> Vec<VariableName> {
         match self {
-            MergedServerSelection::ScalarField(field) => get_variables(&field.arguments).collect(),
+            MergedServerSelection::ScalarField(field) => get_variables(&field.arguments)
+                .chain(get_condition_variables(&field.conditions))
+                .collect(),
             MergedServerSelection::LinkedField(field) => get_variables(&field.arguments)
+                .chain(get_condition_variables(&field.conditions))
                 .chain(
                     field
                         .selection_map
@@ -74,6 +87,11 @@ impl MergedServerSelection {
                 )
                 .collect(),
             MergedServerSelection::InlineFragment(_) => vec![],
+            MergedServerSelection::DeferredFragment(deferred_fragment) => deferred_fragment
+                .selection_map
+                .values()
+                .flat_map(|x| x.reachable_variables())
+                .collect(),
         }
     }
 }
@@ -85,11 +103,23 @@ fn get_variables(arguments: &[ArgumentKeyAndValue]) -> impl Iterator<Item = Vari
     })
 }
 
+fn get_condition_variables(
+    conditions: &[SelectionCondition],
+) -> impl Iterator<Item = VariableName> + '_ {
+    conditions
+        .iter()
+        .flat_map(|condition| match condition.value {
+            NonConstantValue::Variable(v) => Some(v),
+            _ => None,
+        })
+}
+
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
 pub struct MergedScalarFieldSelection {
     // TODO no location
     pub name: ScalarFieldName,
     pub arguments: Vec<ArgumentKeyAndValue>,
+    pub conditions: Vec<SelectionCondition>,
 }
 
 impl MergedScalarFieldSelection {
@@ -114,6 +144,8 @@ pub struct MergedLinkedFieldSelection {
     pub arguments: Vec<ArgumentKeyAndValue>,
     /// Some if the object is concrete; None otherwise.
     pub concrete_type: Option<IsographObjectTypeName>,
+    pub conditions: Vec<SelectionCondition>,
+    pub stream: Option<MergedStream>,
 }
 
 impl MergedLinkedFieldSelection {
@@ -130,6 +162,30 @@ impl MergedLinkedFieldSelection {
     }
 }
 
+/// A list field selected with @stream. Items after the first initial_count items
+/// are sent in later payloads, which are identified by the label.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct MergedStream {
+    pub label: String,
+    pub initial_count: u32,
+}
+
+impl MergedStream {
+    /// A list is streamed if any selection of it is streamed. If several selections are
+    /// streamed, each should receive at least as many items in the initial payload as
+    /// it asked for, so the largest initial count is used.
+    fn merge(existing: &mut Option<MergedStream>, new: Option<&MergedStream>) {
+        if let Some(new) = new {
+            match existing {
+                Some(existing) => {
+                    existing.initial_count = existing.initial_count.max(new.initial_count)
+                }
+                None => *existing = Some(new.clone()),
+            }
+        }
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct MergedInlineFragmentSelection {
     pub type_to_refine_to: IsographObjectTypeName,
@@ -137,6 +193,14 @@ pub struct MergedInlineFragmentSelection {
     pub selection_map: MergedSelectionMap,
 }
 
+/// Selections made with @defer. These are fetched in a later payload of the same
+/// request, which is identified by the label.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct MergedDeferredFragmentSelection {
+    pub label: String,
+    pub selection_map: MergedSelectionMap,
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, PartialOrd, Ord, Hash)]
 pub enum NormalizationKey {
     Discriminator, // AKA typename
@@ -144,6 +208,13 @@ pub enum NormalizationKey {
     // TODO this should not have NameAndArguments, but LinkedFieldNameAndArguments
     ServerField(NameAndArguments),
     InlineFragment(IsographObjectTypeName),
+    /// A selection with @skip or @include. These are kept separate from other selections
+    /// of the same field, which may have different conditions or none at all.
+    Conditional(Box<NormalizationKey>, Vec<SelectionCondition>),
+    /// Selections made with @defer, keyed by their label. Paths to refetched fields
+    /// never include these, since deferred selections are read as if they were not
+    /// deferred.
+    DeferredFragment(String),
 }
 
 impl NormalizationKey {
@@ -162,6 +233,46 @@ impl NormalizationKey {
                 ),
             ),
             NormalizationKey::InlineFragment(o) => NormalizationKey::InlineFragment(*o),
+            NormalizationKey::DeferredFragment(label) => {
+                NormalizationKey::DeferredFragment(label.clone())
+            }
+            NormalizationKey::Conditional(key, conditions) => NormalizationKey::Conditional(
+                Box::new(key.transform_with_parent_variable_context(parent_variable_context)),
+                transform_conditions_with_child_context(
+                    conditions.iter().cloned(),
+                    parent_variable_context,
+                ),
+            ),
+        }
+    }
+
+    pub fn with_conditions(self, conditions: &[SelectionCondition]) -> Self {
+        if conditions.is_empty() {
+            self
+        } else {
+            NormalizationKey::Conditional(Box::new(self), conditions.to_vec())
+        }
+    }
+
+    /// Add conditions to the key of a selection that is already merged. The id and
+    /// __typename are always selected, and fragments pass the conditions on to their
+    /// selections, so their keys are unchanged.
+    fn with_additional_conditions(self, conditions: &[SelectionCondition]) -> Self {
+        match self {
+            NormalizationKey::Discriminator
+            | NormalizationKey::Id
+            | NormalizationKey::InlineFragment(_)
+            | NormalizationKey::DeferredFragment(_) => self,
+            NormalizationKey::ServerField(_) => self.with_conditions(conditions),
+            NormalizationKey::Conditional(key, existing_conditions) => {
+                NormalizationKey::Conditional(
+                    key,
+                    existing_conditions
+                        .into_iter()
+                        .chain(conditions.iter().cloned())
+                        .collect(),
+                )
+            }
         }
     }
 }
@@ -189,6 +300,15 @@ pub struct PathToRefetchFieldInfo {
     pub client_field_id: ClientFieldId,
 }
 
+/// A connection selected with @paginated. Subsequent pages are fetched by
+/// refetching the connection's parent.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PaginatedFieldInfo {
+    pub refetch_field_parent_id: ServerObjectId,
+    pub server_field_id: ServerFieldId,
+    pub direction: PaginationDirection,
+}
+
 pub type RefetchedPathsMap =
     BTreeMap<(PathToRefetchField, IsographSelectionVariant), RootRefetchedPath>;
 
@@ -263,8 +383,7 @@ pub fn current_target_merged_selections<'a>(
     mut parent_selection_map: &'a MergedSelectionMap,
 ) -> &'a MergedSelectionMap {
     for normalization_key in traversal_path {
-        match parent_selection_map
-            .get(normalization_key)
+        match get_selection_including_deferred(parent_selection_map, normalization_key)
             .expect("Expected linked field to exist by now. This is indicate of a bug in Isograph.")
         {
             MergedServerSelection::ScalarField(_) => {
@@ -276,19 +395,146 @@ pub fn current_target_merged_selections<'a>(
             MergedServerSelection::InlineFragment(ref inline_fragment) => {
                 parent_selection_map = &inline_fragment.selection_map;
             }
+            MergedServerSelection::DeferredFragment(ref deferred_fragment) => {
+                parent_selection_map = &deferred_fragment.selection_map;
+            }
         }
     }
     parent_selection_map
 }
 
+/// Look up a selection in a selection map, or in the deferred fragments within it.
+fn get_selection_including_deferred<'a>(
+    selection_map: &'a MergedSelectionMap,
+    normalization_key: &NormalizationKey,
+) -> Option<&'a MergedServerSelection> {
+    selection_map.get(normalization_key).or_else(|| {
+        selection_map
+            .values()
+            .find_map(|selection| match selection {
+                MergedServerSelection::DeferredFragment(deferred_fragment) => {
+                    get_selection_including_deferred(
+                        &deferred_fragment.selection_map,
+                        normalization_key,
+                    )
+                }
+                _ => None,
+            })
+    })
+}
+
+/// The selection map of the deferred fragment with the given label, which is
+/// created if it does not exist.
+fn deferred_fragment_selection_map(
+    parent_map: &mut MergedSelectionMap,
+    label: String,
+) -> &mut MergedSelectionMap {
+    match parent_map
+        .entry(NormalizationKey::DeferredFragment(label.clone()))
+        .or_insert_with(|| {
+            MergedServerSelection::DeferredFragment(MergedDeferredFragmentSelection {
+                label,
+                selection_map: BTreeMap::new(),
+            })
+        }) {
+        MergedServerSelection::DeferredFragment(deferred_fragment) => {
+            &mut deferred_fragment.selection_map
+        }
+        _ => panic!(
+            "Expected deferred fragment. \
+            This is indicative of a bug in Isograph."
+        ),
+    }
+}
+
+fn get_defer_label(parent_type: &SchemaObject, field_name: impl std::fmt::Display) -> String {
+    format!("{}__{}__defer", parent_type.name, field_name)
+}
+
+/// The labels of @defer and @stream selections are generated from the parent type
+/// and field name, so the same label can occur several times in a query, e.g. if a
+/// client field with a deferred selection is selected in two places, or if a list
+/// is streamed under two aliases. Prefix each label with the path (of response keys)
+/// at which it occurs, and add a suffix if that is not enough to make it unique.
+///
+/// Only the labels are changed, not the normalization keys, so paths into the
+/// selection map remain valid.
+pub fn assign_unique_labels(selection_map: &mut MergedSelectionMap) {
+    assign_unique_labels_at_path(selection_map, &mut vec![], &mut HashSet::new());
+}
+
+fn assign_unique_labels_at_path(
+    selection_map: &mut MergedSelectionMap,
+    path: &mut Vec<String>,
+    used_labels: &mut HashSet<String>,
+) {
+    for selection in selection_map.values_mut() {
+        match selection {
+            MergedServerSelection::ScalarField(_) => {}
+            MergedServerSelection::LinkedField(linked_field) => {
+                if let Some(stream) = &mut linked_field.stream {
+                    stream.label = unique_label(path, &stream.label, used_labels);
+                }
+                path.push(
+                    linked_field
+                        .normalization_alias()
+                        .unwrap_or_else(|| linked_field.name.to_string()),
+                );
+                assign_unique_labels_at_path(&mut linked_field.selection_map, path, used_labels);
+                path.pop();
+            }
+            MergedServerSelection::InlineFragment(inline_fragment) => {
+                path.push(inline_fragment.type_to_refine_to.to_string());
+                assign_unique_labels_at_path(&mut inline_fragment.selection_map, path, used_labels);
+                path.pop();
+            }
+            MergedServerSelection::DeferredFragment(deferred_fragment) => {
+                deferred_fragment.label = unique_label(path, &deferred_fragment.label, used_labels);
+                assign_unique_labels_at_path(
+                    &mut deferred_fragment.selection_map,
+                    path,
+                    used_labels,
+                );
+            }
+        }
+    }
+}
+
+fn unique_label(path: &[String], label: &str, used_labels: &mut HashSet<String>) -> String {
+    let label = path
+        .iter()
+        .map(|segment| segment.as_str())
+        .chain(std::iter::once(label))
+        .collect::<Vec<_>>()
+        .join("__");
+    let mut unique_label = label.clone();
+    let mut index = 1;
+    while !used_labels.insert(unique_label.clone()) {
+        unique_label = format!("{}_{}", label, index);
+        index += 1;
+    }
+    unique_label
+}
+
+/// Merge the selections of a child (e.g. of a client field) into the parent map.
+/// If the child is selected with @skip or @include, `conditions` (which are already
+/// in the parent's variable context) are added to each of its selections.
 fn transform_and_merge_child_selection_map_into_parent_map(
     parent_map: &mut MergedSelectionMap,
     untransformed_child_map: &MergedSelectionMap,
     parent_variable_context: &VariableContext,
+    conditions: &[SelectionCondition],
 ) {
     for (normalization_key, new_server_field_selection) in untransformed_child_map.iter() {
-        let transformed_normalization_key =
-            normalization_key.transform_with_parent_variable_context(parent_variable_context);
+        let transformed_normalization_key = normalization_key
+            .transform_with_parent_variable_context(parent_variable_context)
+            .with_additional_conditions(conditions);
+        // The id and __typename of the parent are always selected, regardless
+        // of whether the child is.
+        let selection_conditions = match transformed_normalization_key {
+            NormalizationKey::Discriminator | NormalizationKey::Id => &[],
+            _ => conditions,
+        };
 
         match parent_map.entry(transformed_normalization_key.clone()) {
             Entry::Vacant(vacant) => {
@@ -301,6 +547,13 @@ fn transform_and_merge_child_selection_map_into_parent_map(
                                 scalar_field_selection.arguments.into_iter(),
                                 parent_variable_context,
                             ),
+                            conditions: transform_conditions_with_child_context(
+                                scalar_field_selection.conditions.into_iter(),
+                                parent_variable_context,
+                            )
+                            .into_iter()
+                            .chain(selection_conditions.iter().cloned())
+                            .collect(),
                         })
                     }
                     MergedServerSelection::LinkedField(linked_field_selection) => {
@@ -310,11 +563,20 @@ fn transform_and_merge_child_selection_map_into_parent_map(
                             selection_map: transform_child_map_with_parent_context(
                                 &linked_field_selection.selection_map,
                                 parent_variable_context,
+                                &[],
                             ),
                             arguments: transform_arguments_with_child_context(
                                 linked_field_selection.arguments.into_iter(),
                                 parent_variable_context,
                             ),
+                            conditions: transform_conditions_with_child_context(
+                                linked_field_selection.conditions.into_iter(),
+                                parent_variable_context,
+                            )
+                            .into_iter()
+                            .chain(selection_conditions.iter().cloned())
+                            .collect(),
+                            stream: linked_field_selection.stream,
                         })
                     }
                     MergedServerSelection::InlineFragment(inline_fragment_selection) => {
@@ -323,6 +585,17 @@ fn transform_and_merge_child_selection_map_into_parent_map(
                             selection_map: transform_child_map_with_parent_context(
                                 &inline_fragment_selection.selection_map,
                                 parent_variable_context,
+                                conditions,
+                            ),
+                        })
+                    }
+                    MergedServerSelection::DeferredFragment(deferred_fragment_selection) => {
+                        MergedServerSelection::DeferredFragment(MergedDeferredFragmentSelection {
+                            label: deferred_fragment_selection.label,
+                            selection_map: transform_child_map_with_parent_context(
+                                &deferred_fragment_selection.selection_map,
+                                parent_variable_context,
+                                conditions,
                             ),
                         })
                     }
@@ -347,10 +620,15 @@ fn transform_and_merge_child_selection_map_into_parent_map(
                         if let MergedServerSelection::LinkedField(child_linked_field) =
                             new_server_field_selection
                         {
+                            MergedStream::merge(
+                                &mut target_linked_field.stream,
+                                child_linked_field.stream.as_ref(),
+                            );
                             transform_and_merge_child_selection_map_into_parent_map(
                                 &mut target_linked_field.selection_map,
                                 &child_linked_field.selection_map,
                                 parent_variable_context,
+                                &[],
                             )
                         } else {
                             panic!(
@@ -367,6 +645,7 @@ fn transform_and_merge_child_selection_map_into_parent_map(
                                 &mut target_inline_fragment.selection_map,
                                 &child_inline_fragment.selection_map,
                                 parent_variable_context,
+                                conditions,
                             )
                         } else {
                             panic!(
@@ -375,6 +654,23 @@ fn transform_and_merge_child_selection_map_into_parent_map(
                             )
                         }
                     }
+                    MergedServerSelection::DeferredFragment(target_deferred_fragment) => {
+                        if let MergedServerSelection::DeferredFragment(child_deferred_fragment) =
+                            new_server_field_selection
+                        {
+                            transform_and_merge_child_selection_map_into_parent_map(
+                                &mut target_deferred_fragment.selection_map,
+                                &child_deferred_fragment.selection_map,
+                                parent_variable_context,
+                                conditions,
+                            )
+                        } else {
+                            panic!(
+                                "Error: tried to merge non-deferred fragment into deferred \
+                                fragment. This is indicative of a bug in Isograph."
+                            )
+                        }
+                    }
                 }
             }
         };
@@ -384,12 +680,14 @@ fn transform_and_merge_child_selection_map_into_parent_map(
 fn transform_child_map_with_parent_context(
     selection_map: &MergedSelectionMap,
     parent_variable_context: &VariableContext,
+    conditions: &[SelectionCondition],
 ) -> BTreeMap<NormalizationKey, MergedServerSelection> {
     let mut transformed_child_map = BTreeMap::new();
     transform_and_merge_child_selection_map_into_parent_map(
         &mut transformed_child_map,
         selection_map,
         parent_variable_context,
+        conditions,
     );
     transformed_child_map
 }
@@ -447,29 +745,71 @@ pub fn get_imperatively_loaded_artifact_info(
     reachable_variables: &BTreeSet<VariableName>,
     index: usize,
 ) -> ImperativelyLoadedFieldArtifactInfo {
-    let RootRefetchedPath {
-        path_to_refetch_field_info,
-        ..
-    } = root_refetch_path;
-    let PathToRefetchFieldInfo {
-        refetch_field_parent_id,
-        imperatively_loaded_field_variant,
-        extra_selections: _,
-        client_field_id,
-    } = path_to_refetch_field_info;
+    match root_refetch_path.refetched_path_info {
+        RefetchedPathInfo::ImperativelyLoadedField(PathToRefetchFieldInfo {
+            refetch_field_parent_id,
+            imperatively_loaded_field_variant,
+            extra_selections: _,
+            client_field_id: _,
+        }) => process_imperatively_loaded_field(
+            schema,
+            imperatively_loaded_field_variant,
+            refetch_field_parent_id,
+            nested_selection_map,
+            entrypoint,
+            index,
+            reachable_variables,
+        ),
+        RefetchedPathInfo::PaginatedField(paginated_field_info) => process_paginated_field(
+            schema,
+            paginated_field_info,
+            root_refetch_path.field_name,
+            nested_selection_map,
+            entrypoint,
+            index,
+            reachable_variables,
+        ),
+    }
+}
 
-    let client_field = schema.client_field(client_field_id);
+/// The selection map of the query that fetches a page of a connection selected with
+/// @paginated, i.e. the connection (at connection_key in parent_selection_map) with its
+/// count and cursor arguments passed as variables.
+pub fn paginated_field_selection_map(
+    parent_selection_map: &MergedSelectionMap,
+    connection_key: &NormalizationKey,
+    direction: PaginationDirection,
+) -> MergedSelectionMap {
+    let connection = match get_selection_including_deferred(parent_selection_map, connection_key)
+        .expect(
+            "Expected paginated field to exist in the merged selection map. \
+        This is indicative of a bug in Isograph.",
+        ) {
+        MergedServerSelection::LinkedField(linked_field) => linked_field,
+        _ => panic!(
+            "Expected paginated field to be a linked field. \
+            This is indicative of a bug in Isograph."
+        ),
+    };
 
-    process_imperatively_loaded_field(
-        schema,
-        imperatively_loaded_field_variant,
-        refetch_field_parent_id,
-        nested_selection_map,
-        entrypoint,
-        index,
-        reachable_variables,
-        client_field,
-    )
+    let arguments = direction.page_arguments(connection.arguments.clone());
+    let mut selection_map = BTreeMap::new();
+    selection_map.insert(
+        NormalizationKey::ServerField(NameAndArguments {
+            name: connection.name.into(),
+            arguments: arguments.clone(),
+        })
+        .with_conditions(&connection.conditions),
+        MergedServerSelection::LinkedField(MergedLinkedFieldSelection {
+            name: connection.name,
+            selection_map: connection.selection_map.clone(),
+            arguments,
+            concrete_type: connection.concrete_type,
+            conditions: connection.conditions.clone(),
+            stream: connection.stream.clone(),
+        }),
+    );
+    selection_map
 }
 
 pub fn get_reachable_variables(selection_map: &MergedSelectionMap) -> BTreeSet<VariableName> {
@@ -488,12 +828,12 @@ fn process_imperatively_loaded_field(
     entrypoint: &ValidatedClientField,
     index: usize,
     reachable_variables: &BTreeSet<VariableName>,
-    client_field: &ValidatedClientField,
 ) -> ImperativelyLoadedFieldArtifactInfo {
     let ImperativelyLoadedFieldVariant {
         client_field_scalar_selection_name,
         top_level_schema_field_name,
         top_level_schema_field_arguments,
+        top_level_schema_field_variables,
         top_level_schema_field_concrete_type,
         primary_field_info,
         root_object_id,
@@ -501,14 +841,37 @@ fn process_imperatively_loaded_field(
     // This could be Pet
     let refetch_field_parent_type = schema.server_field_data.object(refetch_field_parent_id);
 
-    let mut definitions_of_used_variables =
-        get_used_variable_definitions(reachable_variables, client_field);
+    let mut definitions_of_used_variables = get_used_variable_definitions(
+        reachable_variables,
+        entrypoint,
+        &top_level_schema_field_variables,
+    );
+    definitions_of_used_variables.extend(top_level_schema_field_variables.into_iter().map(
+        |variable| WithSpan {
+            item: VariableDefinition {
+                name: variable.name,
+                type_: variable.type_.map(|type_name| {
+                    *schema
+                        .server_field_data
+                        .defined_types
+                        .get(&type_name)
+                        .expect(
+                            "Expected type to be found, \
+                            this indicates a bug in Isograph",
+                        )
+                }),
+                default_value: variable.default_value,
+            },
+            span: Span::todo_generated(),
+        },
+    ));
 
     let requires_refinement = if primary_field_info
         .as_ref()
         .map(|x| x.primary_field_return_type_object_id != refetch_field_parent_id)
-        .unwrap_or(true)
-    {
+        .unwrap_or_else(|| {
+            top_level_schema_field_concrete_type != Some(refetch_field_parent_type.name)
+        }) {
         RequiresRefinement::Yes(refetch_field_parent_type.name)
     } else {
         RequiresRefinement::No
@@ -519,36 +882,7 @@ fn process_imperatively_loaded_field(
         selection_map.clone(),
         // TODO why are these types different
         top_level_schema_field_name.lookup().intern().into(),
-        top_level_schema_field_arguments
-            .iter()
-            // TODO don't clone
-            .cloned()
-            .map(|x| {
-                let variable_name = x.name;
-                definitions_of_used_variables.push(WithSpan {
-                    item: VariableDefinition {
-                        name: variable_name,
-                        type_: x.type_.clone().map(|type_name| {
-                            *schema
-                                .server_field_data
-                                .defined_types
-                                .get(&type_name)
-                                .expect(
-                                    "Expected type to be found, \
-                                    this indicates a bug in Isograph",
-                                )
-                        }),
-                        default_value: x.default_value,
-                    },
-                    span: Span::todo_generated(),
-                });
-
-                ArgumentKeyAndValue {
-                    key: x.name.item.lookup().intern().into(),
-                    value: NonConstantValue::Variable(x.name.item),
-                }
-            })
-            .collect(),
+        top_level_schema_field_arguments,
         top_level_schema_field_concrete_type,
         primary_field_info
             .as_ref()
@@ -597,15 +931,142 @@ fn process_imperatively_loaded_field(
     }
 }
 
+fn process_paginated_field(
+    schema: &ValidatedSchema,
+    paginated_field_info: PaginatedFieldInfo,
+    field_name: SelectableFieldName,
+    selection_map: &MergedSelectionMap,
+    entrypoint: &ValidatedClientField,
+    index: usize,
+    reachable_variables: &BTreeSet<VariableName>,
+) -> ImperativelyLoadedFieldArtifactInfo {
+    let PaginatedFieldInfo {
+        refetch_field_parent_id,
+        server_field_id,
+        direction,
+    } = paginated_field_info;
+    let refetch_field_parent_type = schema.server_field_data.object(refetch_field_parent_id);
+    let server_field = schema.server_field(server_field_id);
+    let page_variables = [
+        (direction.count_argument(), direction.count_variable()),
+        (direction.cursor_argument(), direction.cursor_variable()),
+    ];
+
+    // Pages of connections on root objects are fetched by re-running a root query.
+    // Otherwise, the connection is selected on the refetched parent.
+    let (merged_selection_set, refetch_field_arguments) = if schema
+        .fetchable_types
+        .contains_key(&refetch_field_parent_id)
+    {
+        (selection_map.clone(), vec![])
+    } else {
+        let refetch_field = refetch_field_parent_type.refetch_field.as_ref().expect(
+            "Expected paginated field's parent to have a refetch field. \
+            This is indicative of a bug in Isograph.",
+        );
+        (
+            selection_map_wrapped(
+                selection_map.clone(),
+                refetch_field.field_name,
+                refetch_field.top_level_arguments(),
+                refetch_field.field_concrete_type,
+                None,
+                None,
+                refetch_field.requires_refinement,
+            ),
+            refetch_field.arguments.clone(),
+        )
+    };
+
+    let entrypoint_variables = reachable_variables
+        .iter()
+        .filter(|variable_name| {
+            !page_variables
+                .iter()
+                .any(|(_, page_variable)| page_variable == *variable_name)
+        })
+        .copied()
+        .collect();
+    let mut definitions_of_used_variables =
+        get_used_variable_definitions(&entrypoint_variables, entrypoint, &refetch_field_arguments);
+    definitions_of_used_variables.extend(server_field.arguments.iter().filter_map(|argument| {
+        let (_, page_variable) = page_variables.iter().find(|(page_argument, _)| {
+            page_argument.lookup() == argument.item.name.item.lookup()
+        })?;
+        Some(WithSpan {
+            item: VariableDefinition {
+                name: WithLocation::new(*page_variable, Location::generated()),
+                type_: argument.item.type_.clone(),
+                default_value: None,
+            },
+            span: Span::todo_generated(),
+        })
+    }));
+    definitions_of_used_variables.extend(refetch_field_arguments.into_iter().map(|argument| {
+        WithSpan {
+            item: VariableDefinition {
+                name: argument.name,
+                type_: argument.type_.map(|type_name| {
+                    *schema
+                        .server_field_data
+                        .defined_types
+                        .get(&type_name)
+                        .expect(
+                            "Expected type to be found, \
+                            this indicates a bug in Isograph",
+                        )
+                }),
+                default_value: argument.default_value,
+            },
+            span: Span::todo_generated(),
+        }
+    }));
+
+    let query_id = schema.query_id();
+
+    ImperativelyLoadedFieldArtifactInfo {
+        merged_selection_set,
+        variable_definitions: definitions_of_used_variables,
+        root_parent_object: schema
+            .server_field_data
+            .object(entrypoint.parent_object_id)
+            .name,
+        root_fetchable_field: entrypoint.name,
+        refetch_query_index: RefetchQueryIndex(index as u32),
+        root_operation_name: schema
+            .fetchable_types
+            .get(&query_id)
+            .expect(
+                "Expected query type to be fetchable here. \
+                This is indicative of a bug in Isograph.",
+            )
+            .clone(),
+        query_name: format!(
+            "{}__{}__paginate",
+            refetch_field_parent_type.name, field_name
+        )
+        .intern()
+        .into(),
+        concrete_type: schema.server_field_data.object(query_id).name,
+    }
+}
+
 fn get_used_variable_definitions(
     reachable_variables: &BTreeSet<VariableName>,
     entrypoint: &ValidatedClientField,
+    top_level_schema_field_arguments: &[UnvalidatedVariableDefinition],
 ) -> Vec<WithSpan<VariableDefinition<SelectableServerFieldId>>> {
     reachable_variables
         .iter()
         .flat_map(|variable_name| {
-            // HACK
-            if variable_name == &"id".intern().into() {
+            // Variables passed to the top level field (e.g. the id passed to node)
+            // are defined separately. Variables provided by a field map are prefixed
+            // with isograph__refetch__, so they do not shadow the client field's
+            // variables.
+            if top_level_schema_field_arguments
+                .iter()
+                .any(|argument| argument.name.item == *variable_name)
+            {
                 None
             } else {
                 Some(
@@ -660,7 +1121,11 @@ fn merge_validated_selections_into_selection_map(
     encountered_client_field_map: &mut ClientFieldToCompletedMergeTraversalStateMap,
     variable_context: &VariableContext,
 ) {
-    for validated_selection in validated_selections.iter().filter(filter_id_fields) {
+    for validated_selection in validated_selections
+        .iter()
+        .filter(|selection| !is_id_field_selection(schema, parent_type, selection))
+        .filter(|selection| !is_client_only_field_selection(schema, selection))
+    {
         match &validated_selection.item {
             ServerFieldSelection::ScalarField(scalar_field_selection) => {
                 match &scalar_field_selection.associated_data.location {
@@ -716,12 +1181,30 @@ fn merge_validated_selections_into_selection_map(
                             None => merge_non_loadable_scalar_client_field(
                                 parent_type,
                                 schema,
-                                parent_map,
+                                if scalar_field_selection.associated_data.is_deferred {
+                                    deferred_fragment_selection_map(
+                                        parent_map,
+                                        get_defer_label(
+                                            parent_type,
+                                            newly_encountered_scalar_client_field.name,
+                                        ),
+                                    )
+                                } else {
+                                    parent_map
+                                },
                                 merge_traversal_state,
                                 newly_encountered_scalar_client_field,
                                 encountered_client_field_map,
                                 variable_context,
                                 &scalar_field_selection.arguments,
+                                &transform_conditions_with_child_context(
+                                    scalar_field_selection
+                                        .associated_data
+                                        .conditions
+                                        .iter()
+                                        .cloned(),
+                                    variable_context,
+                                ),
                             ),
                         }
 
@@ -775,6 +1258,12 @@ fn merge_validated_selections_into_selection_map(
                                                     This is indicative of a bug in Isograph."
                                                 )
                                             }
+                                            MergedServerSelection::DeferredFragment(_) => {
+                                                panic!(
+                                                    "Expected inline fragment, but encountered deferred fragment. \
+                                                    This is indicative of a bug in Isograph."
+                                                )
+                                            }
                                             MergedServerSelection::InlineFragment(
                                                 existing_inline_fragment,
                                             ) => {
@@ -818,13 +1307,28 @@ fn merge_validated_selections_into_selection_map(
                                         }
                                     }
                                     SchemaServerFieldVariant::LinkedField => {
+                                        let conditions = transform_conditions_with_child_context(
+                                            linked_field_selection
+                                                .associated_data
+                                                .conditions
+                                                .iter()
+                                                .cloned(),
+                                            variable_context,
+                                        );
                                         let normalization_key =
                                             create_transformed_name_and_arguments(
                                                 linked_field_selection.name.item.into(),
                                                 &linked_field_selection.arguments,
                                                 variable_context,
                                             )
-                                            .normalization_key();
+                                            .normalization_key(
+                                                schema.strong_id_field_name(
+                                                    linked_field_selection
+                                                        .associated_data
+                                                        .parent_object_id,
+                                                ),
+                                            )
+                                            .with_conditions(&conditions);
 
                                         merge_traversal_state
                                             .traversal_path
@@ -836,7 +1340,30 @@ fn merge_validated_selections_into_selection_map(
                                         // missing, and panic.
                                         //
                                         // This might be indicative of poor modeling.
-                                        let linked_field = parent_map
+                                        let stream = linked_field_selection
+                                            .associated_data
+                                            .stream
+                                            .map(|stream| MergedStream {
+                                                label: format!(
+                                                    "{}__{}__stream",
+                                                    parent_type.name,
+                                                    linked_field_selection.name.item
+                                                ),
+                                                initial_count: stream.initial_count,
+                                            });
+                                        let target_map =
+                                            if linked_field_selection.associated_data.is_deferred {
+                                                deferred_fragment_selection_map(
+                                                    parent_map,
+                                                    get_defer_label(
+                                                        parent_type,
+                                                        linked_field_selection.name.item,
+                                                    ),
+                                                )
+                                            } else {
+                                                &mut *parent_map
+                                            };
+                                        let linked_field = target_map
                                             .entry(normalization_key)
                                             .or_insert_with(|| {
                                                 MergedServerSelection::LinkedField(
@@ -857,6 +1384,8 @@ fn merge_validated_selections_into_selection_map(
                                                                     }),
                                                                 variable_context,
                                                             ),
+                                                        conditions,
+                                                        stream: None,
                                                     },
                                                 )
                                             });
@@ -870,6 +1399,10 @@ fn merge_validated_selections_into_selection_map(
                                             MergedServerSelection::LinkedField(
                                                 existing_linked_field,
                                             ) => {
+                                                MergedStream::merge(
+                                                    &mut existing_linked_field.stream,
+                                                    stream.as_ref(),
+                                                );
                                                 merge_validated_selections_into_selection_map(
                                                     schema,
                                                     &mut existing_linked_field.selection_map,
@@ -879,6 +1412,20 @@ fn merge_validated_selections_into_selection_map(
                                                     encountered_client_field_map,
                                                     variable_context,
                                                 );
+
+                                                if let ValidatedIsographSelectionVariant::Paginated(
+                                                    direction,
+                                                ) = linked_field_selection
+                                                    .associated_data
+                                                    .selection_variant
+                                                {
+                                                    select_pagination_fields_in_merged_selection(
+                                                        schema,
+                                                        &mut existing_linked_field.selection_map,
+                                                        linked_field_parent_type,
+                                                        direction,
+                                                    );
+                                                }
                                             }
                                             MergedServerSelection::InlineFragment(_) => {
                                                 panic!(
@@ -886,6 +1433,33 @@ fn merge_validated_selections_into_selection_map(
                                                     This is indicative of a bug in Isograph."
                                                 )
                                             }
+                                            MergedServerSelection::DeferredFragment(_) => {
+                                                panic!(
+                                                    "Expected linked field, but encountered deferred fragment. \
+                                                    This is indicative of a bug in Isograph."
+                                                )
+                                            }
+                                        }
+
+                                        if let ValidatedIsographSelectionVariant::Paginated(
+                                            direction,
+                                        ) =
+                                            linked_field_selection.associated_data.selection_variant
+                                        {
+                                            insert_paginated_field_into_refetch_paths(
+                                                schema,
+                                                parent_map,
+                                                parent_type,
+                                                merge_traversal_state,
+                                                encountered_client_field_map,
+                                                variable_context,
+                                                PaginatedFieldInfo {
+                                                    refetch_field_parent_id: parent_type.id,
+                                                    server_field_id,
+                                                    direction,
+                                                },
+                                                linked_field_selection.name.item.into(),
+                                            );
                                         }
                                     }
                                 }
@@ -924,7 +1498,7 @@ fn insert_imperative_field_into_refetch_paths(
         (path, IsographSelectionVariant::Regular),
         RootRefetchedPath {
             field_name: newly_encountered_scalar_client_field.name,
-            path_to_refetch_field_info: info,
+            refetched_path_info: RefetchedPathInfo::ImperativelyLoadedField(info),
         },
     );
 
@@ -946,21 +1520,176 @@ fn insert_imperative_field_into_refetch_paths(
     );
 }
 
-fn filter_id_fields(field: &&WithSpan<ValidatedSelection>) -> bool {
-    // filter out id fields, and eventually other always-selected fields like __typename
+#[allow(clippy::too_many_arguments)]
+fn insert_paginated_field_into_refetch_paths(
+    schema: &ValidatedSchema,
+    parent_map: &mut MergedSelectionMap,
+    parent_type: &SchemaObject,
+    merge_traversal_state: &mut ScalarClientFieldTraversalState,
+    encountered_client_field_map: &mut ClientFieldToCompletedMergeTraversalStateMap,
+    variable_context: &VariableContext,
+    paginated_field_info: PaginatedFieldInfo,
+    field_name: SelectableFieldName,
+) {
+    // N.B. the traversal path includes the connection itself
+    let path = PathToRefetchField {
+        linked_fields: merge_traversal_state.traversal_path.clone(),
+        field_name,
+    };
+    merge_traversal_state.refetch_paths.insert(
+        (
+            path,
+            IsographSelectionVariant::Paginated(PaginatedDirectiveParameters {}),
+        ),
+        RootRefetchedPath {
+            field_name,
+            refetched_path_info: RefetchedPathInfo::PaginatedField(paginated_field_info),
+        },
+    );
+
+    // Subsequent pages are fetched by refetching the parent, so we must select
+    // whatever is needed to refetch it (e.g. its id).
+    if let Some(FieldType::ClientField(ClientType::ClientField(refetch_field_id))) = parent_type
+        .encountered_fields
+        .get(&(*REFETCH_FIELD_NAME).into())
+    {
+        if let Some(refetch_strategy) = &schema.client_field(*refetch_field_id).refetch_strategy {
+            merge_validated_selections_into_selection_map(
+                schema,
+                parent_map,
+                parent_type,
+                refetch_strategy.refetch_selection_set(),
+                merge_traversal_state,
+                encountered_client_field_map,
+                variable_context,
+            );
+        }
+    }
+}
+
+/// Connections selected with @paginated must always select the fields needed to
+/// fetch the next page, i.e. `pageInfo { hasNextPage endCursor }` (or `hasPreviousPage`
+/// and `startCursor`) and `edges { cursor }`.
+fn select_pagination_fields_in_merged_selection(
+    schema: &ValidatedSchema,
+    connection_selection_map: &mut MergedSelectionMap,
+    connection_type: &SchemaObject,
+    direction: PaginationDirection,
+) {
+    for (linked_field_name, scalar_field_names) in [
+        (
+            *PAGE_INFO_FIELD_NAME,
+            vec![direction.has_more_field(), direction.page_cursor_field()],
+        ),
+        (*EDGES_FIELD_NAME, vec![*CURSOR_FIELD_NAME]),
+    ] {
+        let linked_field_type = match connection_type
+            .encountered_fields
+            .get(&linked_field_name)
+            .and_then(|field| field.as_server_field())
+            .map(|server_field_id| &schema.server_field(*server_field_id).associated_data)
+        {
+            Some(SelectionType::Object(associated_data)) => schema
+                .server_field_data
+                .object(associated_data.type_name.inner_non_null()),
+            _ => panic!(
+                "Expected connection to have a {linked_field_name} field. \
+                This is indicative of a bug in Isograph."
+            ),
+        };
+
+        let linked_field = connection_selection_map
+            .entry(NormalizationKey::ServerField(NameAndArguments {
+                name: linked_field_name,
+                arguments: vec![],
+            }))
+            .or_insert_with(|| {
+                MergedServerSelection::LinkedField(MergedLinkedFieldSelection {
+                    name: linked_field_name.lookup().intern().into(),
+                    selection_map: BTreeMap::new(),
+                    arguments: vec![],
+                    concrete_type: linked_field_type.concrete_type,
+                    conditions: vec![],
+                    stream: None,
+                })
+            });
+
+        let MergedServerSelection::LinkedField(linked_field) = linked_field else {
+            panic!(
+                "Expected {linked_field_name} to be a linked field. \
+                This is indicative of a bug in Isograph."
+            )
+        };
+        for scalar_field_name in scalar_field_names {
+            linked_field
+                .selection_map
+                .entry(NormalizationKey::ServerField(NameAndArguments {
+                    name: scalar_field_name,
+                    arguments: vec![],
+                }))
+                .or_insert_with(|| {
+                    MergedServerSelection::ScalarField(MergedScalarFieldSelection {
+                        name: scalar_field_name.lookup().intern().into(),
+                        arguments: vec![],
+                        conditions: vec![],
+                    })
+                });
+        }
+        select_typename_and_id_fields_in_merged_selection(
+            schema,
+            &mut linked_field.selection_map,
+            linked_field_type,
+        );
+    }
+}
+
+/// The id field is always selected (see select_typename_and_id_fields_in_merged_selection),
+/// so selections of it are skipped. Eventually, other always-selected fields like __typename
+/// should be skipped too.
+fn is_id_field_selection(
+    schema: &ValidatedSchema,
+    parent_type: &SchemaObject,
+    field: &WithSpan<ValidatedSelection>,
+) -> bool {
     match &field.item {
         ServerFieldSelection::ScalarField(scalar_field) => {
-            // -------- HACK --------
-            // Here, we check whether the field is named "id", but we should really
-            // know whether it is an id field in some other way. There can be non-id fields
-            // named id and id fields not named "id".
-            scalar_field.name.item != "id".intern().into()
-            // ------ END HACK ------
+            match (&scalar_field.associated_data.location, parent_type.id_field) {
+                (FieldType::ServerField(_), Some(id_field_id)) => {
+                    // Compare names rather than ids, since a client field defined on an
+                    // interface selects the interface's id field, not the parent type's.
+                    let id_field: ValidatedSchemaIdField = schema.id_field(id_field_id);
+                    SelectableFieldName::from(scalar_field.name.item) == id_field.name.item
+                }
+                _ => false,
+            }
         }
-        ServerFieldSelection::LinkedField(_) => true,
+        ServerFieldSelection::LinkedField(_) => false,
     }
 }
 
+/// Client-only fields contain local state, so they are never included in
+/// the query text or the normalization AST.
+fn is_client_only_field_selection(
+    schema: &ValidatedSchema,
+    field: &WithSpan<ValidatedSelection>,
+) -> bool {
+    let server_field_id = match &field.item {
+        ServerFieldSelection::ScalarField(scalar_field) => {
+            match scalar_field.associated_data.location {
+                FieldType::ServerField(server_field_id) => server_field_id,
+                FieldType::ClientField(_) => return false,
+            }
+        }
+        ServerFieldSelection::LinkedField(linked_field) => {
+            match linked_field.associated_data.field_id {
+                FieldType::ServerField(server_field_id) => server_field_id,
+                FieldType::ClientField(_) => return false,
+            }
+        }
+    };
+    schema.server_field(server_field_id).is_client_only
+}
+
 #[allow(clippy::too_many_arguments)]
 fn merge_non_loadable_scalar_client_field(
     parent_type: &SchemaObject,
@@ -971,6 +1700,7 @@ fn merge_non_loadable_scalar_client_field(
     encountered_client_field_map: &mut ClientFieldToCompletedMergeTraversalStateMap,
     parent_variable_context: &VariableContext,
     selection_arguments: &[WithLocation<SelectionFieldArgument>],
+    conditions: &[SelectionCondition],
 ) {
     // Here, we are doing a bunch of work, just so that we can have the refetched paths,
     // which is really really silly.
@@ -1002,6 +1732,7 @@ fn merge_non_loadable_scalar_client_field(
         parent_map,
         &child_merged_selection_map,
         &transformed_child_variable_context,
+        conditions,
     );
     parent_merge_traversal_state.incorporate_results_of_iterating_into_child(
         &traversal_state,
@@ -1014,11 +1745,22 @@ fn merge_scalar_server_field(
     parent_map: &mut MergedSelectionMap,
     variable_context: &VariableContext,
 ) {
-    let normalization_key = NormalizationKey::ServerField(create_transformed_name_and_arguments(
-        scalar_field.name.item.into(),
-        &scalar_field.arguments,
+    let conditions = transform_conditions_with_child_context(
+        scalar_field.associated_data.conditions.iter().cloned(),
         variable_context,
-    ));
+    );
+    // An explicitly selected __typename is merged with the __typename that is selected
+    // on abstract types.
+    let normalization_key = if scalar_field.name.item == *TYPENAME_FIELD_NAME {
+        NormalizationKey::Discriminator
+    } else {
+        NormalizationKey::ServerField(create_transformed_name_and_arguments(
+            scalar_field.name.item.into(),
+            &scalar_field.arguments,
+            variable_context,
+        ))
+    }
+    .with_conditions(&conditions);
     match parent_map.entry(normalization_key) {
         Entry::Occupied(occupied) => {
             match occupied.get() {
@@ -1032,6 +1774,9 @@ fn merge_scalar_server_field(
                 MergedServerSelection::InlineFragment(_) => {
                     panic!("Unexpected inline fragment, probably a bug in Isograph");
                 }
+                MergedServerSelection::DeferredFragment(_) => {
+                    panic!("Unexpected deferred fragment, probably a bug in Isograph");
+                }
             };
         }
         Entry::Vacant(vacant_entry) => {
@@ -1045,6 +1790,7 @@ fn merge_scalar_server_field(
                             .map(|arg| arg.item.into_key_and_value()),
                         variable_context,
                     ),
+                    conditions,
                 },
             ));
         }
@@ -1079,6 +1825,9 @@ fn select_typename_and_id_fields_in_merged_selection(
                     MergedServerSelection::InlineFragment(_) => {
                         panic!("Unexpected inline fragment, probably a bug in Isograph");
                     }
+                    MergedServerSelection::DeferredFragment(_) => {
+                        panic!("Unexpected deferred fragment, probably a bug in Isograph");
+                    }
                 };
             }
             Entry::Vacant(vacant_entry) => {
@@ -1087,6 +1836,7 @@ fn select_typename_and_id_fields_in_merged_selection(
                         // major HACK alert
                         name: id_field.name.item.lookup().intern().into(),
                         arguments: vec![],
+                        conditions: vec![],
                     },
                 ));
             }
@@ -1138,6 +1888,8 @@ pub fn selection_map_wrapped(
                     selection_map: selection_set_with_inline_fragment,
                     arguments: vec![],
                     concrete_type: subfield_concrete_type,
+                    conditions: vec![],
+                    stream: None,
                 }),
             );
             map
@@ -1156,6 +1908,8 @@ pub fn selection_map_wrapped(
             selection_map: selection_set_with_subfield,
             arguments: top_level_field_arguments,
             concrete_type: top_level_field_concrete_type,
+            conditions: vec![],
+            stream: None,
         }),
     );
 
@@ -1169,6 +1923,7 @@ fn maybe_add_typename_selection(selections: &mut MergedSelectionMap) {
         MergedServerSelection::ScalarField(MergedScalarFieldSelection {
             name: *TYPENAME_FIELD_NAME,
             arguments: vec![],
+            conditions: vec![],
         }),
     );
 }
@@ -1,23 +1,23 @@
 use common_lang_types::{
     DirectiveArgumentName, DirectiveName, IsographObjectTypeName, LinkedFieldName, Location,
-    SelectableFieldName, Span, StringLiteralValue, ValueKeyName, WithLocation, WithSpan,
+    SelectableFieldName, StringLiteralValue, ValueKeyName, VariableName, WithLocation,
 };
 use graphql_lang_types::{
     from_graph_ql_directive, DeserializationError, GraphQLConstantValue, GraphQLDirective,
 };
 use intern::{string_key::Intern, Lookup};
 use isograph_lang_types::{
-    ArgumentKeyAndValue, ClientFieldId, IsographSelectionVariant, NonConstantValue,
-    ScalarFieldSelection, SelectableServerFieldId, ServerFieldId, ServerFieldSelection,
+    ArgumentKeyAndValue, ClientFieldId, NonConstantValue, SelectableServerFieldId, ServerFieldId,
     ServerObjectId,
 };
 use serde::Deserialize;
 
 use crate::{
-    generate_refetch_field_strategy, ArgumentMap, ClientField, ClientFieldVariant, ClientType,
-    FieldMapItem, FieldType, ImperativelyLoadedFieldVariant, ObjectTypeAndFieldName,
-    PrimaryFieldInfo, ProcessTypeDefinitionError, ProcessTypeDefinitionResult,
-    ProcessedFieldMapItem, UnvalidatedSchema, UnvalidatedVariableDefinition,
+    generate_refetch_field_strategy, refetch_variable, selections_for_paths, ArgumentMap,
+    ClientField, ClientFieldVariant, ClientType, FieldMapItem, FieldType,
+    ImperativelyLoadedFieldVariant, ObjectTypeAndFieldName, PrimaryFieldInfo,
+    ProcessTypeDefinitionError, ProcessTypeDefinitionResult, ProcessedFieldMapItem,
+    UnvalidatedSchema, UnvalidatedVariableDefinition,
 };
 use lazy_static::lazy_static;
 
@@ -187,41 +187,32 @@ impl UnvalidatedSchema {
                     )),
                 }?;
 
-            let fields = processed_field_map_items
+            // The fields that are read from the parent object, e.g. `id`, or `owner { login }`
+            // for from: "owner.login".
+            let paths = processed_field_map_items
                 .iter()
                 .map(|field_map_item| {
-                    let scalar_field_selection = ScalarFieldSelection {
-                        name: WithLocation::new(
-                            // TODO make this no-op
-                            // TODO split on . here; we should be able to have from: "best_friend.id" or whatnot.
-                            field_map_item.0.from.lookup().intern().into(),
-                            Location::generated(),
-                        ),
-                        reader_alias: None,
-                        associated_data: IsographSelectionVariant::Regular,
-                        // TODO what about arguments? How would we handle them?
-                        arguments: vec![],
-                        directives: vec![],
-                    };
-
-                    WithSpan::new(
-                        ServerFieldSelection::ScalarField(scalar_field_selection),
-                        Span::todo_generated(),
-                    )
+                    field_map_item
+                        .0
+                        .from
+                        .lookup()
+                        .split('.')
+                        .collect::<Vec<_>>()
                 })
                 .collect::<Vec<_>>();
+            let fields = selections_for_paths(&paths);
 
             let mutation_field_client_field_id = self.client_fields.len().into();
             let top_level_arguments = mutation_field_arguments
                 .iter()
                 .map(|input_value_def| {
-                    let arg_name = input_value_def.item.name.item.lookup().intern();
+                    let arg_name = input_value_def.item.name.item.lookup();
                     ArgumentKeyAndValue {
-                        key: arg_name.into(),
-                        value: NonConstantValue::Variable(arg_name.into()),
+                        key: arg_name.intern().into(),
+                        value: NonConstantValue::Variable(argument_variable(field_map, arg_name)),
                     }
                 })
-                .collect();
+                .collect::<Vec<_>>();
 
             let top_level_schema_field_concrete_type = payload_object.concrete_type;
             let primary_field_concrete_type = self
@@ -243,15 +234,27 @@ impl UnvalidatedSchema {
                             .intern()
                             .into(),
                         top_level_schema_field_name,
-                        top_level_schema_field_arguments: mutation_field_arguments
+                        top_level_schema_field_arguments: top_level_arguments.clone(),
+                        top_level_schema_field_variables: mutation_field_arguments
                             .into_iter()
-                            .map(|x| x.item)
+                            .map(|x| UnvalidatedVariableDefinition {
+                                name: x
+                                    .item
+                                    .name
+                                    .map(|name| argument_variable(field_map, name.lookup())),
+                                ..x.item
+                            })
                             .collect::<Vec<_>>(),
                         top_level_schema_field_concrete_type,
                         primary_field_info: Some(PrimaryFieldInfo {
                             primary_field_name,
                             primary_field_return_type_object_id: maybe_abstract_parent_object_id,
-                            primary_field_field_map: field_map.to_vec(),
+                            primary_field_field_map: field_map
+                                .iter()
+                                .map(|field_map_item| {
+                                    variable_field_map_item(field_map, field_map_item)
+                                })
+                                .collect(),
                             primary_field_concrete_type,
                         }),
 
@@ -383,6 +386,42 @@ impl UnvalidatedSchema {
     }
 }
 
+/// The variable that a mutation argument is passed as. Arguments that are provided
+/// by the field map are passed as refetch variables, so that they do not clash with
+/// the variables of the client fields whose data is refetched. The remaining
+/// arguments are provided by the caller, and keep their names.
+fn argument_variable(field_map: &[FieldMapItem], argument_name: &str) -> VariableName {
+    if field_map
+        .iter()
+        .any(|field_map_item| field_map_item.to.lookup() == argument_name)
+    {
+        refetch_variable(argument_name)
+    } else {
+        argument_name.intern().into()
+    }
+}
+
+/// The field map item, with the argument at the start of to replaced by the variable
+/// it is passed as, e.g. to: "id" becomes to: "isograph__refetch__id".
+fn variable_field_map_item(
+    field_map: &[FieldMapItem],
+    field_map_item: &FieldMapItem,
+) -> FieldMapItem {
+    let split_to_arg = field_map_item.split_to_arg();
+    let to = std::iter::once(argument_variable(
+        field_map,
+        split_to_arg.to_argument_name.lookup(),
+    ))
+    .map(|variable| variable.lookup())
+    .chain(split_to_arg.to_field_names.iter().map(|x| x.lookup()))
+    .collect::<Vec<_>>()
+    .join(".");
+    FieldMapItem {
+        from: field_map_item.from,
+        to: to.intern().into(),
+    }
+}
+
 fn skip_arguments_contained_in_field_map(
     // TODO move this to impl Schema
     schema: &mut UnvalidatedSchema,
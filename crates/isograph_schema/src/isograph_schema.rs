@@ -4,9 +4,9 @@ use std::{
 };
 
 use common_lang_types::{
-    ArtifactFileType, DescriptionValue, GraphQLInterfaceTypeName, GraphQLScalarTypeName,
-    IsographObjectTypeName, JavascriptName, SelectableFieldName, UnvalidatedTypeName, WithLocation,
-    WithSpan,
+    ArtifactFileType, DescriptionValue, EnumLiteralValue, GraphQLInterfaceTypeName,
+    GraphQLScalarTypeName, IsographObjectTypeName, JavascriptName, Location, SelectableFieldName,
+    StringLiteralValue, UnvalidatedTypeName, WithLocation, WithSpan,
 };
 use graphql_lang_types::{
     GraphQLConstantValue, GraphQLDirective, GraphQLFieldDefinition,
@@ -21,8 +21,8 @@ use isograph_lang_types::{
 use lazy_static::lazy_static;
 
 use crate::{
-    refetch_strategy::RefetchStrategy, ClientFieldVariant, NormalizationKey,
-    ServerFieldTypeAssociatedData,
+    refetch_strategy::RefetchStrategy, ClientFieldVariant, FieldMapItem, NormalizationKey,
+    RefetchField, ServerFieldTypeAssociatedData,
 };
 
 lazy_static! {
@@ -140,6 +140,28 @@ impl<TSchemaValidationState: SchemaValidationState> Schema<TSchemaValidationStat
             .iter()
             .find(|(_, root_operation_name)| root_operation_name.0 == "query")
     }
+
+    /// Whether the object is the query root type. Client fields on the query
+    /// root can be refetched by selecting them at the root of a query. Other
+    /// root types (e.g. Mutation) cannot be refetched.
+    pub fn is_query_root(&self, object_id: ServerObjectId) -> bool {
+        self.find_query()
+            .is_some_and(|(query_id, _)| *query_id == object_id)
+    }
+
+    /// Which fields are read from an object in order to refetch it, and which
+    /// variables they are passed as. Root objects do not need to read anything.
+    pub fn refetch_field_map(&self, object_id: ServerObjectId) -> Vec<FieldMapItem> {
+        if self.fetchable_types.contains_key(&object_id) {
+            return vec![];
+        }
+        self.server_field_data
+            .object(object_id)
+            .refetch_field
+            .as_ref()
+            .map(|refetch_field| refetch_field.variable_field_map())
+            .unwrap_or_default()
+    }
 }
 
 /// Distinguishes between server-defined fields and locally-defined fields.
@@ -199,6 +221,14 @@ impl<TSchemaValidationState: SchemaValidationState> Schema<TSchemaValidationStat
         &self.server_fields[server_field_id.as_usize()]
     }
 
+    /// The name of an object's strong id field, if it has one.
+    pub fn strong_id_field_name(&self, object_id: ServerObjectId) -> Option<SelectableFieldName> {
+        self.server_field_data
+            .object(object_id)
+            .id_field
+            .map(|id_field_id| self.server_field(id_field_id.into()).name.item)
+    }
+
     /// Get a reference to a given client field by its id.
     pub fn client_field(
         &self,
@@ -263,7 +293,7 @@ impl ServerFieldData {
         &self.server_scalars[scalar_id.as_usize()]
     }
 
-    pub fn lookup_unvalidated_type(&self, type_id: SelectableServerFieldId) -> SchemaType {
+    pub fn lookup_unvalidated_type(&self, type_id: SelectableServerFieldId) -> SchemaType<'_> {
         match type_id {
             SelectableServerFieldId::Object(id) => {
                 SchemaType::Object(self.server_objects.get(id.as_usize()).unwrap())
@@ -355,21 +385,30 @@ impl From<GraphQLInputObjectTypeDefinition> for IsographObjectTypeDefinition {
 pub struct SchemaObject {
     pub description: Option<DescriptionValue>,
     pub name: IsographObjectTypeName,
+    /// The location of the type's name in the schema or schema extension.
+    pub name_location: Location,
     pub id: ServerObjectId,
     // We probably don't want this
     pub directives: Vec<GraphQLDirective<GraphQLConstantValue>>,
     /// TODO remove id_field from fields, and change the type of Option<ServerFieldId>
     /// to something else.
     pub id_field: Option<ServerStrongIdFieldId>,
+    /// Some if objects of this type can be refetched, e.g. via `node(id:)`.
+    pub refetch_field: Option<RefetchField>,
     pub encountered_fields:
         BTreeMap<SelectableFieldName, FieldType<ServerFieldId, ClientType<ClientFieldId>>>,
     /// Some if the object is concrete; None otherwise.
     pub concrete_type: Option<IsographObjectTypeName>,
+    /// Some if the object is abstract (i.e. an interface or union), containing the
+    /// concrete types it can be refined to; None otherwise.
+    pub concrete_subtypes: Option<Vec<ServerObjectId>>,
 }
 
 #[derive(Debug, Clone)]
 pub struct SchemaServerField<TData, TClientFieldVariableDefinitionAssociatedData: Ord + Debug> {
     pub description: Option<DescriptionValue>,
+    /// Some if the field has a @deprecated directive.
+    pub deprecation_reason: Option<StringLiteralValue>,
     /// The name of the server field and the location where it was defined
     /// (an iso literal or Location::Generated).
     pub name: WithLocation<SelectableFieldName>,
@@ -381,6 +420,12 @@ pub struct SchemaServerField<TData, TClientFieldVariableDefinitionAssociatedData
         Vec<WithLocation<VariableDefinition<TClientFieldVariableDefinitionAssociatedData>>>,
     // TODO remove this. This is indicative of poor modeling.
     pub is_discriminator: bool,
+    /// True if the field or its parent type has a @client directive. Client-only
+    /// fields contain local state, and are never fetched from the server.
+    pub is_client_only: bool,
+    /// True if the field has a @semanticNonNull directive, i.e. it is only null if an
+    /// error occurred while resolving it.
+    pub is_semantic_non_null: bool,
 }
 
 impl<TData, TClientFieldVariableDefinitionAssociatedData: Clone + Ord + Debug>
@@ -392,12 +437,15 @@ impl<TData, TClientFieldVariableDefinitionAssociatedData: Clone + Ord + Debug>
     ) -> Result<SchemaServerField<TData2, TClientFieldVariableDefinitionAssociatedData>, E> {
         Ok(SchemaServerField {
             description: self.description,
+            deprecation_reason: self.deprecation_reason,
             name: self.name,
             id: self.id,
             associated_data: convert(&self.associated_data)?,
             parent_type_id: self.parent_type_id,
             arguments: self.arguments.clone(),
             is_discriminator: self.is_discriminator,
+            is_client_only: self.is_client_only,
+            is_semantic_non_null: self.is_semantic_non_null,
         })
     }
 
@@ -407,12 +455,15 @@ impl<TData, TClientFieldVariableDefinitionAssociatedData: Clone + Ord + Debug>
     ) -> SchemaServerField<TData2, TClientFieldVariableDefinitionAssociatedData> {
         SchemaServerField {
             description: self.description,
+            deprecation_reason: self.deprecation_reason,
             name: self.name,
             id: self.id,
             associated_data: convert(&self.associated_data),
             parent_type_id: self.parent_type_id,
             arguments: self.arguments.clone(),
             is_discriminator: self.is_discriminator,
+            is_client_only: self.is_client_only,
+            is_semantic_non_null: self.is_semantic_non_null,
         }
     }
 }
@@ -581,8 +632,13 @@ pub struct NameAndArguments {
 }
 
 impl NameAndArguments {
-    pub fn normalization_key(&self) -> NormalizationKey {
-        if self.name == "id".intern().into() {
+    /// The key of this field in the merged selection map of an object whose strong
+    /// id field is named `strong_id_field_name`.
+    pub fn normalization_key(
+        &self,
+        strong_id_field_name: Option<SelectableFieldName>,
+    ) -> NormalizationKey {
+        if Some(self.name) == strong_id_field_name {
             NormalizationKey::Id
         } else {
             NormalizationKey::ServerField(self.clone())
@@ -597,22 +653,28 @@ impl<T, VariableDefinitionInnerType: Ord + Debug>
     pub fn split(self) -> (SchemaServerField<(), VariableDefinitionInnerType>, T) {
         let Self {
             description,
+            deprecation_reason,
             name,
             id,
             associated_data,
             parent_type_id,
             arguments,
             is_discriminator,
+            is_client_only,
+            is_semantic_non_null,
         } = self;
         (
             SchemaServerField {
                 description,
+                deprecation_reason,
                 name,
                 id,
                 associated_data: (),
                 parent_type_id,
                 arguments,
                 is_discriminator,
+                is_client_only,
+                is_semantic_non_null,
             },
             associated_data,
         )
@@ -626,4 +688,10 @@ pub struct SchemaScalar {
     pub name: WithLocation<GraphQLScalarTypeName>,
     pub id: ServerScalarId,
     pub javascript_name: JavascriptName,
+    /// Enums are currently modeled as scalars. These are the enum's values, in the
+    /// order in which they are defined. This is empty for other scalars.
+    pub enum_values: Vec<EnumLiteralValue>,
+    /// Enums are currently modeled as scalars. These are the enum values that
+    /// have a @deprecated directive, and their reasons.
+    pub deprecated_enum_values: BTreeMap<EnumLiteralValue, StringLiteralValue>,
 }
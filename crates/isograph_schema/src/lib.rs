@@ -5,8 +5,10 @@ mod argument_map;
 mod create_merged_selection_set;
 mod expose_field_directive;
 mod isograph_schema;
+mod pagination;
 mod process_client_field_declaration;
 mod process_type_definition;
+mod refetch_field_directive;
 mod refetch_strategy;
 mod root_types;
 mod unvalidated_schema;
@@ -14,6 +16,7 @@ mod validate_client_field;
 mod validate_entrypoint;
 mod validate_schema;
 mod validate_server_field;
+mod validate_variable_usage;
 mod variable_context;
 
 use argument_map::*;
@@ -21,11 +24,14 @@ use argument_map::*;
 pub use create_merged_selection_set::*;
 pub use expose_field_directive::*;
 pub use isograph_schema::*;
+pub use pagination::*;
 pub use process_client_field_declaration::*;
 pub use process_type_definition::*;
+pub use refetch_field_directive::*;
 pub use refetch_strategy::*;
 use root_types::*;
 pub use unvalidated_schema::*;
 pub use validate_entrypoint::*;
 pub use validate_schema::*;
+use validate_variable_usage::*;
 pub use variable_context::*;
@@ -0,0 +1,114 @@
+use common_lang_types::{FieldArgumentName, SelectableFieldName, VariableName};
+use intern::string_key::Intern;
+use isograph_lang_types::{ArgumentKeyAndValue, NonConstantValue};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    pub static ref EDGES_FIELD_NAME: SelectableFieldName = "edges".intern().into();
+    pub static ref NODE_FIELD_NAME_IN_EDGE: SelectableFieldName = "node".intern().into();
+    pub static ref CURSOR_FIELD_NAME: SelectableFieldName = "cursor".intern().into();
+    pub static ref PAGE_INFO_FIELD_NAME: SelectableFieldName = "pageInfo".intern().into();
+}
+
+/// Which way a connection selected with @paginated is paginated. Connections are
+/// paginated forward (i.e. with first and after) unless the selection passes last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PaginationDirection {
+    Forward,
+    Backward,
+}
+
+impl PaginationDirection {
+    pub fn from_argument_names(
+        mut argument_names: impl Iterator<Item = FieldArgumentName>,
+    ) -> Self {
+        if argument_names.any(|argument_name| argument_name == "last".intern().into()) {
+            PaginationDirection::Backward
+        } else {
+            PaginationDirection::Forward
+        }
+    }
+
+    /// The argument containing the number of edges to fetch, i.e. first or last.
+    pub fn count_argument(&self) -> FieldArgumentName {
+        match self {
+            PaginationDirection::Forward => "first",
+            PaginationDirection::Backward => "last",
+        }
+        .intern()
+        .into()
+    }
+
+    /// The argument containing the cursor to paginate from, i.e. after or before.
+    pub fn cursor_argument(&self) -> FieldArgumentName {
+        match self {
+            PaginationDirection::Forward => "after",
+            PaginationDirection::Backward => "before",
+        }
+        .intern()
+        .into()
+    }
+
+    /// The variable passed as the count argument when fetching a page. Page
+    /// variables are prefixed with `isograph__`, so that they do not clash with the
+    /// variables of the entrypoint (e.g. an entrypoint that defines `$first`).
+    pub fn count_variable(&self) -> VariableName {
+        page_variable(self.count_argument())
+    }
+
+    /// The variable passed as the cursor argument when fetching a page.
+    pub fn cursor_variable(&self) -> VariableName {
+        page_variable(self.cursor_argument())
+    }
+
+    /// The field on pageInfo that indicates whether there are more edges.
+    pub fn has_more_field(&self) -> SelectableFieldName {
+        match self {
+            PaginationDirection::Forward => "hasNextPage",
+            PaginationDirection::Backward => "hasPreviousPage",
+        }
+        .intern()
+        .into()
+    }
+
+    /// The field on pageInfo that contains the cursor that is passed to the next
+    /// pagination query.
+    pub fn page_cursor_field(&self) -> SelectableFieldName {
+        match self {
+            PaginationDirection::Forward => "endCursor",
+            PaginationDirection::Backward => "startCursor",
+        }
+        .intern()
+        .into()
+    }
+
+    /// The arguments passed to the connection when fetching a page, i.e. the arguments
+    /// of the selection, with the count and cursor arguments passed as the page
+    /// variables.
+    pub fn page_arguments(
+        &self,
+        mut arguments: Vec<ArgumentKeyAndValue>,
+    ) -> Vec<ArgumentKeyAndValue> {
+        for (argument_name, variable_name) in [
+            (self.count_argument(), self.count_variable()),
+            (self.cursor_argument(), self.cursor_variable()),
+        ] {
+            let value = NonConstantValue::Variable(variable_name);
+            match arguments
+                .iter_mut()
+                .find(|argument| argument.key == argument_name)
+            {
+                Some(argument) => argument.value = value,
+                None => arguments.push(ArgumentKeyAndValue {
+                    key: argument_name,
+                    value,
+                }),
+            }
+        }
+        arguments
+    }
+}
+
+fn page_variable(argument_name: FieldArgumentName) -> VariableName {
+    format!("isograph__{}", argument_name).intern().into()
+}
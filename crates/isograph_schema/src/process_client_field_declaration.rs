@@ -6,15 +6,17 @@ use common_lang_types::{
 use intern::string_key::Intern;
 use isograph_lang_types::{
     ArgumentKeyAndValue, ClientFieldDeclaration, ClientFieldDeclarationWithValidatedDirectives,
-    DeserializationError, NonConstantValue, SelectableServerFieldId, ServerObjectId,
+    DeserializationError, IsographDirectiveLocation, SelectableServerFieldId, ServerObjectId,
 };
 use lazy_static::lazy_static;
 use thiserror::Error;
 
 use crate::{
-    refetch_strategy::{generate_refetch_field_strategy, id_selection, RefetchStrategy},
-    ClientField, ClientType, FieldMapItem, FieldType, ObjectTypeAndFieldName, RequiresRefinement,
-    UnvalidatedSchema, UnvalidatedVariableDefinition, NODE_FIELD_NAME,
+    refetch_strategy::{
+        generate_refetch_field_strategy, generate_refetch_from_root_strategy, RefetchStrategy,
+    },
+    ClientField, ClientType, FieldMapItem, FieldType, ObjectTypeAndFieldName, UnvalidatedSchema,
+    UnvalidatedVariableDefinition,
 };
 
 impl UnvalidatedSchema {
@@ -59,6 +61,7 @@ impl UnvalidatedSchema {
         client_field_declaration: WithSpan<ClientFieldDeclarationWithValidatedDirectives>,
     ) -> ProcessClientFieldDeclarationResult<()> {
         let query_id = self.query_id();
+        let is_query_root = self.is_query_root(parent_object_id);
         let object = &mut self.server_field_data.server_objects[parent_object_id.as_usize()];
         let client_field_field_name_ws = client_field_declaration.item.client_field_name;
         let client_field_name = client_field_field_name_ws.item;
@@ -101,20 +104,28 @@ impl UnvalidatedSchema {
                 },
 
                 parent_object_id,
-                refetch_strategy: object.id_field.map(|_| {
-                    // Assume that if we have an id field, this implements Node
-                    RefetchStrategy::UseRefetchField(generate_refetch_field_strategy(
-                        vec![id_selection()],
-                        query_id,
-                        format!("refetch__{}", object.name).intern().into(),
-                        *NODE_FIELD_NAME,
-                        id_top_level_arguments(),
-                        None,
-                        RequiresRefinement::Yes(object.name),
-                        None,
-                        None,
+                refetch_strategy: if is_query_root {
+                    Some(RefetchStrategy::UseRefetchField(
+                        generate_refetch_from_root_strategy(
+                            parent_object_id,
+                            format!("refetch__{}", object.name).intern().into(),
+                        ),
                     ))
-                }),
+                } else {
+                    object.refetch_field.as_ref().map(|refetch_field| {
+                        RefetchStrategy::UseRefetchField(generate_refetch_field_strategy(
+                            refetch_field.refetch_selection_set(),
+                            query_id,
+                            format!("refetch__{}", object.name).intern().into(),
+                            refetch_field.field_name,
+                            refetch_field.top_level_arguments(),
+                            refetch_field.field_concrete_type,
+                            refetch_field.requires_refinement,
+                            None,
+                            None,
+                        ))
+                    })
+                },
             }));
         Ok(())
     }
@@ -149,6 +160,22 @@ pub enum ProcessClientFieldDeclarationError {
         directive_name: IsographDirectiveName,
         message: DeserializationError,
     },
+
+    #[error("The \"@{directive_name}\" directive is not a known Isograph directive.")]
+    UnknownDirective {
+        directive_name: IsographDirectiveName,
+    },
+
+    #[error("The \"@{directive_name}\" directive cannot be used on {directive_location}.")]
+    DirectiveNotSupportedAtLocation {
+        directive_name: IsographDirectiveName,
+        directive_location: IsographDirectiveLocation,
+    },
+
+    #[error("The \"@{directive_name}\" directive can only be used once.")]
+    DuplicateDirective {
+        directive_name: IsographDirectiveName,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -166,9 +193,12 @@ pub struct ImperativelyLoadedFieldVariant {
     pub client_field_scalar_selection_name: ScalarFieldName,
     /// What field should we select when generating the refetch query?
     pub top_level_schema_field_name: LinkedFieldName,
-    /// The arguments we must pass to the top level schema field, e.g. id: ID!
-    /// for node(id: $id)
-    pub top_level_schema_field_arguments: Vec<UnvalidatedVariableDefinition>,
+    /// The arguments we must pass to the top level schema field, e.g.
+    /// id: $isograph__refetch__id for node(id: $isograph__refetch__id)
+    pub top_level_schema_field_arguments: Vec<ArgumentKeyAndValue>,
+    /// The definitions of the variables passed to the top level schema field, e.g.
+    /// $isograph__refetch__id: ID!
+    pub top_level_schema_field_variables: Vec<UnvalidatedVariableDefinition>,
 
     /// Some if the object is concrete; None otherwise.
     pub top_level_schema_field_concrete_type: Option<IsographObjectTypeName>,
@@ -193,6 +223,9 @@ pub struct UserWrittenClientFieldInfo {
     pub const_export_name: ConstExportName,
     pub file_path: FilePath,
     pub user_written_component_variant: UserWrittenComponentVariant,
+    /// True if the client field declaration has an @updatable directive, i.e. the
+    /// server fields it selects can be written to.
+    pub is_updatable: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -203,30 +236,26 @@ pub enum ClientFieldVariant {
 
 lazy_static! {
     static ref COMPONENT: IsographDirectiveName = "component".intern().into();
+    static ref UPDATABLE: IsographDirectiveName = "updatable".intern().into();
 }
 
 fn get_client_variant<TScalarField, TLinkedField>(
     client_field_declaration: &ClientFieldDeclaration<TScalarField, TLinkedField>,
 ) -> ClientFieldVariant {
-    for directive in client_field_declaration.directives.iter() {
-        if directive.item.name.item == *COMPONENT {
-            return ClientFieldVariant::UserWritten(UserWrittenClientFieldInfo {
-                const_export_name: client_field_declaration.const_export_name,
-                file_path: client_field_declaration.definition_path,
-                user_written_component_variant: UserWrittenComponentVariant::Component,
-            });
-        }
-    }
+    let has_directive = |directive_name: IsographDirectiveName| {
+        client_field_declaration
+            .directives
+            .iter()
+            .any(|directive| directive.item.name.item == directive_name)
+    };
     ClientFieldVariant::UserWritten(UserWrittenClientFieldInfo {
         const_export_name: client_field_declaration.const_export_name,
         file_path: client_field_declaration.definition_path,
-        user_written_component_variant: UserWrittenComponentVariant::Eager,
+        user_written_component_variant: if has_directive(*COMPONENT) {
+            UserWrittenComponentVariant::Component
+        } else {
+            UserWrittenComponentVariant::Eager
+        },
+        is_updatable: has_directive(*UPDATABLE),
     })
 }
-
-pub fn id_top_level_arguments() -> Vec<ArgumentKeyAndValue> {
-    vec![ArgumentKeyAndValue {
-        key: "id".intern().into(),
-        value: NonConstantValue::Variable("id".intern().into()),
-    }]
-}
@@ -7,11 +7,12 @@ use crate::{
     UnvalidatedSchema, UnvalidatedSchemaSchemaField, ID_GRAPHQL_TYPE, STRING_JAVASCRIPT_TYPE,
 };
 use common_lang_types::{
-    GraphQLObjectTypeName, GraphQLScalarTypeName, IsographObjectTypeName, Location,
-    SelectableFieldName, Span, StringLiteralValue, UnvalidatedTypeName, VariableName, WithLocation,
-    WithSpan,
+    DirectiveName, EnumLiteralValue, GraphQLObjectTypeName, GraphQLScalarTypeName,
+    IsographObjectTypeName, Location, SelectableFieldName, Span, StringLiteralValue,
+    UnvalidatedTypeName, VariableName, WithLocation, WithSpan,
 };
 use graphql_lang_types::{
+    from_graph_ql_directive, DeserializationError, GraphQLConstantValue, GraphQLDirective,
     GraphQLFieldDefinition, GraphQLInputValueDefinition, GraphQLNamedTypeAnnotation,
     GraphQLNonNullTypeAnnotation, GraphQLScalarTypeDefinition, GraphQLTypeAnnotation,
     GraphQLTypeSystemDefinition, GraphQLTypeSystemDocument, GraphQLTypeSystemExtension,
@@ -21,7 +22,8 @@ use graphql_lang_types::{
 use intern::{string_key::Intern, Lookup};
 use isograph_config::ConfigOptions;
 use isograph_lang_types::{
-    SelectableServerFieldId, ServerObjectId, ServerStrongIdFieldId, VariableDefinition,
+    SelectableServerFieldId, ServerFieldId, ServerObjectId, ServerStrongIdFieldId,
+    VariableDefinition,
 };
 use lazy_static::lazy_static;
 use serde::Deserialize;
@@ -30,6 +32,10 @@ use thiserror::Error;
 lazy_static! {
     static ref QUERY_TYPE: IsographObjectTypeName = "Query".intern().into();
     static ref MUTATION_TYPE: IsographObjectTypeName = "Mutation".intern().into();
+    static ref DEPRECATED_DIRECTIVE: DirectiveName = "deprecated".intern().into();
+    static ref STRONG_DIRECTIVE: DirectiveName = "strong".intern().into();
+    static ref CLIENT_DIRECTIVE: DirectiveName = "client".intern().into();
+    static ref SEMANTIC_NON_NULL_DIRECTIVE: DirectiveName = "semanticNonNull".intern().into();
 }
 
 type UnvalidatedTypeRefinementMap = HashMap<UnvalidatedTypeName, Vec<UnvalidatedTypeName>>;
@@ -76,6 +82,7 @@ impl UnvalidatedSchema {
             subscription: None,
         };
         let mut processed_root_types = None;
+        let mut union_definitions = vec![];
 
         for with_location in type_system_document.0 {
             let WithLocation {
@@ -110,10 +117,23 @@ impl UnvalidatedSchema {
                     }
                 }
                 GraphQLTypeSystemDefinition::ScalarTypeDefinition(scalar_type_definition) => {
-                    self.process_scalar_definition(scalar_type_definition)?;
+                    self.process_scalar_definition(
+                        scalar_type_definition,
+                        vec![],
+                        BTreeMap::new(),
+                    )?;
                     // N.B. we assume that Mutation will be an object, not a scalar
                 }
                 GraphQLTypeSystemDefinition::InterfaceTypeDefinition(interface_type_definition) => {
+                    for interface_name in interface_type_definition.interfaces.iter() {
+                        insert_into_type_refinement_maps(
+                            interface_name.item.into(),
+                            interface_type_definition.name.item.into(),
+                            &mut supertype_to_subtype_map,
+                            &mut subtype_to_supertype_map,
+                        );
+                    }
+
                     self.process_object_type_definition(
                         interface_type_definition.into(),
                         true,
@@ -139,16 +159,41 @@ impl UnvalidatedSchema {
                     // but it might choose to allow-list them.
                 }
                 GraphQLTypeSystemDefinition::EnumDefinition(enum_definition) => {
+                    let enum_values = enum_definition
+                        .enum_value_definitions
+                        .iter()
+                        .map(|enum_value_definition| enum_value_definition.item.value.item)
+                        .collect();
+                    let deprecated_enum_values = enum_definition
+                        .enum_value_definitions
+                        .iter()
+                        .filter_map(|enum_value_definition| {
+                            get_deprecation_reason(&enum_value_definition.item.directives)
+                                .map(|reason| {
+                                    reason.map(|reason| {
+                                        (enum_value_definition.item.value.item, reason)
+                                    })
+                                })
+                                .transpose()
+                        })
+                        .collect::<Result<_, _>>()?;
+
                     // TODO Do not do this
-                    self.process_scalar_definition(GraphQLScalarTypeDefinition {
-                        description: enum_definition.description,
-                        name: enum_definition.name.map(|x| x.lookup().intern().into()),
-                        directives: enum_definition.directives,
-                    })?;
+                    self.process_scalar_definition(
+                        GraphQLScalarTypeDefinition {
+                            description: enum_definition.description,
+                            name: enum_definition.name.map(|x| x.lookup().intern().into()),
+                            directives: enum_definition.directives,
+                        },
+                        enum_values,
+                        deprecated_enum_values,
+                    )?;
                 }
                 GraphQLTypeSystemDefinition::UnionTypeDefinition(union_definition) => {
-                    // TODO do something reasonable here, once we add support for type refinements.
-                    self.process_object_type_definition(
+                    // Unions have no fields of their own. They expose __typename, and a
+                    // refinement pointer (e.g. asMember) per member type, which is added in
+                    // add_pointers_to_supertypes.
+                    let outcome = self.process_object_type_definition(
                         IsographObjectTypeDefinition {
                             description: union_definition.description,
                             name: union_definition.name.map(|x| x.into()),
@@ -161,7 +206,7 @@ impl UnvalidatedSchema {
                         None,
                     )?;
 
-                    for union_member_type in union_definition.union_member_types {
+                    for union_member_type in union_definition.union_member_types.iter() {
                         insert_into_type_refinement_maps(
                             union_definition.name.item.into(),
                             union_member_type.item.into(),
@@ -169,6 +214,8 @@ impl UnvalidatedSchema {
                             &mut subtype_to_supertype_map,
                         )
                     }
+                    union_definitions
+                        .push((outcome.object_id, union_definition.union_member_types));
                 }
                 GraphQLTypeSystemDefinition::SchemaDefinition(schema_definition) => {
                     if processed_root_types.is_some() {
@@ -186,8 +233,13 @@ impl UnvalidatedSchema {
             }
         }
 
+        for (union_id, union_member_types) in union_definitions {
+            self.set_union_members(union_id, union_member_types)?;
+        }
+
         let type_refinement_map =
             self.get_type_refinement_map(supertype_to_subtype_map, subtype_to_supertype_map)?;
+        self.set_interface_concrete_subtypes(&type_refinement_map.supertype_to_subtype_map);
 
         let root_types = self.process_root_types(processed_root_types, encountered_root_types)?;
 
@@ -207,18 +259,112 @@ impl UnvalidatedSchema {
         })
     }
 
-    // TODO This is currently a completely useless function, serving only to surface
-    // some validation errors. It might be necessary once we handle __asNode etc.
-    // style fields.
+    /// Union members must be defined object types. In particular, they cannot be scalars,
+    /// interfaces or other unions.
+    fn set_union_members(
+        &mut self,
+        union_id: ServerObjectId,
+        union_member_types: Vec<WithLocation<GraphQLObjectTypeName>>,
+    ) -> ProcessTypeDefinitionResult<()> {
+        let union_name = self.server_field_data.object(union_id).name;
+        let mut union_members = Vec::with_capacity(union_member_types.len());
+        for union_member_type in union_member_types {
+            let member_id = match self
+                .server_field_data
+                .defined_types
+                .get(&union_member_type.item.into())
+            {
+                Some(SelectableServerFieldId::Object(object_id)) => *object_id,
+                Some(SelectableServerFieldId::Scalar(_)) => {
+                    return Err(WithLocation::new(
+                        ProcessTypeDefinitionError::UnionMemberMustBeObjectType {
+                            union_name,
+                            member_name: union_member_type.item.into(),
+                        },
+                        union_member_type.location,
+                    ))
+                }
+                None => {
+                    return Err(WithLocation::new(
+                        ProcessTypeDefinitionError::IsographObjectTypeNameNotDefined {
+                            type_name: union_member_type.item.into(),
+                        },
+                        union_member_type.location,
+                    ))
+                }
+            };
+            if self
+                .server_field_data
+                .object(member_id)
+                .concrete_type
+                .is_none()
+            {
+                return Err(WithLocation::new(
+                    ProcessTypeDefinitionError::UnionMemberMustBeObjectType {
+                        union_name,
+                        member_name: union_member_type.item.into(),
+                    },
+                    union_member_type.location,
+                ));
+            }
+            union_members.push(member_id);
+        }
+        self.server_field_data
+            .object_mut(union_id)
+            .concrete_subtypes = Some(union_members);
+        Ok(())
+    }
+
+    /// Interfaces can be refined to the concrete types that (transitively) implement them.
+    /// The concrete subtypes of unions are their members, which are set in set_union_members.
+    fn set_interface_concrete_subtypes(
+        &mut self,
+        supertype_to_subtype_map: &ValidatedTypeRefinementMap,
+    ) {
+        for (supertype_id, subtype_ids) in supertype_to_subtype_map {
+            let concrete_subtype_ids = subtype_ids
+                .iter()
+                .copied()
+                .filter(|subtype_id| {
+                    self.server_field_data
+                        .object(*subtype_id)
+                        .concrete_type
+                        .is_some()
+                })
+                .collect();
+            let supertype = self.server_field_data.object_mut(*supertype_id);
+            if supertype.concrete_type.is_none() && supertype.concrete_subtypes.is_none() {
+                supertype.concrete_subtypes = Some(concrete_subtype_ids);
+            }
+        }
+    }
+
+    /// Since interfaces can implement other interfaces, the returned maps are transitive,
+    /// i.e. an object implementing an interface is a subtype of all of that interface's
+    /// supertypes, too.
     fn get_type_refinement_map(
         &mut self,
         unvalidated_supertype_to_subtype_map: UnvalidatedTypeRefinementMap,
         unvalidated_subtype_to_supertype_map: UnvalidatedTypeRefinementMap,
     ) -> ProcessTypeDefinitionResult<TypeRefinementMaps> {
-        let supertype_to_subtype_map =
-            self.validate_type_refinement_map(unvalidated_supertype_to_subtype_map)?;
-        let subtype_to_supertype_map =
-            self.validate_type_refinement_map(unvalidated_subtype_to_supertype_map)?;
+        // This surfaces errors for supertypes that are not defined
+        self.validate_type_refinement_map(unvalidated_supertype_to_subtype_map)?;
+        let subtype_to_supertype_map = transitive_type_refinement_map(
+            &self.validate_type_refinement_map(unvalidated_subtype_to_supertype_map)?,
+        );
+
+        let mut supertype_to_subtype_map: ValidatedTypeRefinementMap = HashMap::new();
+        for (subtype_id, supertype_ids) in subtype_to_supertype_map.iter() {
+            for supertype_id in supertype_ids {
+                supertype_to_subtype_map
+                    .entry(*supertype_id)
+                    .or_default()
+                    .push(*subtype_id);
+            }
+        }
+        for subtype_ids in supertype_to_subtype_map.values_mut() {
+            subtype_ids.sort();
+        }
 
         Ok(TypeRefinementMaps {
             subtype_to_supertype_map,
@@ -307,7 +453,7 @@ impl UnvalidatedSchema {
         for extension in extensions.into_iter() {
             // TODO collect errors into vec
             // TODO we can encounter new interface implementations; we should account for that
-            self.process_graphql_type_system_extension(extension)?;
+            self.process_graphql_type_system_extension(extension, options)?;
         }
 
         Ok(outcome)
@@ -316,6 +462,7 @@ impl UnvalidatedSchema {
     fn process_graphql_type_system_extension(
         &mut self,
         extension: WithLocation<GraphQLTypeSystemExtension>,
+        options: ConfigOptions,
     ) -> ProcessTypeDefinitionResult<()> {
         match extension.item {
             GraphQLTypeSystemExtension::ObjectTypeExtension(object_extension) => {
@@ -331,16 +478,72 @@ impl UnvalidatedSchema {
 
                 match *id {
                     SelectableServerFieldId::Object(object_id) => {
-                        let schema_object = self.server_field_data.object_mut(object_id);
-
-                        if !object_extension.fields.is_empty() {
-                            panic!("Adding fields in schema extensions is not allowed, yet.");
-                        }
                         if !object_extension.interfaces.is_empty() {
                             panic!("Adding interfaces in schema extensions is not allowed, yet.");
                         }
 
-                        schema_object.directives.extend(object_extension.directives);
+                        // Fields can be added in extensions, e.g. client-only fields containing
+                        // local state.
+                        let schema_object = self.server_field_data.object(object_id);
+                        let parent_type_is_client_only =
+                            has_client_directive(&schema_object.directives);
+                        let id_name = get_strong_id_field_name(
+                            &object_extension.fields,
+                            schema_object.name,
+                            options,
+                        )?;
+                        for field in object_extension.fields {
+                            let server_field_id_usize = self.server_fields.len();
+                            let server_field_id = server_field_id_usize.into();
+                            let field_name = field.item.name;
+                            let schema_object = self.server_field_data.object_mut(object_id);
+                            if schema_object
+                                .encountered_fields
+                                .insert(field_name.item, FieldType::ServerField(server_field_id))
+                                .is_some()
+                            {
+                                return Err(WithLocation::new(
+                                    ProcessTypeDefinitionError::DuplicateField {
+                                        field_name: field_name.item,
+                                        parent_type: schema_object.name,
+                                    },
+                                    field_name.location,
+                                ));
+                            }
+                            // The id field may be added in an extension, if the type does not
+                            // already have one.
+                            if field_name.item == id_name {
+                                match schema_object.id_field {
+                                    None => set_and_validate_id_field(
+                                        &mut schema_object.id_field,
+                                        server_field_id_usize,
+                                        &field,
+                                        schema_object.name,
+                                        options,
+                                    )?,
+                                    Some(_) if has_strong_directive(&field) => {
+                                        return Err(WithLocation::new(
+                                            ProcessTypeDefinitionError::DuplicateStrongIdField {
+                                                parent_type: schema_object.name,
+                                            },
+                                            field_name.location,
+                                        ));
+                                    }
+                                    Some(_) => {}
+                                }
+                            }
+                            self.server_fields.push(create_server_field(
+                                field,
+                                server_field_id,
+                                object_id,
+                                parent_type_is_client_only,
+                            )?);
+                        }
+
+                        self.server_field_data
+                            .object_mut(object_id)
+                            .directives
+                            .extend(object_extension.directives);
 
                         Ok(())
                     }
@@ -400,17 +603,23 @@ impl UnvalidatedSchema {
                     type_def_2.name.item,
                     get_typename_type(string_type_for_typename.item),
                     may_have_id_field,
+                    has_client_directive(&object_type_definition.directives),
                     options,
                 )?;
 
                 objects.push(SchemaObject {
                     description: object_type_definition.description.map(|d| d.item),
                     name: object_type_definition.name.item,
+                    name_location: object_type_definition.name.location,
                     id: next_object_id,
                     encountered_fields,
                     id_field,
+                    // This is determined once all types and extensions have been processed
+                    refetch_field: None,
                     directives: object_type_definition.directives,
                     concrete_type,
+                    // This is determined once all types have been processed
+                    concrete_subtypes: None,
                 });
 
                 schema_fields.extend(unvalidated_schema_fields);
@@ -438,6 +647,8 @@ impl UnvalidatedSchema {
     fn process_scalar_definition(
         &mut self,
         scalar_type_definition: GraphQLScalarTypeDefinition,
+        enum_values: Vec<EnumLiteralValue>,
+        deprecated_enum_values: BTreeMap<EnumLiteralValue, StringLiteralValue>,
     ) -> ProcessTypeDefinitionResult<()> {
         let &mut Schema {
             server_field_data: ref mut schema_data,
@@ -462,6 +673,8 @@ impl UnvalidatedSchema {
                     name: scalar_type_definition.name,
                     id: next_scalar_id,
                     javascript_name: *STRING_JAVASCRIPT_TYPE,
+                    enum_values,
+                    deprecated_enum_values,
                 });
 
                 vacant.insert(SelectableServerFieldId::Scalar(next_scalar_id));
@@ -577,6 +790,7 @@ struct FieldObjectIdsEtc {
 
 /// Given a vector of fields from the schema AST all belonging to the same object/interface,
 /// return a vector of unvalidated fields and a set of field names.
+#[allow(clippy::too_many_arguments)]
 fn get_field_objects_ids_and_names(
     new_fields: Vec<WithLocation<GraphQLFieldDefinition>>,
     next_field_id: usize,
@@ -585,6 +799,7 @@ fn get_field_objects_ids_and_names(
     typename_type: GraphQLTypeAnnotation<UnvalidatedTypeName>,
     // TODO this is hacky
     may_have_field_id: bool,
+    parent_type_is_client_only: bool,
     options: ConfigOptions,
 ) -> ProcessTypeDefinitionResult<FieldObjectIdsEtc> {
     let new_field_count = new_fields.len();
@@ -592,7 +807,7 @@ fn get_field_objects_ids_and_names(
     let mut unvalidated_fields = Vec::with_capacity(new_field_count);
     let mut server_field_ids = Vec::with_capacity(new_field_count + 1); // +1 for the typename
     let mut id_field = None;
-    let id_name = "id".intern().into();
+    let id_name = get_strong_id_field_name(&new_fields, parent_type_name, options)?;
     for (current_field_index, field) in new_fields.into_iter().enumerate() {
         let next_server_field_id_usize = next_field_id + current_field_index;
         let next_server_field_id = next_server_field_id_usize.into();
@@ -602,7 +817,6 @@ fn get_field_objects_ids_and_names(
             FieldType::ServerField(next_server_field_id),
         ) {
             None => {
-                // TODO check for @strong directive instead!
                 if may_have_field_id && field.item.name.item == id_name {
                     set_and_validate_id_field(
                         &mut id_field,
@@ -613,23 +827,12 @@ fn get_field_objects_ids_and_names(
                     )?;
                 }
 
-                unvalidated_fields.push(SchemaServerField {
-                    description: field.item.description.map(|d| d.item),
-                    name: field.item.name,
-                    id: next_server_field_id,
-                    associated_data: ServerFieldTypeAssociatedData {
-                        type_name: field.item.type_,
-                        variant: SchemaServerFieldVariant::LinkedField,
-                    },
+                unvalidated_fields.push(create_server_field(
+                    field,
+                    next_server_field_id,
                     parent_type_id,
-                    arguments: field
-                        .item
-                        .arguments
-                        .into_iter()
-                        .map(graphql_input_value_definition_to_variable_definition)
-                        .collect::<Result<Vec<_>, _>>()?,
-                    is_discriminator: false,
-                });
+                    parent_type_is_client_only,
+                )?);
                 server_field_ids.push(next_server_field_id);
             }
             Some(_) => {
@@ -655,6 +858,7 @@ fn get_field_objects_ids_and_names(
     server_field_ids.push(typename_field_id);
     unvalidated_fields.push(SchemaServerField {
         description: None,
+        deprecation_reason: None,
         name: typename_name,
         id: typename_field_id,
         associated_data: ServerFieldTypeAssociatedData {
@@ -664,6 +868,8 @@ fn get_field_objects_ids_and_names(
         parent_type_id,
         arguments: vec![],
         is_discriminator: true,
+        is_client_only: parent_type_is_client_only,
+        is_semantic_non_null: false,
     });
 
     if encountered_fields
@@ -719,6 +925,38 @@ pub fn graphql_input_value_definition_to_variable_definition(
     ))
 }
 
+/// The strong id field of a type is the field with a @strong directive, if any,
+/// and otherwise the field named by the strong_id_field config option.
+fn get_strong_id_field_name(
+    fields: &[WithLocation<GraphQLFieldDefinition>],
+    parent_type_name: IsographObjectTypeName,
+    options: ConfigOptions,
+) -> ProcessTypeDefinitionResult<SelectableFieldName> {
+    let strong_fields = fields
+        .iter()
+        .filter(|field| has_strong_directive(field))
+        .collect::<Vec<_>>();
+
+    match strong_fields.as_slice() {
+        [] => Ok(options.strong_id_field_name),
+        [strong_field] => Ok(strong_field.item.name.item),
+        [_, strong_field, ..] => Err(WithLocation::new(
+            ProcessTypeDefinitionError::DuplicateStrongIdField {
+                parent_type: parent_type_name,
+            },
+            strong_field.item.name.location,
+        )),
+    }
+}
+
+fn has_strong_directive(field: &WithLocation<GraphQLFieldDefinition>) -> bool {
+    field
+        .item
+        .directives
+        .iter()
+        .any(|directive| directive.name.item == *STRONG_DIRECTIVE)
+}
+
 /// If we have encountered an id field, we can:
 /// - validate that the id field is properly defined, i.e. has type ID!
 /// - set the id field
@@ -743,7 +981,7 @@ fn set_and_validate_id_field(
                 options.on_invalid_id_type.on_failure(|| {
                     WithLocation::new(
                         ProcessTypeDefinitionError::IdFieldMustBeNonNullIdType {
-                            strong_field_name: "id",
+                            strong_field_name: field.item.name.item,
                             parent_type: parent_type_name,
                         },
                         // TODO this shows the wrong span?
@@ -757,7 +995,7 @@ fn set_and_validate_id_field(
             options.on_invalid_id_type.on_failure(|| {
                 WithLocation::new(
                     ProcessTypeDefinitionError::IdFieldMustBeNonNullIdType {
-                        strong_field_name: "id",
+                        strong_field_name: field.item.name.item,
                         parent_type: parent_type_name,
                     },
                     // TODO this shows the wrong span?
@@ -769,6 +1007,118 @@ fn set_and_validate_id_field(
     }
 }
 
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DeprecatedDirective {
+    #[serde(default = "default_deprecation_reason")]
+    reason: StringLiteralValue,
+}
+
+fn default_deprecation_reason() -> StringLiteralValue {
+    // This is the default reason in the GraphQL spec
+    "No longer supported".intern().into()
+}
+
+fn create_server_field(
+    field: WithLocation<GraphQLFieldDefinition>,
+    server_field_id: ServerFieldId,
+    parent_type_id: ServerObjectId,
+    parent_type_is_client_only: bool,
+) -> ProcessTypeDefinitionResult<UnvalidatedSchemaSchemaField> {
+    let deprecation_reason = get_deprecation_reason(&field.item.directives)?;
+    let is_client_only = parent_type_is_client_only || has_client_directive(&field.item.directives);
+    let is_semantic_non_null = get_is_semantic_non_null(&field.item.directives)?;
+
+    Ok(SchemaServerField {
+        description: field.item.description.map(|d| d.item),
+        deprecation_reason,
+        name: field.item.name,
+        id: server_field_id,
+        associated_data: ServerFieldTypeAssociatedData {
+            type_name: field.item.type_,
+            variant: SchemaServerFieldVariant::LinkedField,
+        },
+        parent_type_id,
+        arguments: field
+            .item
+            .arguments
+            .into_iter()
+            .map(graphql_input_value_definition_to_variable_definition)
+            .collect::<Result<Vec<_>, _>>()?,
+        is_discriminator: false,
+        is_client_only,
+        is_semantic_non_null,
+    })
+}
+
+pub(crate) fn has_client_directive(directives: &[GraphQLDirective<GraphQLConstantValue>]) -> bool {
+    directives
+        .iter()
+        .any(|directive| directive.name.item == *CLIENT_DIRECTIVE)
+}
+
+/// If a field or enum value has a @deprecated directive, return its reason.
+fn get_deprecation_reason(
+    directives: &[GraphQLDirective<GraphQLConstantValue>],
+) -> ProcessTypeDefinitionResult<Option<StringLiteralValue>> {
+    directives
+        .iter()
+        .find(|directive| directive.name.item == *DEPRECATED_DIRECTIVE)
+        .map(|directive| {
+            from_graph_ql_directive::<DeprecatedDirective>(directive)
+                .map(|deprecated_directive| deprecated_directive.reason)
+                .map_err(|err| match err {
+                    DeserializationError::Custom(err) => WithLocation::new(
+                        ProcessTypeDefinitionError::FailedToDeserialize(err),
+                        directive.name.location.into(),
+                    ),
+                })
+        })
+        .transpose()
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SemanticNonNullDirective {
+    #[serde(default = "default_semantic_non_null_levels")]
+    levels: Vec<i64>,
+}
+
+fn default_semantic_non_null_levels() -> Vec<i64> {
+    vec![0]
+}
+
+/// Whether a field has a @semanticNonNull directive. Only the field itself (level 0)
+/// can be semantically non-null; the items of list fields cannot, yet.
+fn get_is_semantic_non_null(
+    directives: &[GraphQLDirective<GraphQLConstantValue>],
+) -> ProcessTypeDefinitionResult<bool> {
+    let directive = match directives
+        .iter()
+        .find(|directive| directive.name.item == *SEMANTIC_NON_NULL_DIRECTIVE)
+    {
+        Some(directive) => directive,
+        None => return Ok(false),
+    };
+    let semantic_non_null_directive = from_graph_ql_directive::<SemanticNonNullDirective>(
+        directive,
+    )
+    .map_err(|err| match err {
+        DeserializationError::Custom(err) => WithLocation::new(
+            ProcessTypeDefinitionError::FailedToDeserialize(err),
+            directive.name.location.into(),
+        ),
+    })?;
+    match semantic_non_null_directive.levels.as_slice() {
+        [] => Ok(false),
+        [0] => Ok(true),
+        _ => Err(WithLocation::new(
+            ProcessTypeDefinitionError::UnsupportedSemanticNonNullLevels,
+            directive.name.location.into(),
+        )),
+    }
+}
+
 fn convert_graphql_constant_value_to_isograph_constant_value(
     graphql_constant_value: graphql_lang_types::GraphQLConstantValue,
 ) -> isograph_lang_types::ConstantValue {
@@ -819,6 +1169,31 @@ fn convert_graphql_constant_value_to_isograph_constant_value(
     }
 }
 
+/// For each type, find all of the types that are reachable from it in the map, e.g.
+/// all of the supertypes of each subtype. The type itself is excluded, even if the
+/// map contains a cycle.
+fn transitive_type_refinement_map(
+    type_refinement_map: &ValidatedTypeRefinementMap,
+) -> ValidatedTypeRefinementMap {
+    type_refinement_map
+        .iter()
+        .map(|(type_id, directly_related_type_ids)| {
+            let mut related_type_ids = vec![];
+            let mut stack = directly_related_type_ids.clone();
+            while let Some(related_type_id) = stack.pop() {
+                if related_type_id != *type_id && !related_type_ids.contains(&related_type_id) {
+                    related_type_ids.push(related_type_id);
+                    if let Some(next_type_ids) = type_refinement_map.get(&related_type_id) {
+                        stack.extend(next_type_ids.iter().copied());
+                    }
+                }
+            }
+            related_type_ids.sort();
+            (*type_id, related_type_ids)
+        })
+        .collect()
+}
+
 fn insert_into_type_refinement_maps(
     supertype_name: UnvalidatedTypeName,
     subtype_name: UnvalidatedTypeName, // aka the concrete type or union member
@@ -877,6 +1252,12 @@ pub enum ProcessTypeDefinitionError {
     #[error("Type \"{type_name}\" is never defined.")]
     IsographObjectTypeNameNotDefined { type_name: UnvalidatedTypeName },
 
+    #[error(
+        "@semanticNonNull is only supported on the field itself, i.e. with levels: [0]. \
+        The items of list fields cannot be semantically non-null, yet."
+    )]
+    UnsupportedSemanticNonNullLevels,
+
     // When type Foo implements Bar and Bar is scalar
     #[error("\"{implementing_object}\" attempted to implement \"{type_name}\". However, \"{type_name}\" is a scalar, but only other object types can be implemented.")]
     ObjectIsScalar {
@@ -887,6 +1268,15 @@ pub enum ProcessTypeDefinitionError {
     #[error("Expected {type_name} to be an object, but it was a scalar.")]
     GenericObjectIsScalar { type_name: UnvalidatedTypeName },
 
+    #[error(
+        "The union \"{union_name}\" has a member \"{member_name}\", which is not an object type. \
+        Union members must be object types."
+    )]
+    UnionMemberMustBeObjectType {
+        union_name: IsographObjectTypeName,
+        member_name: UnvalidatedTypeName,
+    },
+
     #[error(
         "You cannot manually defined the \"__typename\" field, which is defined in \"{parent_type}\"."
     )]
@@ -898,9 +1288,12 @@ pub enum ProcessTypeDefinitionError {
     )]
     IdFieldMustBeNonNullIdType {
         parent_type: IsographObjectTypeName,
-        strong_field_name: &'static str,
+        strong_field_name: SelectableFieldName,
     },
 
+    #[error("Only one field on \"{parent_type}\" can have a @strong directive.")]
+    DuplicateStrongIdField { parent_type: IsographObjectTypeName },
+
     #[error("The @exposeField directive should have three arguments")]
     InvalidPrimaryDirectiveArgumentCount,
 
@@ -979,6 +1372,63 @@ pub enum ProcessTypeDefinitionError {
     #[error("Root types must be objects. This type is a scalar.")]
     RootTypeMustBeObject,
 
+    #[error("`{type_name}` has more than one @refetchField directive.")]
+    DuplicateRefetchFieldDirective { type_name: IsographObjectTypeName },
+
+    #[error(
+        "The @refetchField directive on `{type_name}` refers to \
+        `{query_type_name}.{field_name}`, which is not a server field."
+    )]
+    RefetchFieldNotFound {
+        type_name: IsographObjectTypeName,
+        query_type_name: IsographObjectTypeName,
+        field_name: SelectableFieldName,
+    },
+
+    #[error(
+        "`{query_type_name}.{field_name}` cannot be used to refetch `{type_name}`, \
+        because it returns neither `{type_name}` nor an abstract type."
+    )]
+    RefetchFieldInvalidReturnType {
+        type_name: IsographObjectTypeName,
+        query_type_name: IsographObjectTypeName,
+        field_name: SelectableFieldName,
+    },
+
+    #[error(
+        "In a @refetchField directive's fieldMap, `{from}` is not a path to a scalar \
+        server field on `{type_name}`."
+    )]
+    RefetchFieldMapFromNotFound {
+        type_name: IsographObjectTypeName,
+        from: StringLiteralValue,
+    },
+
+    #[error(
+        "In a @refetchField directive's fieldMap, `{argument_name}` is not an argument \
+        of `{query_type_name}.{field_name}`."
+    )]
+    RefetchFieldMapArgumentNotFound {
+        query_type_name: IsographObjectTypeName,
+        field_name: SelectableFieldName,
+        argument_name: StringLiteralValue,
+    },
+
+    #[error(
+        "In a @refetchField directive's fieldMap, `{argument_name}` is provided more than once."
+    )]
+    RefetchFieldMapDuplicateArgument { argument_name: StringLiteralValue },
+
+    #[error(
+        "The @refetchField directive's fieldMap does not provide the required argument \
+        `{argument_name}` of `{query_type_name}.{field_name}`."
+    )]
+    RefetchFieldMapMissingArgument {
+        query_type_name: IsographObjectTypeName,
+        field_name: SelectableFieldName,
+        argument_name: VariableName,
+    },
+
     #[error("Failed to deserialize {0}")]
     FailedToDeserialize(String),
 }
@@ -0,0 +1,399 @@
+use std::collections::BTreeSet;
+
+use common_lang_types::{
+    DirectiveName, IsographObjectTypeName, LinkedFieldName, Location, SelectableFieldName, Span,
+    StringLiteralValue, VariableName, WithLocation, WithSpan,
+};
+use graphql_lang_types::{
+    from_graph_ql_directive, DeserializationError, GraphQLConstantValue, GraphQLDirective,
+    GraphQLTypeAnnotation,
+};
+use intern::{string_key::Intern, Lookup};
+use isograph_config::ConfigOptions;
+use isograph_lang_types::{
+    ArgumentKeyAndValue, IsographSelectionVariant, LinkedFieldSelection, NonConstantValue,
+    ScalarFieldSelection, SelectableServerFieldId, ServerFieldSelection, ServerObjectId,
+};
+use lazy_static::lazy_static;
+use serde::Deserialize;
+
+use crate::{
+    id_arguments, FieldMapItem, FieldType, ProcessTypeDefinitionError, ProcessTypeDefinitionResult,
+    RequiresRefinement, UnvalidatedSchema, UnvalidatedVariableDefinition, NODE_FIELD_NAME,
+};
+
+lazy_static! {
+    static ref REFETCH_FIELD_DIRECTIVE: DirectiveName = "refetchField".intern().into();
+}
+
+#[derive(Deserialize, Eq, PartialEq, Debug)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct RefetchFieldDirective {
+    field: StringLiteralValue,
+    #[serde(default)]
+    field_map: Option<Vec<FieldMapItem>>,
+}
+
+/// The variable that an argument of a refetch field is passed as. Refetch variables
+/// are prefixed with `isograph__refetch__`, so that they do not clash with the
+/// variables of the client fields whose data is refetched (e.g. a client field
+/// that defines `$id`).
+pub fn refetch_variable(argument_name: &str) -> VariableName {
+    format!("isograph__refetch__{argument_name}")
+        .intern()
+        .into()
+}
+
+/// Pass the object's strong id field as the id argument.
+fn default_refetch_field_map(id_field_name: SelectableFieldName) -> Vec<FieldMapItem> {
+    vec![FieldMapItem {
+        from: id_field_name.lookup().intern().into(),
+        to: "id".intern().into(),
+    }]
+}
+
+/// How objects of a given type are refetched, i.e. which field on the query
+/// type is selected, and which of the object's fields are passed as its arguments.
+///
+/// Objects with a strong id field are assumed to implement Node, and are refetched via
+/// `node(id:)`, unless the type has a `@refetchField` directive, e.g.
+/// `@refetchField(field: "repository", fieldMap: [{ from: "owner.login", to: "owner" }, ...])`.
+#[derive(Debug, Clone)]
+pub struct RefetchField {
+    pub field_name: LinkedFieldName,
+    /// Each from is a (possibly dotted) path to a scalar field on the object, and
+    /// each to is the name of an argument of the field.
+    pub field_map: Vec<FieldMapItem>,
+    /// The definitions of the variables passed as the field's arguments, which are
+    /// provided by the field map. See [refetch_variable].
+    pub arguments: Vec<UnvalidatedVariableDefinition>,
+    /// Some if the field returns a concrete type; None otherwise.
+    pub field_concrete_type: Option<IsographObjectTypeName>,
+    pub requires_refinement: RequiresRefinement,
+}
+
+impl RefetchField {
+    fn node(object_name: IsographObjectTypeName, id_field_name: SelectableFieldName) -> Self {
+        RefetchField {
+            field_name: *NODE_FIELD_NAME,
+            field_map: default_refetch_field_map(id_field_name),
+            arguments: id_arguments()
+                .into_iter()
+                .map(|argument| UnvalidatedVariableDefinition {
+                    name: argument.name.map(|name| refetch_variable(name.lookup())),
+                    ..argument
+                })
+                .collect(),
+            field_concrete_type: None,
+            requires_refinement: RequiresRefinement::Yes(object_name),
+        }
+    }
+
+    /// The fields that must be read from the object in order to refetch it.
+    pub fn refetch_selection_set(
+        &self,
+    ) -> Vec<WithSpan<ServerFieldSelection<IsographSelectionVariant, IsographSelectionVariant>>>
+    {
+        let paths = self
+            .field_map
+            .iter()
+            .map(|field_map_item| field_map_item.from.lookup().split('.').collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        selections_for_paths(&paths)
+    }
+
+    pub fn top_level_arguments(&self) -> Vec<ArgumentKeyAndValue> {
+        self.field_map
+            .iter()
+            .map(|field_map_item| ArgumentKeyAndValue {
+                key: field_map_item.to.lookup().intern().into(),
+                value: NonConstantValue::Variable(refetch_variable(field_map_item.to.lookup())),
+            })
+            .collect()
+    }
+
+    /// The field map, with each to replaced by the variable that the argument is
+    /// passed as.
+    pub fn variable_field_map(&self) -> Vec<FieldMapItem> {
+        self.field_map
+            .iter()
+            .map(|field_map_item| FieldMapItem {
+                from: field_map_item.from,
+                to: refetch_variable(field_map_item.to.lookup())
+                    .lookup()
+                    .intern()
+                    .into(),
+            })
+            .collect()
+    }
+}
+
+/// Turn paths like ["owner", "login"] and ["name"] into the selections
+/// `owner { login }, name`.
+pub(crate) fn selections_for_paths(
+    paths: &[Vec<&str>],
+) -> Vec<WithSpan<ServerFieldSelection<IsographSelectionVariant, IsographSelectionVariant>>> {
+    let mut selections = vec![];
+    let mut seen = BTreeSet::new();
+    for path in paths {
+        let (first, _) = path
+            .split_first()
+            .expect("Expected path to be non-empty. This is indicative of a bug in Isograph.");
+        if !seen.insert(*first) {
+            continue;
+        }
+        let subpaths = paths
+            .iter()
+            .filter(|other_path| other_path.first() == Some(first) && other_path.len() > 1)
+            .map(|other_path| other_path[1..].to_vec())
+            .collect::<Vec<_>>();
+
+        let selection = if subpaths.is_empty() {
+            ServerFieldSelection::ScalarField(ScalarFieldSelection {
+                name: WithLocation::new(first.intern().into(), Location::generated()),
+                reader_alias: None,
+                associated_data: IsographSelectionVariant::Regular,
+                arguments: vec![],
+                directives: vec![],
+            })
+        } else {
+            ServerFieldSelection::LinkedField(LinkedFieldSelection {
+                name: WithLocation::new(first.intern().into(), Location::generated()),
+                reader_alias: None,
+                associated_data: IsographSelectionVariant::Regular,
+                selection_set: selections_for_paths(&subpaths),
+                arguments: vec![],
+                directives: vec![],
+            })
+        };
+        selections.push(WithSpan::new(selection, Span::todo_generated()));
+    }
+    selections
+}
+
+impl UnvalidatedSchema {
+    /// For each object, determine how it is refetched. Objects with a `@refetchField`
+    /// directive are refetched via the field it names; other objects with an id field
+    /// are refetched via `node(id:)`.
+    pub fn add_refetch_fields_to_object_types(
+        &mut self,
+        options: ConfigOptions,
+    ) -> ProcessTypeDefinitionResult<()> {
+        for object_id in 0..self.server_field_data.server_objects.len() {
+            let object_id = object_id.into();
+            let object = self.server_field_data.object(object_id);
+
+            let refetch_field_directives = object
+                .directives
+                .iter()
+                .filter(|directive| directive.name.item == *REFETCH_FIELD_DIRECTIVE)
+                .collect::<Vec<_>>();
+
+            let refetch_field = match refetch_field_directives.as_slice() {
+                [] => object.id_field.map(|id_field_id| {
+                    RefetchField::node(object.name, self.server_field(id_field_id.into()).name.item)
+                }),
+                [directive] => {
+                    Some(self.process_refetch_field_directive(object_id, directive, options)?)
+                }
+                [_, directive, ..] => {
+                    return Err(WithLocation::new(
+                        ProcessTypeDefinitionError::DuplicateRefetchFieldDirective {
+                            type_name: object.name,
+                        },
+                        directive.name.location.into(),
+                    ))
+                }
+            };
+
+            self.server_field_data.object_mut(object_id).refetch_field = refetch_field;
+        }
+        Ok(())
+    }
+
+    fn process_refetch_field_directive(
+        &self,
+        object_id: ServerObjectId,
+        directive: &GraphQLDirective<GraphQLConstantValue>,
+        options: ConfigOptions,
+    ) -> ProcessTypeDefinitionResult<RefetchField> {
+        let location: Location = directive.name.location.into();
+        let RefetchFieldDirective { field, field_map } = from_graph_ql_directive(directive)
+            .map_err(|err| match err {
+                DeserializationError::Custom(err) => WithLocation::new(
+                    ProcessTypeDefinitionError::FailedToDeserialize(err),
+                    location,
+                ),
+            })?;
+
+        let object = self.server_field_data.object(object_id);
+        let field_map = field_map.unwrap_or_else(|| {
+            default_refetch_field_map(
+                self.strong_id_field_name(object_id)
+                    .unwrap_or(options.strong_id_field_name),
+            )
+        });
+        let query = self.server_field_data.object(self.query_id());
+        let field_name: SelectableFieldName = field.lookup().intern().into();
+
+        let server_field = match query.encountered_fields.get(&field_name) {
+            Some(FieldType::ServerField(server_field_id)) => self.server_field(*server_field_id),
+            _ => {
+                return Err(WithLocation::new(
+                    ProcessTypeDefinitionError::RefetchFieldNotFound {
+                        type_name: object.name,
+                        query_type_name: query.name,
+                        field_name,
+                    },
+                    location,
+                ))
+            }
+        };
+
+        let (field_concrete_type, requires_refinement) = match self
+            .server_field_data
+            .defined_types
+            .get(server_field.associated_data.type_name.inner())
+        {
+            Some(SelectableServerFieldId::Object(return_type_id))
+                if *return_type_id == object_id =>
+            {
+                (object.concrete_type, RequiresRefinement::No)
+            }
+            Some(SelectableServerFieldId::Object(return_type_id))
+                if self
+                    .server_field_data
+                    .object(*return_type_id)
+                    .concrete_type
+                    .is_none() =>
+            {
+                (None, RequiresRefinement::Yes(object.name))
+            }
+            _ => {
+                return Err(WithLocation::new(
+                    ProcessTypeDefinitionError::RefetchFieldInvalidReturnType {
+                        type_name: object.name,
+                        query_type_name: query.name,
+                        field_name,
+                    },
+                    location,
+                ))
+            }
+        };
+
+        let mut arguments = vec![];
+        for field_map_item in field_map.iter() {
+            self.validate_refetch_field_map_from(object_id, field_map_item.from, location)?;
+
+            let argument = server_field
+                .arguments
+                .iter()
+                .find(|argument| argument.item.name.item.lookup() == field_map_item.to.lookup())
+                .ok_or_else(|| {
+                    WithLocation::new(
+                        ProcessTypeDefinitionError::RefetchFieldMapArgumentNotFound {
+                            query_type_name: query.name,
+                            field_name,
+                            argument_name: field_map_item.to,
+                        },
+                        location,
+                    )
+                })?;
+            if arguments
+                .iter()
+                .any(|existing: &UnvalidatedVariableDefinition| {
+                    existing.name.item == refetch_variable(argument.item.name.item.lookup())
+                })
+            {
+                return Err(WithLocation::new(
+                    ProcessTypeDefinitionError::RefetchFieldMapDuplicateArgument {
+                        argument_name: field_map_item.to,
+                    },
+                    location,
+                ));
+            }
+            arguments.push(UnvalidatedVariableDefinition {
+                name: argument
+                    .item
+                    .name
+                    .map(|name| refetch_variable(name.lookup())),
+                type_: argument.item.type_.clone(),
+                default_value: None,
+            });
+        }
+
+        for argument in server_field.arguments.iter() {
+            let is_required = matches!(argument.item.type_, GraphQLTypeAnnotation::NonNull(_))
+                && argument.item.default_value.is_none();
+            if is_required
+                && !arguments.iter().any(|provided| {
+                    provided.name.item == refetch_variable(argument.item.name.item.lookup())
+                })
+            {
+                return Err(WithLocation::new(
+                    ProcessTypeDefinitionError::RefetchFieldMapMissingArgument {
+                        query_type_name: query.name,
+                        field_name,
+                        argument_name: argument.item.name.item,
+                    },
+                    location,
+                ));
+            }
+        }
+
+        Ok(RefetchField {
+            field_name: field_name.lookup().intern().into(),
+            field_map,
+            arguments,
+            field_concrete_type,
+            requires_refinement,
+        })
+    }
+
+    /// The from path must consist of linked server fields, followed by a scalar server field.
+    fn validate_refetch_field_map_from(
+        &self,
+        object_id: ServerObjectId,
+        from: StringLiteralValue,
+        location: Location,
+    ) -> ProcessTypeDefinitionResult<()> {
+        let type_name = self.server_field_data.object(object_id).name;
+        let invalid_from = || {
+            WithLocation::new(
+                ProcessTypeDefinitionError::RefetchFieldMapFromNotFound { type_name, from },
+                location,
+            )
+        };
+
+        let mut segments = from.lookup().split('.').peekable();
+        let mut current_object_id = object_id;
+        while let Some(segment) = segments.next() {
+            let segment_name: SelectableFieldName = segment.intern().into();
+            let server_field = match self
+                .server_field_data
+                .object(current_object_id)
+                .encountered_fields
+                .get(&segment_name)
+            {
+                Some(FieldType::ServerField(server_field_id)) => {
+                    self.server_field(*server_field_id)
+                }
+                _ => return Err(invalid_from()),
+            };
+
+            match (
+                self.server_field_data
+                    .defined_types
+                    .get(server_field.associated_data.type_name.inner()),
+                segments.peek().is_some(),
+            ) {
+                (Some(SelectableServerFieldId::Object(next_object_id)), true) => {
+                    current_object_id = *next_object_id;
+                }
+                (Some(SelectableServerFieldId::Scalar(_)), false) => {}
+                _ => return Err(invalid_from()),
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,14 +1,9 @@
 use std::{collections::BTreeSet, fmt::Debug};
 
 use common_lang_types::{
-    IsographObjectTypeName, LinkedFieldName, Location, QueryOperationName, Span, VariableName,
-    WithLocation, WithSpan,
-};
-use intern::string_key::Intern;
-use isograph_lang_types::{
-    ArgumentKeyAndValue, IsographSelectionVariant, ScalarFieldSelection, ServerFieldSelection,
-    ServerObjectId,
+    IsographObjectTypeName, LinkedFieldName, QueryOperationName, VariableName, WithSpan,
 };
+use isograph_lang_types::{ArgumentKeyAndValue, ServerFieldSelection, ServerObjectId};
 
 use crate::{
     get_reachable_variables, selection_map_wrapped, MergedSelectionMap, RequiresRefinement,
@@ -94,6 +89,27 @@ pub fn generate_refetch_field_strategy<
     }
 }
 
+/// Generate a refetch strategy for a field on a root object (e.g. `Query`). Such
+/// fields need nothing from their parent, and are refetched by re-running the
+/// root query, instead of by wrapping the selections in `node(id:)`.
+pub fn generate_refetch_from_root_strategy<
+    TClientFieldSelectionScalarFieldAssociatedData,
+    TClientFieldSelectionLinkedFieldAssociatedData,
+>(
+    root_fetchable_type: ServerObjectId,
+    refetch_query_name: QueryOperationName,
+) -> UseRefetchFieldRefetchStrategy<
+    TClientFieldSelectionScalarFieldAssociatedData,
+    TClientFieldSelectionLinkedFieldAssociatedData,
+> {
+    UseRefetchFieldRefetchStrategy {
+        refetch_selection_set: vec![],
+        root_fetchable_type,
+        generate_refetch_query: Box::new(GenerateRefetchFromRootQueryImpl),
+        refetch_query_name,
+    }
+}
+
 #[derive(Debug)]
 pub struct UseRefetchFieldRefetchStrategy<
     TClientFieldSelectionScalarFieldAssociatedData,
@@ -163,16 +179,15 @@ impl GenerateRefetchQueryFn for GenerateRefetchQueryImpl {
     }
 }
 
-pub fn id_selection(
-) -> WithSpan<ServerFieldSelection<IsographSelectionVariant, IsographSelectionVariant>> {
-    WithSpan::new(
-        ServerFieldSelection::ScalarField(ScalarFieldSelection {
-            name: WithLocation::new("id".intern().into(), Location::generated()),
-            reader_alias: None,
-            associated_data: IsographSelectionVariant::Regular,
-            arguments: vec![],
-            directives: vec![],
-        }),
-        Span::todo_generated(),
-    )
+#[derive(Debug)]
+struct GenerateRefetchFromRootQueryImpl;
+
+impl GenerateRefetchQueryFn for GenerateRefetchFromRootQueryImpl {
+    fn generate_refetch_query(
+        &self,
+        inner_selection_map: MergedSelectionMap,
+    ) -> (MergedSelectionMap, BTreeSet<VariableName>) {
+        let variables = get_reachable_variables(&inner_selection_map);
+        (inner_selection_map, variables)
+    }
 }
@@ -181,6 +181,8 @@ fn add_schema_defined_scalar_type(
         name: typename,
         id: scalar_id,
         javascript_name,
+        enum_values: vec![],
+        deprecated_enum_values: BTreeMap::new(),
     });
     defined_types.insert(
         typename.item.into(),
@@ -1,27 +1,40 @@
-use std::collections::{BTreeSet, HashMap};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+};
 
 use common_lang_types::{
-    FieldArgumentName, Location, SelectableFieldName, UnvalidatedTypeName, VariableName,
-    WithLocation, WithSpan,
+    EnumLiteralValue, FieldArgumentName, IsographDirectiveName, Location, SelectableFieldName,
+    Span, UnvalidatedTypeName, VariableName, WithLocation, WithSpan,
+};
+use graphql_lang_types::{
+    GraphQLNamedTypeAnnotation, GraphQLNonNullTypeAnnotation, GraphQLTypeAnnotation,
 };
 use intern::{string_key::Intern, Lookup};
+use isograph_config::ConfigOptions;
 use isograph_lang_types::{
-    reachable_variables, ClientFieldId, IsographSelectionVariant, LinkedFieldSelection,
-    ScalarFieldSelection, SelectionFieldArgument, SelectionType, UnvalidatedScalarFieldSelection,
-    UnvalidatedSelection, VariableDefinition,
+    from_isograph_field_directive, reachable_variables, ClientFieldId, IsographFieldDirective,
+    IsographSelectionVariant, LinkedFieldSelection, NonConstantValue, RequiredAction,
+    RequiredDirectiveParameters, ScalarFieldSelection, SelectableServerFieldId, SelectionCondition,
+    SelectionConditionKind, SelectionFieldArgument, SelectionType, ServerFieldId, ServerObjectId,
+    StreamDirectiveParameters, UnvalidatedScalarFieldSelection, UnvalidatedSelection,
+    VariableDefinition,
 };
 use lazy_static::lazy_static;
 
 use crate::{
     get_all_errors_or_all_ok, get_all_errors_or_all_ok_as_hashmap, get_all_errors_or_all_ok_iter,
-    get_all_errors_or_tuple_ok, ClientField, ClientType, FieldType, ObjectTypeAndFieldName,
-    RefetchStrategy, SchemaObject, ServerFieldData, UnvalidatedClientField,
-    UnvalidatedLinkedFieldSelection, UnvalidatedRefetchFieldStrategy,
-    UnvalidatedVariableDefinition, ValidateSchemaError, ValidateSchemaResult, ValidatedClientField,
-    ValidatedIsographSelectionVariant, ValidatedLinkedFieldAssociatedData,
-    ValidatedLinkedFieldSelection, ValidatedRefetchFieldStrategy,
-    ValidatedScalarFieldAssociatedData, ValidatedScalarFieldSelection, ValidatedSchemaServerField,
-    ValidatedSelection, ValidatedVariableDefinition,
+    get_all_errors_or_tuple_ok, get_name, print_validated_type, variable_usage_is_allowed,
+    ClientField, ClientFieldVariant, ClientType, FieldType, ObjectTypeAndFieldName,
+    PaginationDirection, RefetchStrategy, RootOperationName, SchemaObject, SchemaScalar,
+    ServerFieldData, UnvalidatedClientField, UnvalidatedLinkedFieldSelection,
+    UnvalidatedRefetchFieldStrategy, UnvalidatedVariableDefinition, ValidateSchemaError,
+    ValidateSchemaResult, ValidatedClientField, ValidatedIsographSelectionVariant,
+    ValidatedLinkedFieldAssociatedData, ValidatedLinkedFieldSelection,
+    ValidatedRefetchFieldStrategy, ValidatedScalarFieldAssociatedData,
+    ValidatedScalarFieldSelection, ValidatedSchemaServerField, ValidatedSelection,
+    ValidatedVariableDefinition, CURSOR_FIELD_NAME, EDGES_FIELD_NAME, NODE_FIELD_NAME_IN_EDGE,
+    PAGE_INFO_FIELD_NAME,
 };
 
 type UsedVariables = BTreeSet<VariableName>;
@@ -30,12 +43,20 @@ type ClientFieldArgsMap =
 
 lazy_static! {
     static ref ID: FieldArgumentName = "id".intern().into();
+    static ref IF: VariableName = "if".intern().into();
+    static ref REQUIRED_DIRECTIVE_NAME: IsographDirectiveName = "required".intern().into();
+    static ref UPDATABLE_DIRECTIVE_NAME: IsographDirectiveName = "updatable".intern().into();
+    static ref DEFER_DIRECTIVE_NAME: IsographDirectiveName = "defer".intern().into();
+    static ref STREAM_DIRECTIVE_NAME: IsographDirectiveName = "stream".intern().into();
 }
 
 pub(crate) fn validate_and_transform_client_fields(
     client_fields: Vec<ClientType<UnvalidatedClientField>>,
     schema_data: &ServerFieldData,
     server_fields: &[ValidatedSchemaServerField],
+    fetchable_types: &BTreeMap<ServerObjectId, RootOperationName>,
+    options: ConfigOptions,
+    warnings: &mut Vec<WithLocation<ValidateSchemaError>>,
 ) -> Result<Vec<ClientType<ValidatedClientField>>, Vec<WithLocation<ValidateSchemaError>>> {
     // TODO this smells. We probably should do this in two passes instead of doing it this
     // way. We are validating client fields, which includes validating their selections. When
@@ -60,18 +81,38 @@ pub(crate) fn validate_and_transform_client_fields(
             }
         }))?;
 
-    get_all_errors_or_all_ok_iter(client_fields.into_iter().map(|client_field| {
-        match client_field {
-            ClientType::ClientField(client_field) => validate_client_field_selection_set(
-                schema_data,
-                client_field,
-                server_fields,
-                &client_field_args,
-            )
-            .map(ClientType::ClientField)
-            .map_err(|err| err.into_iter()),
-        }
-    }))
+    // Likewise, when validating a selection of a client field with @loadable, we need to
+    // know whether that client field can be refetched.
+    let refetchable_client_fields = client_fields
+        .iter()
+        .filter_map(|client_field| match client_field {
+            ClientType::ClientField(client_field) => client_field
+                .refetch_strategy
+                .as_ref()
+                .map(|_| client_field.id),
+        })
+        .collect::<HashSet<_>>();
+
+    let collected_warnings = RefCell::new(vec![]);
+    let validated_client_fields =
+        get_all_errors_or_all_ok_iter(client_fields.into_iter().map(|client_field| {
+            match client_field {
+                ClientType::ClientField(client_field) => validate_client_field_selection_set(
+                    schema_data,
+                    client_field,
+                    server_fields,
+                    &client_field_args,
+                    &refetchable_client_fields,
+                    fetchable_types,
+                    options,
+                    &collected_warnings,
+                )
+                .map(ClientType::ClientField)
+                .map_err(|err| err.into_iter()),
+            }
+        }));
+    warnings.extend(collected_warnings.into_inner());
+    validated_client_fields
 }
 
 fn validate_all_variables_are_used(
@@ -91,7 +132,8 @@ fn validate_all_variables_are_used(
         })
         .collect();
 
-    if !unused_variables.is_empty() {
+    if let Some(first_unused_variable) = unused_variables.first() {
+        let location = first_unused_variable.item.name.location;
         return Err(WithLocation::new(
             ValidateSchemaError::UnusedVariables {
                 unused_variables,
@@ -102,7 +144,7 @@ fn validate_all_variables_are_used(
                     .client_field_type_and_field_name
                     .field_name,
             },
-            Location::generated(),
+            location,
         ));
     }
     Ok(())
@@ -112,24 +154,39 @@ fn validate_all_variables_are_used(
 // encapsulate them in a single struct.
 struct ValidateSchemaSharedInfo<'a> {
     client_field_args: &'a ClientFieldArgsMap,
+    refetchable_client_fields: &'a HashSet<ClientFieldId>,
     client_field_type_and_field_name: ObjectTypeAndFieldName,
     client_field_parent_object: &'a SchemaObject,
     schema_data: &'a ServerFieldData,
     server_fields: &'a [ValidatedSchemaServerField],
+    fetchable_types: &'a BTreeMap<ServerObjectId, RootOperationName>,
+    options: ConfigOptions,
+    /// Validation failures that are configured to be warnings, e.g. selections
+    /// of deprecated fields.
+    warnings: &'a RefCell<Vec<WithLocation<ValidateSchemaError>>>,
 }
 
+#[allow(clippy::too_many_arguments)]
 fn validate_client_field_selection_set(
     schema_data: &ServerFieldData,
     top_level_client_field: UnvalidatedClientField,
     server_fields: &[ValidatedSchemaServerField],
     client_field_args: &ClientFieldArgsMap,
+    refetchable_client_fields: &HashSet<ClientFieldId>,
+    fetchable_types: &BTreeMap<ServerObjectId, RootOperationName>,
+    options: ConfigOptions,
+    warnings: &RefCell<Vec<WithLocation<ValidateSchemaError>>>,
 ) -> Result<ValidatedClientField, Vec<WithLocation<ValidateSchemaError>>> {
     let top_level_client_field_info = ValidateSchemaSharedInfo {
         client_field_args,
+        refetchable_client_fields,
         client_field_type_and_field_name: top_level_client_field.type_and_field,
         client_field_parent_object: schema_data.object(top_level_client_field.parent_object_id),
         schema_data,
         server_fields,
+        fetchable_types,
+        options,
+        warnings,
     };
 
     let variable_definitions = client_field_args
@@ -140,12 +197,17 @@ fn validate_client_field_selection_set(
         )
         .clone();
 
+    let is_updatable = match &top_level_client_field.variant {
+        ClientFieldVariant::UserWritten(info) => info.is_updatable,
+        ClientFieldVariant::ImperativelyLoadedField(_) => false,
+    };
     let selection_set_result = top_level_client_field
         .reader_selection_set
         .map(|selection_set| {
             validate_client_field_definition_selections_exist_and_types_match(
                 selection_set,
                 top_level_client_field.variable_definitions,
+                is_updatable,
                 &top_level_client_field_info,
             )
         })
@@ -190,6 +252,7 @@ fn validate_use_refetch_field_strategy(
     let refetch_selection_set = validate_client_field_definition_selections_exist_and_types_match(
         use_refetch_field_strategy.refetch_selection_set,
         vec![],
+        false,
         top_level_client_field_info,
     )?;
 
@@ -237,6 +300,7 @@ fn validate_variable_definitions(
 fn validate_client_field_definition_selections_exist_and_types_match(
     field_selection_set: Vec<WithSpan<UnvalidatedSelection>>,
     field_variable_definitions: Vec<WithSpan<UnvalidatedVariableDefinition>>,
+    is_updatable: bool,
     top_level_client_field_info: &ValidateSchemaSharedInfo<'_>,
 ) -> Result<Vec<WithSpan<ValidatedSelection>>, Vec<WithLocation<ValidateSchemaError>>> {
     // Currently, we only check that each field exists and has an appropriate type, not that
@@ -251,6 +315,7 @@ fn validate_client_field_definition_selections_exist_and_types_match(
                 top_level_client_field_info.client_field_parent_object,
                 &mut used_variables,
                 &field_variable_definitions,
+                is_updatable,
                 top_level_client_field_info,
             )
         }));
@@ -273,6 +338,7 @@ fn validate_client_field_definition_selection_exists_and_type_matches(
     field_parent_object: &SchemaObject,
     used_variables: &mut UsedVariables,
     variable_definitions: &[WithSpan<UnvalidatedVariableDefinition>],
+    is_updatable: bool,
     top_level_client_field_info: &ValidateSchemaSharedInfo<'_>,
 ) -> ValidateSchemaResult<WithSpan<ValidatedSelection>> {
     let mut used_variables2 = BTreeSet::new();
@@ -285,6 +351,7 @@ fn validate_client_field_definition_selection_exists_and_type_matches(
                     scalar_field_selection,
                     used_variables,
                     variable_definitions,
+                    is_updatable,
                     top_level_client_field_info,
                 )
             },
@@ -294,6 +361,7 @@ fn validate_client_field_definition_selection_exists_and_type_matches(
                     linked_field_selection,
                     &mut used_variables2,
                     variable_definitions,
+                    is_updatable,
                     top_level_client_field_info,
                 )
             },
@@ -312,6 +380,7 @@ fn validate_field_type_exists_and_is_scalar(
     scalar_field_selection: UnvalidatedScalarFieldSelection,
     used_variables: &mut UsedVariables,
     variable_definitions: &[WithSpan<UnvalidatedVariableDefinition>],
+    is_updatable: bool,
     top_level_client_field_info: &ValidateSchemaSharedInfo<'_>,
 ) -> ValidateSchemaResult<ValidatedScalarFieldSelection> {
     let scalar_field_name = scalar_field_selection.name.item.into();
@@ -323,6 +392,12 @@ fn validate_field_type_exists_and_is_scalar(
             FieldType::ServerField(server_field_id) => {
                 let server_field =
                     &top_level_client_field_info.server_fields[server_field_id.as_usize()];
+                validate_server_field_is_not_deprecated(
+                    server_field,
+                    scalar_field_selection_parent_object,
+                    scalar_field_selection.name.location,
+                    top_level_client_field_info,
+                )?;
                 let missing_arguments = get_missing_arguments_and_validate_argument_types(
                     server_field
                         .arguments
@@ -333,7 +408,35 @@ fn validate_field_type_exists_and_is_scalar(
                     scalar_field_selection.name.location,
                     used_variables,
                     variable_definitions,
+                    top_level_client_field_info,
+                )?;
+                let conditions = validate_selection_conditions(
+                    &scalar_field_selection.directives,
+                    scalar_field_selection.name.location,
+                    used_variables,
+                    variable_definitions,
+                    top_level_client_field_info,
                 )?;
+                let required = get_required_action(
+                    &scalar_field_selection.directives,
+                    server_field.is_semantic_non_null,
+                );
+                if is_deferred(&scalar_field_selection.directives) {
+                    return Err(WithLocation::new(
+                        ValidateSchemaError::ServerScalarFieldCannotBeDeferred {
+                            server_field_name: scalar_field_name,
+                        },
+                        scalar_field_selection.name.location,
+                    ));
+                }
+                // The id and __typename of a record determine which record it is, so
+                // they cannot be updated.
+                let is_updatable = is_updatable
+                    && !server_field.is_discriminator
+                    && scalar_field_selection_parent_object
+                        .id_field
+                        .map(ServerFieldId::from)
+                        != Some(*server_field_id);
 
                 match &server_field.associated_data {
                     SelectionType::Scalar(_) => Ok(ScalarFieldSelection {
@@ -345,6 +448,7 @@ fn validate_field_type_exists_and_is_scalar(
                                     assert_no_missing_arguments(
                                         missing_arguments,
                                         scalar_field_selection.name.location,
+                                        top_level_client_field_info.schema_data,
                                     )?;
                                     ValidatedIsographSelectionVariant::Regular
                                 }
@@ -358,7 +462,17 @@ fn validate_field_type_exists_and_is_scalar(
                                         missing_arguments,
                                     ))
                                 }
+                                IsographSelectionVariant::Paginated(_) => {
+                                    panic!(
+                                        "Expected @paginated not to be used on a scalar field. \
+                                        This is indicative of a bug in Isograph."
+                                    )
+                                }
                             },
+                            conditions,
+                            required,
+                            is_updatable,
+                            is_deferred: false,
                         },
                         reader_alias: scalar_field_selection.reader_alias,
                         arguments: scalar_field_selection.arguments,
@@ -386,9 +500,18 @@ fn validate_field_type_exists_and_is_scalar(
                 }
             }
             FieldType::ClientField(ClientType::ClientField(client_field_id)) => {
+                if is_updatable {
+                    return Err(WithLocation::new(
+                        ValidateSchemaError::ClientFieldCannotBeSelectedInUpdatableSelectionSet {
+                            client_field_name: scalar_field_name,
+                        },
+                        scalar_field_selection.name.location,
+                    ));
+                }
                 validate_client_field(
                     client_field_id,
                     scalar_field_selection,
+                    scalar_field_selection_parent_object,
                     used_variables,
                     variable_definitions,
                     top_level_client_field_info,
@@ -414,6 +537,7 @@ fn validate_field_type_exists_and_is_scalar(
 fn validate_client_field(
     client_field_id: &ClientFieldId,
     scalar_field_selection: UnvalidatedScalarFieldSelection,
+    scalar_field_selection_parent_object: &SchemaObject,
     used_variables: &mut UsedVariables,
     variable_definitions: &[WithSpan<UnvalidatedVariableDefinition>],
     top_level_client_field_info: &ValidateSchemaSharedInfo<'_>,
@@ -434,7 +558,24 @@ fn validate_client_field(
         scalar_field_selection.name.location,
         used_variables,
         variable_definitions,
+        top_level_client_field_info,
+    )?;
+    let conditions = validate_selection_conditions(
+        &scalar_field_selection.directives,
+        scalar_field_selection.name.location,
+        used_variables,
+        variable_definitions,
+        top_level_client_field_info,
     )?;
+    let is_deferred = is_deferred(&scalar_field_selection.directives);
+    if get_required_action(&scalar_field_selection.directives, false).is_some() {
+        return Err(WithLocation::new(
+            ValidateSchemaError::ClientFieldCannotBeSelectedWithRequired {
+                client_field_name: scalar_field_selection.name.item.into(),
+            },
+            scalar_field_selection.name.location,
+        ));
+    }
 
     Ok(ScalarFieldSelection {
         name: scalar_field_selection.name,
@@ -446,13 +587,37 @@ fn validate_client_field(
                     assert_no_missing_arguments(
                         missing_arguments,
                         scalar_field_selection.name.location,
+                        top_level_client_field_info.schema_data,
                     )?;
                     ValidatedIsographSelectionVariant::Regular
                 }
                 IsographSelectionVariant::Loadable(l) => {
+                    if is_deferred {
+                        return Err(WithLocation::new(
+                            ValidateSchemaError::LoadableClientFieldCannotBeDeferred {
+                                client_field_name: scalar_field_selection.name.item.into(),
+                            },
+                            scalar_field_selection.name.location,
+                        ));
+                    }
+                    client_field_can_be_selected_loadably(
+                        client_field_id,
+                        scalar_field_selection_parent_object,
+                        scalar_field_selection.name.item.into(),
+                        scalar_field_selection.name.location,
+                        top_level_client_field_info,
+                    )?;
                     ValidatedIsographSelectionVariant::Loadable((l, missing_arguments))
                 }
+                IsographSelectionVariant::Paginated(_) => panic!(
+                    "Expected @paginated not to be used on a scalar field. \
+                    This is indicative of a bug in Isograph."
+                ),
             },
+            conditions,
+            required: None,
+            is_updatable: false,
+            is_deferred,
         },
         arguments: scalar_field_selection.arguments,
         directives: scalar_field_selection.directives,
@@ -466,14 +631,27 @@ fn validate_field_type_exists_and_is_linked(
     linked_field_selection: UnvalidatedLinkedFieldSelection,
     used_variables: &mut UsedVariables,
     variable_definitions: &[WithSpan<UnvalidatedVariableDefinition>],
+    is_updatable: bool,
     top_level_client_field_info: &ValidateSchemaSharedInfo<'_>,
 ) -> ValidateSchemaResult<ValidatedLinkedFieldSelection> {
     let linked_field_name = linked_field_selection.name.item.into();
+    // Everything selected within a linked field selected with @updatable can be updated.
+    let is_updatable = is_updatable
+        || linked_field_selection
+            .directives
+            .iter()
+            .any(|directive| directive.item.name.item == *UPDATABLE_DIRECTIVE_NAME);
     match (field_parent_object.encountered_fields).get(&linked_field_name) {
         Some(defined_field_type) => match defined_field_type {
             FieldType::ServerField(server_field_id) => {
                 let server_field =
                     &top_level_client_field_info.server_fields[server_field_id.as_usize()];
+                validate_server_field_is_not_deprecated(
+                    server_field,
+                    field_parent_object,
+                    linked_field_selection.name.location,
+                    top_level_client_field_info,
+                )?;
                 match &server_field.associated_data {
                     SelectionType::Scalar(scalar_id) => Err(WithLocation::new(
                         ValidateSchemaError::ClientFieldSelectionFieldIsScalar {
@@ -512,7 +690,30 @@ fn validate_field_type_exists_and_is_linked(
                             linked_field_selection.name.location,
                             used_variables,
                             variable_definitions,
+                            top_level_client_field_info,
+                        )?;
+                        let conditions = validate_selection_conditions(
+                            &linked_field_selection.directives,
+                            linked_field_selection.name.location,
+                            used_variables,
+                            variable_definitions,
+                            top_level_client_field_info,
                         )?;
+                        let required = get_required_action(
+                            &linked_field_selection.directives,
+                            server_field.is_semantic_non_null,
+                        );
+                        let is_deferred = is_deferred(&linked_field_selection.directives);
+                        let stream = get_stream_parameters(&linked_field_selection.directives);
+                        if stream.is_some() && !object_id.type_name.is_plural() {
+                            return Err(WithLocation::new(
+                                ValidateSchemaError::StreamedFieldMustBeAList {
+                                    field_parent_type_name: field_parent_object.name,
+                                    field_name: linked_field_name,
+                                },
+                                linked_field_selection.name.location,
+                            ));
+                        }
 
                         Ok(LinkedFieldSelection {
                             name: linked_field_selection.name,
@@ -524,23 +725,47 @@ fn validate_field_type_exists_and_is_linked(
                                         linked_field_target_object,
                                         used_variables,
                                         variable_definitions,
+                                        is_updatable,
                                         top_level_client_field_info
                                     )
                                 },
                             ).collect::<Result<Vec<_>, _>>()?,
                             associated_data: ValidatedLinkedFieldAssociatedData {
                                 concrete_type: linked_field_target_object.concrete_type,
+                                conditions,
+                                required,
+                                is_deferred,
+                                stream,
                                 parent_object_id: object_id.type_name.inner_non_null(),
                                 field_id: FieldType::ServerField(server_field.id),
                                 selection_variant: match linked_field_selection.associated_data {
                                     IsographSelectionVariant::Regular => {
-                                        assert_no_missing_arguments(missing_arguments, linked_field_selection.name.location)?;
+                                        assert_no_missing_arguments(missing_arguments, linked_field_selection.name.location, top_level_client_field_info.schema_data)?;
                                         ValidatedIsographSelectionVariant::Regular
                                     },
                                     IsographSelectionVariant::Loadable(l) => {
                                         server_field_cannot_be_selected_loadably(linked_field_name, linked_field_selection.name.location)?;
                                         ValidatedIsographSelectionVariant::Loadable((l, missing_arguments))
                                     },
+                                    IsographSelectionVariant::Paginated(_) => {
+                                        if is_deferred || stream.is_some() {
+                                            return Err(WithLocation::new(
+                                                ValidateSchemaError::PaginatedFieldCannotBeDeferredOrStreamed {
+                                                    field_name: linked_field_name,
+                                                },
+                                                linked_field_selection.name.location,
+                                            ));
+                                        }
+                                        assert_no_missing_arguments(missing_arguments, linked_field_selection.name.location, top_level_client_field_info.schema_data)?;
+                                        ValidatedIsographSelectionVariant::Paginated(validate_paginated_field(
+                                            server_field,
+                                            field_parent_object,
+                                            linked_field_target_object,
+                                            &linked_field_selection.arguments,
+                                            linked_field_selection.name.location,
+                                            top_level_client_field_info,
+                                        )?)
+                                    },
                                 },
                             },
                             arguments: linked_field_selection.arguments,
@@ -579,6 +804,33 @@ fn validate_field_type_exists_and_is_linked(
     }
 }
 
+fn validate_server_field_is_not_deprecated(
+    server_field: &ValidatedSchemaServerField,
+    field_parent_object: &SchemaObject,
+    location: Location,
+    top_level_client_field_info: &ValidateSchemaSharedInfo<'_>,
+) -> ValidateSchemaResult<()> {
+    if let Some(deprecation_reason) = server_field.deprecation_reason {
+        top_level_client_field_info
+            .options
+            .on_deprecated_field_selection
+            .on_failure_collecting_warnings(
+                &mut top_level_client_field_info.warnings.borrow_mut(),
+                || {
+                    WithLocation::new(
+                        ValidateSchemaError::DeprecatedFieldSelected {
+                            field_parent_type_name: field_parent_object.name,
+                            field_name: server_field.name.item,
+                            deprecation_reason,
+                        },
+                        location,
+                    )
+                },
+            )?;
+    }
+    Ok(())
+}
+
 fn server_field_cannot_be_selected_loadably(
     server_field_name: SelectableFieldName,
     location: Location,
@@ -589,13 +841,296 @@ fn server_field_cannot_be_selected_loadably(
     ))
 }
 
+/// A client field can only be selected loadably if it can be refetched, i.e. if
+/// its parent type has an id field or is the query root type.
+fn client_field_can_be_selected_loadably(
+    client_field_id: &ClientFieldId,
+    client_field_parent_object: &SchemaObject,
+    client_field_name: SelectableFieldName,
+    location: Location,
+    top_level_client_field_info: &ValidateSchemaSharedInfo<'_>,
+) -> ValidateSchemaResult<()> {
+    if top_level_client_field_info
+        .refetchable_client_fields
+        .contains(client_field_id)
+    {
+        Ok(())
+    } else {
+        Err(WithLocation::new(
+            ValidateSchemaError::ClientFieldCannotBeSelectedLoadably {
+                field_parent_type_name: client_field_parent_object.name,
+                field_name: client_field_name,
+            },
+            location,
+        ))
+    }
+}
+
+/// A field can be selected with @paginated if it follows the connection spec, i.e. it
+/// accepts first and after (or last and before), and returns an object with edges
+/// (each with a cursor and a node) and pageInfo. Since pages are fetched by refetching
+/// the parent, the parent must be refetchable.
+fn validate_paginated_field(
+    server_field: &ValidatedSchemaServerField,
+    field_parent_object: &SchemaObject,
+    connection_object: &SchemaObject,
+    arguments: &[WithLocation<SelectionFieldArgument>],
+    location: Location,
+    top_level_client_field_info: &ValidateSchemaSharedInfo<'_>,
+) -> ValidateSchemaResult<PaginationDirection> {
+    let field_parent_type_name = field_parent_object.name;
+    let field_name = server_field.name.item;
+
+    let direction = PaginationDirection::from_argument_names(
+        arguments
+            .iter()
+            .map(|argument| argument.item.name.item.lookup().intern().into()),
+    );
+    let count_argument = direction.count_argument();
+    if !arguments
+        .iter()
+        .any(|argument| argument.item.name.item.lookup() == count_argument.lookup())
+    {
+        return Err(WithLocation::new(
+            ValidateSchemaError::PaginatedFieldMissingCountArgument {
+                field_parent_type_name,
+                field_name,
+            },
+            location,
+        ));
+    }
+
+    for argument_name in [count_argument, direction.cursor_argument()] {
+        if !server_field
+            .arguments
+            .iter()
+            .any(|argument| argument.item.name.item.lookup() == argument_name.lookup())
+        {
+            return Err(WithLocation::new(
+                ValidateSchemaError::PaginatedFieldMissingArgument {
+                    field_parent_type_name,
+                    field_name,
+                    argument_name,
+                },
+                location,
+            ));
+        }
+    }
+
+    let missing_field = |missing_field: String| {
+        WithLocation::new(
+            ValidateSchemaError::PaginatedFieldIsNotAConnection {
+                field_parent_type_name,
+                field_name,
+                missing_field,
+            },
+            location,
+        )
+    };
+
+    let edge_object = get_server_field_object(
+        connection_object,
+        *EDGES_FIELD_NAME,
+        top_level_client_field_info,
+    )
+    .ok_or_else(|| missing_field(EDGES_FIELD_NAME.to_string()))?;
+    for edge_field_name in [*CURSOR_FIELD_NAME, *NODE_FIELD_NAME_IN_EDGE] {
+        if !has_server_field(edge_object, edge_field_name) {
+            return Err(missing_field(format!(
+                "{}.{}",
+                *EDGES_FIELD_NAME, edge_field_name
+            )));
+        }
+    }
+
+    let page_info_object = get_server_field_object(
+        connection_object,
+        *PAGE_INFO_FIELD_NAME,
+        top_level_client_field_info,
+    )
+    .ok_or_else(|| missing_field(PAGE_INFO_FIELD_NAME.to_string()))?;
+    for page_info_field_name in [direction.has_more_field(), direction.page_cursor_field()] {
+        if !has_server_field(page_info_object, page_info_field_name) {
+            return Err(missing_field(format!(
+                "{}.{}",
+                *PAGE_INFO_FIELD_NAME, page_info_field_name
+            )));
+        }
+    }
+
+    let parent_can_be_refetched = top_level_client_field_info
+        .fetchable_types
+        .contains_key(&field_parent_object.id)
+        || field_parent_object.refetch_field.is_some();
+    if !parent_can_be_refetched {
+        return Err(WithLocation::new(
+            ValidateSchemaError::PaginatedFieldCannotBeRefetched {
+                field_parent_type_name,
+                field_name,
+            },
+            location,
+        ));
+    }
+
+    Ok(direction)
+}
+
+fn has_server_field(object: &SchemaObject, field_name: SelectableFieldName) -> bool {
+    matches!(
+        object.encountered_fields.get(&field_name),
+        Some(FieldType::ServerField(_))
+    )
+}
+
+fn get_server_field_object<'a>(
+    object: &SchemaObject,
+    field_name: SelectableFieldName,
+    top_level_client_field_info: &ValidateSchemaSharedInfo<'a>,
+) -> Option<&'a SchemaObject> {
+    match object.encountered_fields.get(&field_name) {
+        Some(FieldType::ServerField(server_field_id)) => {
+            match &top_level_client_field_info.server_fields[server_field_id.as_usize()]
+                .associated_data
+            {
+                SelectionType::Object(associated_data) => Some(
+                    top_level_client_field_info
+                        .schema_data
+                        .object(associated_data.type_name.inner_non_null()),
+                ),
+                SelectionType::Scalar(_) => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Validates the @skip and @include directives of a selection. As in GraphQL, their `if`
+/// argument must be passed a `Boolean!`.
+fn validate_selection_conditions(
+    directives: &[WithSpan<IsographFieldDirective>],
+    location: Location,
+    used_variables: &mut UsedVariables,
+    variable_definitions: &[WithSpan<UnvalidatedVariableDefinition>],
+    top_level_client_field_info: &ValidateSchemaSharedInfo<'_>,
+) -> ValidateSchemaResult<Vec<SelectionCondition>> {
+    let mut conditions = vec![];
+    for directive in directives {
+        let kind = match directive.item.name.item.lookup() {
+            "skip" => SelectionConditionKind::Skip,
+            "include" => SelectionConditionKind::Include,
+            _ => continue,
+        };
+
+        let boolean_type_id = *top_level_client_field_info
+            .schema_data
+            .defined_types
+            .get(&"Boolean".intern().into())
+            .expect("Expected Boolean to be defined. This is indicative of a bug in Isograph.");
+        let if_argument_definition = VariableDefinition {
+            name: WithLocation::new(*IF, Location::generated()),
+            type_: GraphQLTypeAnnotation::NonNull(Box::new(GraphQLNonNullTypeAnnotation::Named(
+                GraphQLNamedTypeAnnotation(WithSpan::new(boolean_type_id, Span::todo_generated())),
+            ))),
+            default_value: None,
+        };
+
+        let missing_arguments = get_missing_arguments_and_validate_argument_types(
+            std::iter::once(&if_argument_definition),
+            &directive.item.arguments,
+            false,
+            location,
+            used_variables,
+            variable_definitions,
+            top_level_client_field_info,
+        )?;
+        assert_no_missing_arguments(
+            missing_arguments,
+            location,
+            top_level_client_field_info.schema_data,
+        )?;
+
+        let if_argument = directive
+            .item
+            .arguments
+            .iter()
+            .find(|argument| argument.item.name.item.lookup() == IF.lookup())
+            .expect("Expected if argument to exist. This is indicative of a bug in Isograph.");
+        if !matches!(
+            if_argument.item.value.item,
+            NonConstantValue::Variable(_) | NonConstantValue::Boolean(_)
+        ) {
+            return Err(WithLocation::new(
+                ValidateSchemaError::SelectionConditionIsNotBoolean {
+                    directive_name: kind.directive_name(),
+                },
+                if_argument.item.value.location,
+            ));
+        }
+        conditions.push(SelectionCondition {
+            kind,
+            value: if_argument.item.value.item.clone(),
+        });
+    }
+    Ok(conditions)
+}
+
+/// What the runtime should do if the selected field is null. An explicit @required
+/// takes precedence over the field being semantically non-null.
+fn get_required_action(
+    directives: &[WithSpan<IsographFieldDirective>],
+    is_semantic_non_null: bool,
+) -> Option<RequiredAction> {
+    directives
+        .iter()
+        .find(|directive| directive.item.name.item == *REQUIRED_DIRECTIVE_NAME)
+        .map(|directive| {
+            from_isograph_field_directive::<RequiredDirectiveParameters>(&directive.item)
+                .expect(
+                    "Expected @required to have been validated. \
+                    This is indicative of a bug in Isograph.",
+                )
+                .action
+        })
+        .or(is_semantic_non_null.then_some(RequiredAction::Throw))
+}
+
+fn is_deferred(directives: &[WithSpan<IsographFieldDirective>]) -> bool {
+    directives
+        .iter()
+        .any(|directive| directive.item.name.item == *DEFER_DIRECTIVE_NAME)
+}
+
+fn get_stream_parameters(
+    directives: &[WithSpan<IsographFieldDirective>],
+) -> Option<StreamDirectiveParameters> {
+    directives
+        .iter()
+        .find(|directive| directive.item.name.item == *STREAM_DIRECTIVE_NAME)
+        .map(|directive| {
+            from_isograph_field_directive(&directive.item).expect(
+                "Expected @stream to have been validated. \
+                This is indicative of a bug in Isograph.",
+            )
+        })
+}
+
 fn assert_no_missing_arguments(
     missing_arguments: Vec<ValidatedVariableDefinition>,
     location: Location,
+    schema_data: &ServerFieldData,
 ) -> ValidateSchemaResult<()> {
     if !missing_arguments.is_empty() {
         return Err(WithLocation::new(
-            ValidateSchemaError::MissingArguments { missing_arguments },
+            ValidateSchemaError::MissingArguments {
+                missing_arguments: missing_arguments
+                    .into_iter()
+                    .map(|argument| {
+                        argument.map(&mut |type_id| {
+                            get_name(schema_data.lookup_unvalidated_type(type_id))
+                        })
+                    })
+                    .collect(),
+            },
             location,
         ));
     }
@@ -609,6 +1144,7 @@ fn get_missing_arguments_and_validate_argument_types<'a>(
     location: Location,
     used_variables: &mut UsedVariables,
     variable_definitions: &[WithSpan<UnvalidatedVariableDefinition>],
+    top_level_client_field_info: &ValidateSchemaSharedInfo<'_>,
 ) -> ValidateSchemaResult<Vec<ValidatedVariableDefinition>> {
     let reachable_variables = validate_no_undefined_variables_and_get_reachable_variables(
         arguments,
@@ -618,8 +1154,24 @@ fn get_missing_arguments_and_validate_argument_types<'a>(
 
     let argument_definitions_vec: Vec<_> = argument_definitions.collect();
     validate_no_extraneous_arguments(&argument_definitions_vec, arguments, location)?;
+    validate_variable_usages_are_allowed(
+        &argument_definitions_vec,
+        arguments,
+        variable_definitions,
+        top_level_client_field_info.schema_data,
+    )?;
+    validate_enum_values_are_defined(
+        &argument_definitions_vec,
+        arguments,
+        top_level_client_field_info.schema_data,
+    )?;
+    validate_no_deprecated_enum_values(
+        &argument_definitions_vec,
+        arguments,
+        top_level_client_field_info,
+    )?;
 
-    // TODO validate argument types
+    // TODO validate the types of non-variable arguments
     Ok(get_missing_arguments(
         argument_definitions_vec.into_iter(),
         arguments,
@@ -678,6 +1230,205 @@ fn validate_no_undefined_variables_and_get_reachable_variables(
     Ok(all_reachable_variables)
 }
 
+/// For each argument that is passed a variable, ensure that the variable's type
+/// is allowed in the position of that argument, e.g. that a `String` variable is
+/// not passed to an `ID!` argument.
+fn validate_variable_usages_are_allowed(
+    argument_definitions: &[&ValidatedVariableDefinition],
+    arguments: &[WithLocation<SelectionFieldArgument>],
+    variable_definitions: &[WithSpan<UnvalidatedVariableDefinition>],
+    schema_data: &ServerFieldData,
+) -> ValidateSchemaResult<()> {
+    for argument in arguments {
+        let NonConstantValue::Variable(variable_name) = argument.item.value.item else {
+            continue;
+        };
+        let Some(argument_definition) = argument_definitions.iter().find(|definition| {
+            // TODO do not call .lookup
+            definition.name.item.lookup() == argument.item.name.item.lookup()
+        }) else {
+            continue;
+        };
+        let Some(variable_definition) = variable_definitions
+            .iter()
+            .find(|definition| definition.item.name.item == variable_name)
+        else {
+            continue;
+        };
+
+        // If the inner type of the variable does not exist, we have already reported
+        // an error when validating the variable definitions.
+        let Ok(variable_type) = variable_definition
+            .item
+            .type_
+            .clone()
+            .and_then(|type_name| schema_data.defined_types.get(&type_name).copied().ok_or(()))
+        else {
+            continue;
+        };
+
+        if !variable_usage_is_allowed(
+            &variable_type,
+            variable_definition.item.default_value.is_some(),
+            &argument_definition.type_,
+            argument_definition.default_value.is_some(),
+        ) {
+            return Err(WithLocation::new(
+                ValidateSchemaError::VariableTypeMismatch {
+                    variable_name,
+                    variable_type: variable_definition.item.type_.to_string(),
+                    argument_name: argument.item.name.item,
+                    argument_type: print_validated_type(schema_data, &argument_definition.type_),
+                },
+                argument.item.value.location,
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Identifiers other than true and false are parsed as enum values, so this is
+/// also where a misspelled boolean (e.g. `treu`) is reported.
+fn validate_enum_values_are_defined(
+    argument_definitions: &[&ValidatedVariableDefinition],
+    arguments: &[WithLocation<SelectionFieldArgument>],
+    schema_data: &ServerFieldData,
+) -> ValidateSchemaResult<()> {
+    for argument in arguments {
+        let Some(argument_definition) = argument_definitions.iter().find(|definition| {
+            // TODO do not call .lookup
+            definition.name.item.lookup() == argument.item.name.item.lookup()
+        }) else {
+            continue;
+        };
+        let enum_values: &[EnumLiteralValue] = match argument_definition.type_.inner() {
+            SelectableServerFieldId::Scalar(scalar_id) => {
+                &schema_data.scalar(*scalar_id).enum_values
+            }
+            SelectableServerFieldId::Object(_) => &[],
+        };
+        validate_value_is_defined_enum_value(
+            &argument.item.value,
+            enum_values,
+            argument.item.name.item,
+            argument_definition,
+            schema_data,
+        )?;
+    }
+    Ok(())
+}
+
+fn validate_value_is_defined_enum_value(
+    value: &WithLocation<NonConstantValue>,
+    enum_values: &[EnumLiteralValue],
+    argument_name: FieldArgumentName,
+    argument_definition: &ValidatedVariableDefinition,
+    schema_data: &ServerFieldData,
+) -> ValidateSchemaResult<()> {
+    match &value.item {
+        NonConstantValue::Enum(enum_value) => {
+            if !enum_values.contains(enum_value) {
+                return Err(WithLocation::new(
+                    ValidateSchemaError::InvalidEnumValue {
+                        enum_value: *enum_value,
+                        argument_name,
+                        argument_type: print_validated_type(
+                            schema_data,
+                            &argument_definition.type_,
+                        ),
+                    },
+                    value.location,
+                ));
+            }
+            Ok(())
+        }
+        NonConstantValue::List(values) => {
+            for value in values {
+                validate_value_is_defined_enum_value(
+                    value,
+                    enum_values,
+                    argument_name,
+                    argument_definition,
+                    schema_data,
+                )?;
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Enums are modeled as scalars, so an enum argument's inner type is a scalar,
+/// which knows which of its values are deprecated.
+fn validate_no_deprecated_enum_values(
+    argument_definitions: &[&ValidatedVariableDefinition],
+    arguments: &[WithLocation<SelectionFieldArgument>],
+    top_level_client_field_info: &ValidateSchemaSharedInfo<'_>,
+) -> ValidateSchemaResult<()> {
+    for argument in arguments {
+        let Some(argument_definition) = argument_definitions.iter().find(|definition| {
+            // TODO do not call .lookup
+            definition.name.item.lookup() == argument.item.name.item.lookup()
+        }) else {
+            continue;
+        };
+        let SelectableServerFieldId::Scalar(scalar_id) = argument_definition.type_.inner() else {
+            continue;
+        };
+        let scalar = top_level_client_field_info.schema_data.scalar(*scalar_id);
+        if scalar.deprecated_enum_values.is_empty() {
+            continue;
+        }
+        validate_value_is_not_deprecated_enum_value(
+            &argument.item.value,
+            scalar,
+            top_level_client_field_info,
+        )?;
+    }
+    Ok(())
+}
+
+fn validate_value_is_not_deprecated_enum_value(
+    value: &WithLocation<NonConstantValue>,
+    scalar: &SchemaScalar,
+    top_level_client_field_info: &ValidateSchemaSharedInfo<'_>,
+) -> ValidateSchemaResult<()> {
+    match &value.item {
+        NonConstantValue::Enum(enum_value) => {
+            if let Some(deprecation_reason) = scalar.deprecated_enum_values.get(enum_value) {
+                top_level_client_field_info
+                    .options
+                    .on_deprecated_field_selection
+                    .on_failure_collecting_warnings(
+                        &mut top_level_client_field_info.warnings.borrow_mut(),
+                        || {
+                            WithLocation::new(
+                                ValidateSchemaError::DeprecatedEnumValueUsed {
+                                    enum_name: scalar.name.item,
+                                    enum_value: *enum_value,
+                                    deprecation_reason: *deprecation_reason,
+                                },
+                                value.location,
+                            )
+                        },
+                    )?;
+            }
+            Ok(())
+        }
+        NonConstantValue::List(values) => {
+            for value in values {
+                validate_value_is_not_deprecated_enum_value(
+                    value,
+                    scalar,
+                    top_level_client_field_info,
+                )?;
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
 fn validate_no_extraneous_arguments(
     argument_definitions: &[&ValidatedVariableDefinition],
     arguments: &[WithLocation<SelectionFieldArgument>],
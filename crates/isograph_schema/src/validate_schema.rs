@@ -1,21 +1,26 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use common_lang_types::{
-    IsographObjectTypeName, SelectableFieldName, UnvalidatedTypeName, VariableName, WithLocation,
+    EnumLiteralValue, FieldArgumentName, GraphQLScalarTypeName, IsographObjectTypeName,
+    SelectableFieldName, StringLiteralValue, UnvalidatedTypeName, VariableName, WithLocation,
     WithSpan,
 };
 use intern::Lookup;
+use isograph_config::ConfigOptions;
 use isograph_lang_types::{
-    ClientFieldId, LinkedFieldSelection, LoadableDirectiveParameters, ScalarFieldSelection,
-    SelectableServerFieldId, SelectionFieldArgument, SelectionType, ServerFieldId,
-    ServerFieldSelection, ServerObjectId, ServerScalarId, TypeAnnotation, VariableDefinition,
+    ClientFieldId, LinkedFieldSelection, LoadableDirectiveParameters, RequiredAction,
+    ScalarFieldSelection, SelectableServerFieldId, SelectionCondition, SelectionFieldArgument,
+    SelectionType, ServerFieldId, ServerFieldSelection, ServerObjectId, ServerScalarId,
+    StreamDirectiveParameters, TypeAnnotation, VariableDefinition,
 };
 use thiserror::Error;
 
 use crate::{
     validate_client_field::validate_and_transform_client_fields,
-    validate_server_field::validate_and_transform_server_fields, ClientField, ClientFieldVariant,
-    FieldType, ImperativelyLoadedFieldVariant, Schema, SchemaIdField, SchemaObject,
+    validate_entrypoint_variable_usages,
+    validate_server_field::validate_and_transform_server_fields, ClientField,
+    ClientFieldToCompletedMergeTraversalStateMap, ClientFieldVariant, FieldType,
+    ImperativelyLoadedFieldVariant, PaginationDirection, Schema, SchemaIdField, SchemaObject,
     SchemaServerField, SchemaValidationState, ServerFieldData, ServerFieldTypeAssociatedData,
     UnvalidatedSchema, UnvalidatedVariableDefinition, UseRefetchFieldRefetchStrategy,
     ValidateEntrypointDeclarationError,
@@ -64,12 +69,33 @@ pub struct ValidatedLinkedFieldAssociatedData {
     pub selection_variant: ValidatedIsographSelectionVariant,
     /// Some if the object is concrete; None otherwise.
     pub concrete_type: Option<IsographObjectTypeName>,
+    /// From @skip and @include. Empty if the field is always selected.
+    pub conditions: Vec<SelectionCondition>,
+    /// From @required, or THROW if the field is semantically non-null. None if the
+    /// field may be null.
+    pub required: Option<RequiredAction>,
+    /// From @defer. The field is fetched in a later payload of the same query.
+    pub is_deferred: bool,
+    /// From @stream. The items of the list after the initial count are fetched in
+    /// later payloads of the same query.
+    pub stream: Option<StreamDirectiveParameters>,
 }
 
 #[derive(Debug, Clone)]
 pub struct ValidatedScalarFieldAssociatedData {
     pub location: ValidatedFieldDefinitionLocation,
     pub selection_variant: ValidatedIsographSelectionVariant,
+    /// From @skip and @include. Empty if the field is always selected.
+    pub conditions: Vec<SelectionCondition>,
+    /// From @required, or THROW if the field is semantically non-null. None if the
+    /// field may be null.
+    pub required: Option<RequiredAction>,
+    /// True if the field is a server field selected within an @updatable client field
+    /// or linked field, i.e. it can be written to.
+    pub is_updatable: bool,
+    /// From @defer. Only client fields can be deferred, in which case the server fields
+    /// they select are fetched in a later payload of the same query.
+    pub is_deferred: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -82,6 +108,7 @@ pub enum ValidatedIsographSelectionVariant {
             MissingArguments,
         ),
     ),
+    Paginated(PaginationDirection),
 }
 
 pub type MissingArguments = Vec<ValidatedVariableDefinition>;
@@ -104,9 +131,19 @@ impl SchemaValidationState for ValidatedSchemaState {
 pub type ValidatedSchema = Schema<ValidatedSchemaState>;
 
 impl ValidatedSchema {
+    /// Validate the schema. Also returns the merged selection maps of the
+    /// entrypoints (and of every field they select), which are created during
+    /// validation and can be reused when generating artifacts. Validation
+    /// failures that are configured to be warnings are added to `warnings`,
+    /// whether or not validation succeeds.
     pub fn validate_and_construct(
         unvalidated_schema: UnvalidatedSchema,
-    ) -> Result<Self, Vec<WithLocation<ValidateSchemaError>>> {
+        options: ConfigOptions,
+        warnings: &mut Vec<WithLocation<ValidateSchemaError>>,
+    ) -> Result<
+        (Self, ClientFieldToCompletedMergeTraversalStateMap),
+        Vec<WithLocation<ValidateSchemaError>>,
+    > {
         let mut errors = vec![];
 
         let mut updated_entrypoints = HashSet::new();
@@ -159,6 +196,9 @@ impl ValidatedSchema {
             client_fields,
             &schema_data,
             &updated_server_fields,
+            &root_types,
+            options,
+            warnings,
         ) {
             Ok(client_fields) => client_fields,
             Err(new_errors) => {
@@ -179,7 +219,7 @@ impl ValidatedSchema {
                 .map(transform_object_field_ids)
                 .collect();
 
-            Ok(Self {
+            let validated_schema = Self {
                 server_fields: updated_server_fields,
                 client_fields: updated_client_fields,
                 entrypoints: updated_entrypoints,
@@ -195,7 +235,18 @@ impl ValidatedSchema {
                 int_type_id,
                 fetchable_types: root_types,
                 null_type_id,
-            })
+            };
+
+            let mut encountered_client_field_map = BTreeMap::new();
+            let errors = validate_entrypoint_variable_usages(
+                &validated_schema,
+                &mut encountered_client_field_map,
+            );
+            if errors.is_empty() {
+                Ok((validated_schema, encountered_client_field_map))
+            } else {
+                Err(errors)
+            }
         } else {
             Err(errors)
         }
@@ -205,12 +256,15 @@ impl ValidatedSchema {
 fn transform_object_field_ids(unvalidated_object: SchemaObject) -> SchemaObject {
     let SchemaObject {
         name,
+        name_location,
         description,
         id,
         encountered_fields: unvalidated_encountered_fields,
         id_field,
+        refetch_field,
         directives,
         concrete_type,
+        concrete_subtypes,
     } = unvalidated_object;
 
     let validated_encountered_fields = unvalidated_encountered_fields
@@ -229,11 +283,14 @@ fn transform_object_field_ids(unvalidated_object: SchemaObject) -> SchemaObject
     SchemaObject {
         description,
         name,
+        name_location,
         id,
         encountered_fields: validated_encountered_fields,
         id_field,
+        refetch_field,
         directives,
         concrete_type,
+        concrete_subtypes,
     }
 }
 
@@ -332,7 +389,8 @@ pub fn categorize_field_loadability<'a>(
 ) -> Option<Loadability<'a>> {
     match &client_field.variant {
         ClientFieldVariant::UserWritten(_) => match selection_variant {
-            ValidatedIsographSelectionVariant::Regular => None,
+            ValidatedIsographSelectionVariant::Regular
+            | ValidatedIsographSelectionVariant::Paginated(_) => None,
             ValidatedIsographSelectionVariant::Loadable((l, _)) => {
                 Some(Loadability::LoadablySelectedField(l))
             }
@@ -375,6 +433,16 @@ pub enum ValidateSchemaError {
         field_type: UnvalidatedTypeName,
     },
 
+    #[error(
+        "The field `{parent_type_name}.{field_name}` has type `{field_type}`, which is a client-only type. \
+        Fields with a client-only type must also be client-only, i.e. have a @client directive."
+    )]
+    FieldOfClientOnlyTypeMustBeClientOnly {
+        parent_type_name: IsographObjectTypeName,
+        field_name: SelectableFieldName,
+        field_type: UnvalidatedTypeName,
+    },
+
     #[error(
         "The argument `{argument_name}` on field `{parent_type_name}.{field_name}` has inner type `{argument_type}`, which does not exist."
     )]
@@ -437,16 +505,115 @@ pub enum ValidateSchemaError {
         field_name: SelectableFieldName,
     },
 
+    #[error(
+        "`{client_field_name}` is a client field, and cannot be selected within an \
+        `@updatable` selection set, since only server fields can be updated."
+    )]
+    ClientFieldCannotBeSelectedInUpdatableSelectionSet {
+        client_field_name: SelectableFieldName,
+    },
+
+    #[error(
+        "`{server_field_name}` is a scalar server field, and cannot be selected with `@defer`. \
+        Only linked fields and client fields can be deferred."
+    )]
+    ServerScalarFieldCannotBeDeferred {
+        server_field_name: SelectableFieldName,
+    },
+
+    #[error(
+        "`{client_field_name}` is selected with `@loadable`, so it is fetched in a \
+        separate query, and cannot also be selected with `@defer`"
+    )]
+    LoadableClientFieldCannotBeDeferred {
+        client_field_name: SelectableFieldName,
+    },
+
+    #[error(
+        "`{field_name}` is selected with `@paginated`, and cannot also be selected with \
+        `@defer` or `@stream`"
+    )]
+    PaginatedFieldCannotBeDeferredOrStreamed { field_name: SelectableFieldName },
+
+    #[error(
+        "`{field_parent_type_name}.{field_name}` is not a list, and cannot be selected \
+        with `@stream`"
+    )]
+    StreamedFieldMustBeAList {
+        field_parent_type_name: IsographObjectTypeName,
+        field_name: SelectableFieldName,
+    },
+
+    #[error("`{client_field_name}` is a client field, and cannot be selected with `@required`")]
+    ClientFieldCannotBeSelectedWithRequired {
+        client_field_name: SelectableFieldName,
+    },
+
     #[error("`{server_field_name}` is a server field, and cannot be selected with `@loadable`")]
     ServerFieldCannotBeSelectedLoadably {
         server_field_name: SelectableFieldName,
     },
 
+    #[error(
+        "`{field_parent_type_name}.{field_name}` cannot be selected with `@loadable`, \
+        because `{field_parent_type_name}` cannot be refetched. Only client fields on \
+        types with a strong id field or on the query root type can be loaded."
+    )]
+    ClientFieldCannotBeSelectedLoadably {
+        field_parent_type_name: IsographObjectTypeName,
+        field_name: SelectableFieldName,
+    },
+
+    #[error(
+        "`{field_parent_type_name}.{field_name}` cannot be selected with `@paginated`, \
+        because it does not follow the connection spec. `{field_parent_type_name}.{field_name}` \
+        must have the field `{missing_field}`."
+    )]
+    PaginatedFieldIsNotAConnection {
+        field_parent_type_name: IsographObjectTypeName,
+        field_name: SelectableFieldName,
+        missing_field: String,
+    },
+
+    #[error(
+        "`{field_parent_type_name}.{field_name}` cannot be selected with `@paginated`, \
+        because it does not accept the `{argument_name}` argument."
+    )]
+    PaginatedFieldMissingArgument {
+        field_parent_type_name: IsographObjectTypeName,
+        field_name: SelectableFieldName,
+        argument_name: FieldArgumentName,
+    },
+
+    #[error(
+        "`{field_parent_type_name}.{field_name}` is selected with `@paginated`, \
+        so either `first` or `last` must be passed to it."
+    )]
+    PaginatedFieldMissingCountArgument {
+        field_parent_type_name: IsographObjectTypeName,
+        field_name: SelectableFieldName,
+    },
+
+    #[error(
+        "`{field_parent_type_name}.{field_name}` cannot be selected with `@paginated`, \
+        because `{field_parent_type_name}` cannot be refetched. Only fields on types with \
+        a strong id field or on root types (e.g. `Query`) can be paginated."
+    )]
+    PaginatedFieldCannotBeRefetched {
+        field_parent_type_name: IsographObjectTypeName,
+        field_name: SelectableFieldName,
+    },
+
+    #[error("The `if` argument of `@{directive_name}` must be passed a Boolean or a variable.")]
+    SelectionConditionIsNotBoolean { directive_name: &'static str },
+
     #[error(
         "This field has missing arguments: {0}",
         missing_arguments.iter().map(|arg| format!("${}", arg.name.item)).collect::<Vec<_>>().join(", ")
     )]
-    MissingArguments { missing_arguments: MissingArguments },
+    MissingArguments {
+        missing_arguments: Vec<UnvalidatedVariableDefinition>,
+    },
 
     #[error(
         "The variable `{variable_name}` has type `{type_}`, but the inner type \
@@ -483,4 +650,59 @@ pub enum ValidateSchemaError {
 
     #[error("This variable is not defined: ${undefined_variable}")]
     UsedUndefinedVariable { undefined_variable: VariableName },
+
+    #[error(
+        "The variable `${variable_name}` has type `{variable_type}`, but it is passed \
+        to the argument `{argument_name}`, which has type `{argument_type}`."
+    )]
+    VariableTypeMismatch {
+        variable_name: VariableName,
+        variable_type: String,
+        argument_name: FieldArgumentName,
+        argument_type: String,
+    },
+
+    #[error(
+        "In the entrypoint `{entrypoint_parent_type_name}.{entrypoint_name}`, the variable \
+        `${variable_name}` has type `{variable_type}`, but it is passed to the argument \
+        `{argument_name}`, which has type `{argument_type}`."
+    )]
+    EntrypointVariableTypeMismatch {
+        entrypoint_parent_type_name: IsographObjectTypeName,
+        entrypoint_name: SelectableFieldName,
+        variable_name: VariableName,
+        variable_type: String,
+        argument_name: FieldArgumentName,
+        argument_type: String,
+    },
+
+    #[error(
+        "`{enum_value}` is passed to the argument `{argument_name}`, which has type \
+        `{argument_type}`, but it is not a value of that type."
+    )]
+    InvalidEnumValue {
+        enum_value: EnumLiteralValue,
+        argument_name: FieldArgumentName,
+        argument_type: String,
+    },
+
+    #[error(
+        "The field `{field_parent_type_name}.{field_name}` is deprecated. Reason: {deprecation_reason}\n\
+        This error can be suppressed using the \"on_deprecated_field_selection\" config parameter."
+    )]
+    DeprecatedFieldSelected {
+        field_parent_type_name: IsographObjectTypeName,
+        field_name: SelectableFieldName,
+        deprecation_reason: StringLiteralValue,
+    },
+
+    #[error(
+        "The enum value `{enum_name}.{enum_value}` is deprecated. Reason: {deprecation_reason}\n\
+        This error can be suppressed using the \"on_deprecated_field_selection\" config parameter."
+    )]
+    DeprecatedEnumValueUsed {
+        enum_name: GraphQLScalarTypeName,
+        enum_value: EnumLiteralValue,
+        deprecation_reason: StringLiteralValue,
+    },
 }
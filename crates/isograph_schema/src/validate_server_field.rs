@@ -5,8 +5,8 @@ use isograph_lang_types::{
 };
 
 use crate::{
-    get_all_errors_or_all_ok, get_all_errors_or_all_ok_iter, SchemaServerField,
-    SchemaServerFieldVariant, SchemaValidationState, ServerFieldData,
+    get_all_errors_or_all_ok, get_all_errors_or_all_ok_iter, has_client_directive,
+    SchemaServerField, SchemaServerFieldVariant, SchemaValidationState, ServerFieldData,
     ServerFieldTypeAssociatedData, ServerFieldTypeAssociatedDataInlineFragment,
     UnvalidatedSchemaSchemaField, UnvalidatedSchemaState, UnvalidatedVariableDefinition,
     ValidateSchemaError, ValidateSchemaResult, ValidatedSchemaServerField,
@@ -79,6 +79,7 @@ fn validate_and_transform_server_field(
         if let Some(valid_arguments) = valid_arguments {
             return Ok(SchemaServerField {
                 description: empty_field.description,
+                deprecation_reason: empty_field.deprecation_reason,
                 name: empty_field.name,
                 id: empty_field.id,
                 associated_data: match field_type {
@@ -93,6 +94,8 @@ fn validate_and_transform_server_field(
                 parent_type_id: empty_field.parent_type_id,
                 arguments: valid_arguments,
                 is_discriminator: empty_field.is_discriminator,
+                is_client_only: empty_field.is_client_only,
+                is_semantic_non_null: empty_field.is_semantic_non_null,
             });
         }
     }
@@ -120,6 +123,18 @@ fn validate_server_field_type_exists(
                 ))
             }
             SelectionType::Object(object_id) => {
+                if !field.is_client_only
+                    && has_client_directive(&schema_data.object(*object_id).directives)
+                {
+                    return Err(WithLocation::new(
+                        ValidateSchemaError::FieldOfClientOnlyTypeMustBeClientOnly {
+                            parent_type_name: schema_data.object(field.parent_type_id).name,
+                            field_name: field.name.item,
+                            field_type: *server_field_type.inner(),
+                        },
+                        field.name.location,
+                    ));
+                }
                 SelectionType::Object(TypeAnnotation::from_graphql_type_annotation(
                     server_field_type.clone().map(|_| *object_id),
                 ))
@@ -0,0 +1,417 @@
+use common_lang_types::WithLocation;
+use graphql_lang_types::{GraphQLNonNullTypeAnnotation, GraphQLTypeAnnotation};
+use intern::Lookup;
+use isograph_lang_types::{
+    ArgumentKeyAndValue, NonConstantValue, SelectableServerFieldId, SelectionType, ServerObjectId,
+};
+
+use crate::{
+    create_merged_selection_map_for_field_and_insert_into_global_map, get_name,
+    ClientFieldToCompletedMergeTraversalStateMap, FieldType, MergedSelectionMap,
+    MergedServerSelection, ServerFieldData, ValidateSchemaError, ValidatedClientField,
+    ValidatedSchema, ValidatedVariableDefinition,
+};
+
+/// GraphQL's "All Variable Usages Are Allowed" rule. A variable can be passed to
+/// a location (i.e. an argument) if its type is at least as strict as the type of
+/// the location. A nullable variable may be passed to a non-null location only if
+/// either the variable or the location has a default value.
+pub(crate) fn variable_usage_is_allowed<T: PartialEq>(
+    variable_type: &GraphQLTypeAnnotation<T>,
+    variable_has_default_value: bool,
+    location_type: &GraphQLTypeAnnotation<T>,
+    location_has_default_value: bool,
+) -> bool {
+    if let GraphQLTypeAnnotation::NonNull(non_null_location_type) = location_type {
+        if variable_type.is_nullable() {
+            if !variable_has_default_value && !location_has_default_value {
+                return false;
+            }
+            return types_are_compatible(
+                TypeRef::Nullable(variable_type),
+                TypeRef::NonNullInner(non_null_location_type),
+            );
+        }
+    }
+    types_are_compatible(
+        TypeRef::Nullable(variable_type),
+        TypeRef::Nullable(location_type),
+    )
+}
+
+/// A borrowed view of a type annotation, so that we can strip a `!` without
+/// reconstructing the annotation.
+enum TypeRef<'a, T> {
+    Nullable(&'a GraphQLTypeAnnotation<T>),
+    NonNullInner(&'a GraphQLNonNullTypeAnnotation<T>),
+}
+
+// Derive would require T: Copy
+impl<T> Clone for TypeRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for TypeRef<'_, T> {}
+
+fn types_are_compatible<T: PartialEq>(
+    variable_type: TypeRef<'_, T>,
+    location_type: TypeRef<'_, T>,
+) -> bool {
+    match (variable_type, location_type) {
+        (TypeRef::Nullable(GraphQLTypeAnnotation::NonNull(variable)), _) => match location_type {
+            TypeRef::Nullable(GraphQLTypeAnnotation::NonNull(location)) => types_are_compatible(
+                TypeRef::NonNullInner(variable),
+                TypeRef::NonNullInner(location),
+            ),
+            _ => types_are_compatible(TypeRef::NonNullInner(variable), location_type),
+        },
+        (_, TypeRef::Nullable(GraphQLTypeAnnotation::NonNull(_))) => false,
+        (TypeRef::Nullable(GraphQLTypeAnnotation::Named(variable)), _) => {
+            named_types_are_equal(&variable.0.item, location_type)
+        }
+        (TypeRef::NonNullInner(GraphQLNonNullTypeAnnotation::Named(variable)), _) => {
+            named_types_are_equal(&variable.0.item, location_type)
+        }
+        (TypeRef::Nullable(GraphQLTypeAnnotation::List(variable)), _) => {
+            list_types_are_compatible(&variable.0, location_type)
+        }
+        (TypeRef::NonNullInner(GraphQLNonNullTypeAnnotation::List(variable)), _) => {
+            list_types_are_compatible(&variable.0, location_type)
+        }
+    }
+}
+
+fn named_types_are_equal<T: PartialEq>(variable_type: &T, location_type: TypeRef<'_, T>) -> bool {
+    match location_type {
+        TypeRef::Nullable(GraphQLTypeAnnotation::Named(location)) => {
+            *variable_type == location.0.item
+        }
+        TypeRef::NonNullInner(GraphQLNonNullTypeAnnotation::Named(location)) => {
+            *variable_type == location.0.item
+        }
+        _ => false,
+    }
+}
+
+fn list_types_are_compatible<T: PartialEq>(
+    variable_item_type: &GraphQLTypeAnnotation<T>,
+    location_type: TypeRef<'_, T>,
+) -> bool {
+    match location_type {
+        TypeRef::Nullable(GraphQLTypeAnnotation::List(location)) => types_are_compatible(
+            TypeRef::Nullable(variable_item_type),
+            TypeRef::Nullable(&location.0),
+        ),
+        TypeRef::NonNullInner(GraphQLNonNullTypeAnnotation::List(location)) => {
+            types_are_compatible(
+                TypeRef::Nullable(variable_item_type),
+                TypeRef::Nullable(&location.0),
+            )
+        }
+        _ => false,
+    }
+}
+
+pub(crate) fn print_validated_type(
+    schema_data: &ServerFieldData,
+    type_: &GraphQLTypeAnnotation<SelectableServerFieldId>,
+) -> String {
+    type_
+        .clone()
+        .map(|type_id| get_name(schema_data.lookup_unvalidated_type(type_id)))
+        .to_string()
+}
+
+/// Client fields are validated one at a time, and each variable usage is checked
+/// against the variables of the client field in which it occurs. However, when
+/// an entrypoint's query is generated, variables are substituted across client
+/// field boundaries (see VariableContext), and default values of intermediate
+/// client fields are lost. So, we also check every variable usage in the final
+/// query against the entrypoint's variable definitions.
+///
+/// The merged selection maps are inserted into `encountered_client_field_map`,
+/// so that they can be reused when generating artifacts.
+pub(crate) fn validate_entrypoint_variable_usages(
+    schema: &ValidatedSchema,
+    encountered_client_field_map: &mut ClientFieldToCompletedMergeTraversalStateMap,
+) -> Vec<WithLocation<ValidateSchemaError>> {
+    let mut errors = vec![];
+
+    let mut entrypoints = schema.entrypoints.iter().collect::<Vec<_>>();
+    entrypoints.sort();
+
+    for entrypoint_id in entrypoints {
+        let entrypoint = schema.client_field(*entrypoint_id);
+        let merged_selection_map =
+            create_merged_selection_map_for_field_and_insert_into_global_map(
+                schema,
+                schema.server_field_data.object(entrypoint.parent_object_id),
+                entrypoint.selection_set_for_parent_query(),
+                encountered_client_field_map,
+                FieldType::ClientField(entrypoint.id),
+                &entrypoint.initial_variable_context(),
+            )
+            .merged_selection_map;
+
+        validate_variable_usages_in_selection_map(
+            schema,
+            entrypoint,
+            entrypoint.parent_object_id,
+            &merged_selection_map,
+            &mut errors,
+        );
+    }
+
+    errors
+}
+
+fn validate_variable_usages_in_selection_map(
+    schema: &ValidatedSchema,
+    entrypoint: &ValidatedClientField,
+    parent_object_id: ServerObjectId,
+    selection_map: &MergedSelectionMap,
+    errors: &mut Vec<WithLocation<ValidateSchemaError>>,
+) {
+    let parent_object = schema.server_field_data.object(parent_object_id);
+    for selection in selection_map.values() {
+        match selection {
+            MergedServerSelection::ScalarField(scalar_field) => {
+                if let Some(FieldType::ServerField(server_field_id)) = parent_object
+                    .encountered_fields
+                    .get(&scalar_field.name.into())
+                {
+                    validate_variable_usages_in_arguments(
+                        schema,
+                        entrypoint,
+                        schema.server_field(*server_field_id).arguments.iter(),
+                        &scalar_field.arguments,
+                        errors,
+                    );
+                }
+            }
+            MergedServerSelection::LinkedField(linked_field) => {
+                if let Some(FieldType::ServerField(server_field_id)) = parent_object
+                    .encountered_fields
+                    .get(&linked_field.name.into())
+                {
+                    let server_field = schema.server_field(*server_field_id);
+                    validate_variable_usages_in_arguments(
+                        schema,
+                        entrypoint,
+                        server_field.arguments.iter(),
+                        &linked_field.arguments,
+                        errors,
+                    );
+                    if let SelectionType::Object(associated_data) = &server_field.associated_data {
+                        validate_variable_usages_in_selection_map(
+                            schema,
+                            entrypoint,
+                            associated_data.type_name.inner_non_null(),
+                            &linked_field.selection_map,
+                            errors,
+                        );
+                    }
+                }
+            }
+            MergedServerSelection::InlineFragment(inline_fragment) => {
+                if let Some(SelectableServerFieldId::Object(object_id)) = schema
+                    .server_field_data
+                    .defined_types
+                    .get(&inline_fragment.type_to_refine_to.into())
+                {
+                    validate_variable_usages_in_selection_map(
+                        schema,
+                        entrypoint,
+                        *object_id,
+                        &inline_fragment.selection_map,
+                        errors,
+                    );
+                }
+            }
+            MergedServerSelection::DeferredFragment(deferred_fragment) => {
+                validate_variable_usages_in_selection_map(
+                    schema,
+                    entrypoint,
+                    parent_object_id,
+                    &deferred_fragment.selection_map,
+                    errors,
+                );
+            }
+        }
+    }
+}
+
+fn validate_variable_usages_in_arguments<'a>(
+    schema: &ValidatedSchema,
+    entrypoint: &ValidatedClientField,
+    argument_definitions: impl Iterator<Item = &'a WithLocation<ValidatedVariableDefinition>>,
+    arguments: &[ArgumentKeyAndValue],
+    errors: &mut Vec<WithLocation<ValidateSchemaError>>,
+) {
+    let argument_definitions = argument_definitions.collect::<Vec<_>>();
+    for argument in arguments {
+        let NonConstantValue::Variable(variable_name) = argument.value else {
+            continue;
+        };
+        let Some(argument_definition) = argument_definitions
+            .iter()
+            // TODO do not call .lookup
+            .find(|definition| definition.item.name.item.lookup() == argument.key.lookup())
+        else {
+            continue;
+        };
+        // Variables that are not defined on the entrypoint are provided at runtime
+        // (e.g. the id of a refetched object), and are not part of this query.
+        let Some(variable_definition) = entrypoint
+            .variable_definitions
+            .iter()
+            .find(|definition| definition.item.name.item == variable_name)
+        else {
+            continue;
+        };
+
+        if !variable_usage_is_allowed(
+            &variable_definition.item.type_,
+            variable_definition.item.default_value.is_some(),
+            &argument_definition.item.type_,
+            argument_definition.item.default_value.is_some(),
+        ) {
+            errors.push(WithLocation::new(
+                ValidateSchemaError::EntrypointVariableTypeMismatch {
+                    entrypoint_parent_type_name: entrypoint.type_and_field.type_name,
+                    entrypoint_name: entrypoint.type_and_field.field_name,
+                    variable_name,
+                    variable_type: print_validated_type(
+                        &schema.server_field_data,
+                        &variable_definition.item.type_,
+                    ),
+                    argument_name: argument.key,
+                    argument_type: print_validated_type(
+                        &schema.server_field_data,
+                        &argument_definition.item.type_,
+                    ),
+                },
+                variable_definition.item.name.location,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common_lang_types::{Span, WithSpan};
+    use graphql_lang_types::{
+        GraphQLListTypeAnnotation, GraphQLNamedTypeAnnotation, GraphQLNonNullTypeAnnotation,
+        GraphQLTypeAnnotation,
+    };
+
+    use super::variable_usage_is_allowed;
+
+    fn named(name: &'static str) -> GraphQLTypeAnnotation<&'static str> {
+        GraphQLTypeAnnotation::Named(GraphQLNamedTypeAnnotation(WithSpan::new(
+            name,
+            Span::todo_generated(),
+        )))
+    }
+
+    fn list(inner: GraphQLTypeAnnotation<&'static str>) -> GraphQLTypeAnnotation<&'static str> {
+        GraphQLTypeAnnotation::List(Box::new(GraphQLListTypeAnnotation(inner)))
+    }
+
+    fn non_null(inner: GraphQLTypeAnnotation<&'static str>) -> GraphQLTypeAnnotation<&'static str> {
+        GraphQLTypeAnnotation::NonNull(Box::new(match inner {
+            GraphQLTypeAnnotation::Named(named) => GraphQLNonNullTypeAnnotation::Named(named),
+            GraphQLTypeAnnotation::List(list) => GraphQLNonNullTypeAnnotation::List(*list),
+            GraphQLTypeAnnotation::NonNull(_) => panic!("Cannot double wrap in non-null"),
+        }))
+    }
+
+    #[test]
+    fn named_types_must_match() {
+        assert!(variable_usage_is_allowed(
+            &named("ID"),
+            false,
+            &named("ID"),
+            false
+        ));
+        assert!(!variable_usage_is_allowed(
+            &named("String"),
+            false,
+            &named("ID"),
+            false
+        ));
+        assert!(!variable_usage_is_allowed(
+            &non_null(named("String")),
+            false,
+            &non_null(named("ID")),
+            false
+        ));
+    }
+
+    #[test]
+    fn non_null_variables_can_be_passed_to_nullable_arguments() {
+        assert!(variable_usage_is_allowed(
+            &non_null(named("ID")),
+            false,
+            &named("ID"),
+            false
+        ));
+        assert!(variable_usage_is_allowed(
+            &list(non_null(named("ID"))),
+            false,
+            &list(named("ID")),
+            false
+        ));
+    }
+
+    #[test]
+    fn nullable_variables_require_a_default_value_for_non_null_arguments() {
+        assert!(!variable_usage_is_allowed(
+            &named("Int"),
+            false,
+            &non_null(named("Int")),
+            false
+        ));
+        assert!(variable_usage_is_allowed(
+            &named("Int"),
+            true,
+            &non_null(named("Int")),
+            false
+        ));
+        assert!(variable_usage_is_allowed(
+            &named("Int"),
+            false,
+            &non_null(named("Int")),
+            true
+        ));
+        assert!(!variable_usage_is_allowed(
+            &list(named("Int")),
+            true,
+            &list(non_null(named("Int"))),
+            false
+        ));
+    }
+
+    #[test]
+    fn lists_must_match() {
+        assert!(!variable_usage_is_allowed(
+            &list(named("ID")),
+            false,
+            &named("ID"),
+            false
+        ));
+        assert!(!variable_usage_is_allowed(
+            &named("ID"),
+            false,
+            &list(named("ID")),
+            false
+        ));
+        assert!(variable_usage_is_allowed(
+            &non_null(list(non_null(named("ID")))),
+            false,
+            &list(named("ID")),
+            false
+        ));
+    }
+}
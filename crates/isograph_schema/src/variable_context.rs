@@ -3,7 +3,8 @@ use std::{collections::HashMap, fmt::Debug};
 
 use common_lang_types::{SelectableFieldName, VariableName, WithLocation, WithSpan};
 use isograph_lang_types::{
-    ArgumentKeyAndValue, ConstantValue, NonConstantValue, SelectionFieldArgument,
+    ArgumentKeyAndValue, ConstantValue, NonConstantValue, SelectionCondition,
+    SelectionFieldArgument,
 };
 
 use crate::{
@@ -153,29 +154,29 @@ fn transform_selection_field_argument_into_merged_arg_with_child_context(
     arg: ArgumentKeyAndValue,
     variable_context: &VariableContext,
 ) -> ArgumentKeyAndValue {
-    if let NonConstantValue::Variable(used_variable_name) = arg.value {
+    ArgumentKeyAndValue {
+        key: arg.key,
+        value: transform_value_with_child_context(arg.value, variable_context),
+    }
+}
+
+fn transform_value_with_child_context(
+    value: NonConstantValue,
+    variable_context: &VariableContext,
+) -> NonConstantValue {
+    if let NonConstantValue::Variable(used_variable_name) = value {
         // Look up the variable in the variables in context, and use that value
         //
         // This will give us the *actual value* that we need for the merged selection set.
-        let value = variable_context.0.get(&used_variable_name);
-
-        return match value {
-            Some(value) => ArgumentKeyAndValue {
-                key: arg.key,
-                value: value.clone(),
-            },
-            None => {
-                // There is no variable. The value is missing! It had better be optional.
-                // TODO we should validate that
-                ArgumentKeyAndValue {
-                    key: arg.key,
-                    value: NonConstantValue::Null,
-                }
-            }
+        return match variable_context.0.get(&used_variable_name) {
+            Some(value) => value.clone(),
+            // There is no variable. The value is missing! It had better be optional.
+            // TODO we should validate that
+            None => NonConstantValue::Null,
         };
     }
 
-    arg
+    value
 }
 
 pub fn transform_arguments_with_child_context(
@@ -192,6 +193,21 @@ pub fn transform_arguments_with_child_context(
         .collect::<Vec<_>>()
 }
 
+pub fn transform_conditions_with_child_context(
+    conditions: impl Iterator<Item = SelectionCondition>,
+    transformed_child_variable_context: &VariableContext,
+) -> Vec<SelectionCondition> {
+    conditions
+        .map(|condition| SelectionCondition {
+            kind: condition.kind,
+            value: transform_value_with_child_context(
+                condition.value,
+                transformed_child_variable_context,
+            ),
+        })
+        .collect()
+}
+
 pub fn transform_name_and_arguments_with_child_variable_context(
     name_and_arguments: NameAndArguments,
     transformed_child_variable_context: &VariableContext,
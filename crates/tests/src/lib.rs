@@ -1 +1,194 @@
+use std::{collections::BTreeMap, fs, path::PathBuf};
 
+use isograph_compiler::{compile_and_print, CompilerState, OverlaidSourceFiles};
+use isograph_config::create_config;
+
+/// A project written to a temporary directory, consisting of an
+/// `isograph.config.json`, a `schema.graphql` and source files in `src`.
+pub struct TestProject {
+    root: PathBuf,
+}
+
+impl TestProject {
+    /// Write a project whose source files are given as (path relative to `src`,
+    /// content) pairs.
+    pub fn new(name: &str, schema: &str, source_files: &[(&str, &str)]) -> Self {
+        Self::with_options(name, "{}", schema, source_files)
+    }
+
+    /// Like `new`, but with the given (JSON) `options` in the config file.
+    pub fn with_options(
+        name: &str,
+        options: &str,
+        schema: &str,
+        source_files: &[(&str, &str)],
+    ) -> Self {
+        Self::write(name, options, schema, None, source_files)
+    }
+
+    /// Like `new`, but with a schema extension, which is written to
+    /// `schema_extension.graphql`.
+    pub fn with_schema_extension(
+        name: &str,
+        schema: &str,
+        schema_extension: &str,
+        source_files: &[(&str, &str)],
+    ) -> Self {
+        Self::write(name, "{}", schema, Some(schema_extension), source_files)
+    }
+
+    fn write(
+        name: &str,
+        options: &str,
+        schema: &str,
+        schema_extension: Option<&str>,
+        source_files: &[(&str, &str)],
+    ) -> Self {
+        let root = std::env::temp_dir().join("isograph-tests").join(format!(
+            "{}-{}",
+            name,
+            std::process::id()
+        ));
+        if root.exists() {
+            fs::remove_dir_all(&root).expect("Unable to remove test project");
+        }
+        fs::create_dir_all(root.join("src")).expect("Unable to create test project");
+
+        fs::write(
+            root.join("isograph.config.json"),
+            format!(
+                "{{ \"project_root\": \"./src\", \"schema\": \"./schema.graphql\", \
+                \"schema_extensions\": [{}], \"options\": {} }}",
+                if schema_extension.is_some() {
+                    "\"./schema_extension.graphql\""
+                } else {
+                    ""
+                },
+                options
+            ),
+        )
+        .expect("Unable to write config");
+        fs::write(root.join("schema.graphql"), schema).expect("Unable to write schema");
+        if let Some(schema_extension) = schema_extension {
+            fs::write(root.join("schema_extension.graphql"), schema_extension)
+                .expect("Unable to write schema extension");
+        }
+        for (path, content) in source_files {
+            let path: PathBuf = root.join("src").join(path);
+            fs::create_dir_all(path.parent().expect("Expected path to have a parent"))
+                .expect("Unable to create source directory");
+            fs::write(path, content).expect("Unable to write source file");
+        }
+
+        Self { root }
+    }
+
+    /// Compile the project, returning the printed errors if it is invalid.
+    pub fn validate(&self) -> Result<(), String> {
+        compile_and_print(self.root.join("isograph.config.json")).map_err(|e| e.to_string())
+    }
+
+    /// The printed errors, panicking if the project is valid.
+    pub fn validation_error(&self) -> String {
+        match self.validate() {
+            Ok(()) => panic!("Expected validation to fail"),
+            Err(error) => error,
+        }
+    }
+
+    /// The validation failures that are configured to be warnings, e.g.
+    /// selections of deprecated fields, as they are reported by the language
+    /// server.
+    pub fn warnings(&self) -> Vec<String> {
+        let config = create_config(self.root.join("isograph.config.json")).expect("Invalid config");
+        let source_files = OverlaidSourceFiles::read_and_parse_all_files(&config);
+        let mut warnings = vec![];
+        let _ = CompilerState::from_config(config)
+            .validate_overlaid_source_files(&source_files, &mut warnings);
+        warnings
+            .into_iter()
+            .map(|warning| warning.item.to_string())
+            .collect()
+    }
+
+    /// The content of each artifact, keyed by its path relative to the artifact
+    /// directory, e.g. `Query/HomeRoute/entrypoint.ts`.
+    pub fn artifacts(&self) -> Result<BTreeMap<String, String>, String> {
+        self.validate()?;
+        let artifact_directory = self.root.join("src").join("__isograph");
+        let mut artifacts = BTreeMap::new();
+        let mut directories = vec![artifact_directory.clone()];
+        while let Some(directory) = directories.pop() {
+            for entry in fs::read_dir(&directory).expect("Unable to read artifact directory") {
+                let path = entry.expect("Unable to read artifact directory").path();
+                if path.is_dir() {
+                    directories.push(path);
+                } else {
+                    let relative_path = path
+                        .strip_prefix(&artifact_directory)
+                        .expect("Expected artifact to be in the artifact directory")
+                        .to_string_lossy()
+                        .replace('\\', "/");
+                    let content = fs::read_to_string(&path).expect("Unable to read artifact");
+                    artifacts.insert(relative_path, content);
+                }
+            }
+        }
+        Ok(artifacts)
+    }
+
+    /// The content of the artifact at `path`, panicking if there is none.
+    pub fn artifact(&self, path: &str) -> String {
+        let mut artifacts = self.artifacts().expect("Expected project to be valid");
+        artifacts.remove(path).unwrap_or_else(|| {
+            panic!(
+                "Expected an artifact at {}. Artifacts: {:?}",
+                path,
+                artifacts.keys().collect::<Vec<_>>()
+            )
+        })
+    }
+}
+
+/// The content of a source file that imports `iso` and contains the given
+/// declarations, e.g. `source_file([client_field("Query.Home { name }"),
+/// entrypoint("Query.Home")])`.
+pub fn source_file(declarations: impl IntoIterator<Item = String>) -> String {
+    declarations
+        .into_iter()
+        .fold("import { iso } from '@iso';\n".to_string(), |output, x| {
+            output + "\n" + &x
+        })
+}
+
+/// A client field declaration, e.g. `Query.Home { name }`, which is exported as a
+/// const named after the client field.
+pub fn client_field(declaration: &str) -> String {
+    let name: String = declaration
+        .split_once('.')
+        .expect("Expected the declaration to start with Type.Name")
+        .1
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect();
+    format!(
+        "export const {name} = iso(`\n  field {declaration}\n`)(function {name}({{ data }}) {{\n  \
+        return data;\n}});\n"
+    )
+}
+
+/// An entrypoint declaration, e.g. `Query.Home`.
+pub fn entrypoint(type_and_field: &str) -> String {
+    format!("iso(`entrypoint {type_and_field}`);\n")
+}
+
+/// The query text of an entrypoint or refetch artifact.
+pub fn query_text(artifact: &str) -> &str {
+    let start = artifact
+        .find("const queryText = '")
+        .expect("Expected artifact to contain query text");
+    let end = artifact[start..]
+        .find("}';")
+        .expect("Expected query text to end");
+    &artifact[start..start + end]
+}
@@ -0,0 +1,153 @@
+use tests::{client_field, entrypoint, source_file, TestProject};
+
+const SCHEMA: &str = "
+type Query {
+  pet: Pet
+}
+
+type Pet {
+  name: String!
+}
+";
+
+fn pet_detail() -> String {
+    source_file([
+        client_field(
+            "Query.PetDetail {
+    pet {
+      name
+      isSelectedLocally
+      tags
+    }
+  }",
+        ),
+        entrypoint("Query.PetDetail"),
+    ])
+}
+
+#[test]
+fn client_only_fields_are_not_fetched() {
+    let project = TestProject::with_schema_extension(
+        "client_only_fields_are_not_fetched",
+        SCHEMA,
+        "extend type Pet {
+  isSelectedLocally: Boolean! @client
+  tags: [String!]! @client
+}",
+        &[("PetDetail.ts", &pet_detail())],
+    );
+
+    let entrypoint = project.artifact("Query/PetDetail/entrypoint.ts");
+    assert!(!entrypoint.contains("isSelectedLocally"), "{}", entrypoint);
+    assert!(!entrypoint.contains("tags"), "{}", entrypoint);
+
+    let reader = project.artifact("Query/PetDetail/resolver_reader.ts");
+    assert!(
+        reader.contains("fieldName: \"isSelectedLocally\""),
+        "{}",
+        reader
+    );
+}
+
+#[test]
+fn client_only_fields_are_nullable_in_param_types() {
+    let project = TestProject::with_schema_extension(
+        "client_only_fields_are_nullable_in_param_types",
+        SCHEMA,
+        "extend type Pet {
+  isSelectedLocally: Boolean! @client
+  tags: [String!]! @client
+}",
+        &[("PetDetail.ts", &pet_detail())],
+    );
+
+    let param_type = project.artifact("Query/PetDetail/param_type.ts");
+    // Client-only fields are null until they are written, even if their type
+    // in the schema is non-null.
+    for expected in [
+        "readonly name: string,",
+        "readonly isSelectedLocally: (boolean | null),",
+        "readonly tags: (ReadonlyArray<string> | null),",
+    ] {
+        assert!(param_type.contains(expected), "{}", param_type);
+    }
+}
+
+#[test]
+fn fields_of_client_only_types_must_be_client_only() {
+    let project = TestProject::with_schema_extension(
+        "fields_of_client_only_types_must_be_client_only",
+        SCHEMA,
+        "type PetState @client {
+  isSelected: Boolean
+}
+
+extend type Pet {
+  state: PetState
+}",
+        &[],
+    );
+
+    let error = project.validation_error();
+    assert!(
+        error.contains("The field `Pet.state` has type `PetState`, which is a client-only type."),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn id_fields_can_be_added_in_extensions() {
+    let project = TestProject::with_schema_extension(
+        "id_fields_can_be_added_in_extensions",
+        SCHEMA,
+        "extend type Pet {
+  id: ID!
+}",
+        &[(
+            "PetName.ts",
+            &source_file([
+                client_field(
+                    "Query.PetName {
+    pet {
+      name
+    }
+  }",
+                ),
+                entrypoint("Query.PetName"),
+            ]),
+        )],
+    );
+
+    // The id field is always fetched, so that the pet can be normalized.
+    let entrypoint = project.artifact("Query/PetName/entrypoint.ts");
+    assert!(
+        entrypoint.contains(
+            "  pet {\\
+    id,\\
+    name,\\
+  },\\"
+        ),
+        "{}",
+        entrypoint
+    );
+}
+
+#[test]
+fn id_fields_added_in_extensions_must_have_type_id() {
+    let project = TestProject::with_schema_extension(
+        "id_fields_added_in_extensions_must_have_type_id",
+        SCHEMA,
+        "extend type Pet {
+  id: String
+}",
+        &[],
+    );
+
+    let error = project.validation_error();
+    assert!(
+        error.contains("The id field on \"Pet\" must have type \"ID!\"."),
+        "{}",
+        error
+    );
+}
@@ -0,0 +1,35 @@
+use isograph_compiler::{batch_compile::BatchCompileError, compile_and_print};
+use tests::TestProject;
+
+const SCHEMA: &str = "
+type Query {
+  name: String!
+}
+";
+
+#[test]
+fn invalid_configs_are_reported_as_errors() {
+    let project = TestProject::with_options(
+        "invalid_configs_are_reported_as_errors",
+        "{ \"on_invalid_id_type\": ",
+        SCHEMA,
+        &[],
+    );
+    let error = project.validation_error();
+    assert!(
+        error.contains("Unable to parse the config."),
+        "Unexpected error: {error}"
+    );
+}
+
+#[test]
+fn missing_configs_are_reported_as_errors() {
+    let config_location = std::env::temp_dir()
+        .join("isograph-tests")
+        .join(format!("missing_config-{}", std::process::id()))
+        .join("isograph.config.json");
+    assert!(matches!(
+        compile_and_print(config_location),
+        Err(BatchCompileError::UnableToLoadConfig(_))
+    ));
+}
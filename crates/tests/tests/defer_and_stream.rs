@@ -0,0 +1,128 @@
+use tests::{client_field, entrypoint, query_text, source_file, TestProject};
+
+const SCHEMA: &str = "
+type Query {
+  viewer: User!
+}
+
+type User {
+  id: ID!
+  name: String!
+  manager: User
+  friends: [User!]!
+}
+";
+
+fn friend_names() -> String {
+    source_file([
+        client_field(
+            "User.FriendNames {
+    friends {
+      name
+    } @stream(initialCount: 2)
+  }",
+        ),
+        client_field(
+            "User.ManagerName {
+    manager {
+      name
+    } @defer
+  }",
+        ),
+    ])
+}
+
+#[test]
+fn labels_are_unique_per_path() {
+    let project = TestProject::new(
+        "labels_are_unique_per_path",
+        SCHEMA,
+        &[
+            ("FriendNames.ts", &friend_names()),
+            (
+                "Home.ts",
+                &source_file([
+                    client_field(
+                        "Query.Home {
+    viewer {
+      FriendNames
+      ManagerName
+      manager {
+        FriendNames
+        ManagerName
+      }
+    }
+  }",
+                    ),
+                    entrypoint("Query.Home"),
+                ]),
+            ),
+        ],
+    );
+    let entrypoint = project.artifact("Query/Home/entrypoint.ts");
+    let query_text = query_text(&entrypoint);
+
+    for expected in [
+        "friends @stream(label: \"viewer__User__friends__stream\", initialCount: 2)",
+        "friends @stream(label: \"viewer__manager__User__friends__stream\", initialCount: 2)",
+        "... @defer(label: \"viewer__User__manager__defer\")",
+        "... @defer(label: \"viewer__manager__User__manager__defer\")",
+    ] {
+        assert!(query_text.contains(expected), "{}", query_text);
+    }
+
+    // The normalization AST uses the same labels as the query text.
+    assert!(
+        entrypoint.contains("stream: { label: \"viewer__manager__User__friends__stream\""),
+        "{}",
+        entrypoint
+    );
+}
+
+#[test]
+fn streamed_selections_are_merged() {
+    let project = TestProject::new(
+        "streamed_selections_are_merged",
+        SCHEMA,
+        &[
+            ("FriendNames.ts", &friend_names()),
+            (
+                "Home.ts",
+                &source_file([
+                    client_field(
+                        "Query.Home {
+    viewer {
+      FriendNames
+      friends {
+        id
+      } @stream(initialCount: 5)
+    }
+  }",
+                    ),
+                    entrypoint("Query.Home"),
+                ]),
+            ),
+        ],
+    );
+    let entrypoint = project.artifact("Query/Home/entrypoint.ts");
+    let query_text = query_text(&entrypoint);
+
+    // Both selections of friends are merged into one, which receives the larger
+    // initial count.
+    assert_eq!(
+        query_text.matches("friends @stream").count(),
+        1,
+        "{}",
+        query_text
+    );
+    assert!(
+        query_text.contains(
+            "    friends @stream(label: \"viewer__User__friends__stream\", initialCount: 5) {\\
+      id,\\
+      name,\\
+    },\\"
+        ),
+        "{}",
+        query_text
+    );
+}
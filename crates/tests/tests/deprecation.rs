@@ -0,0 +1,153 @@
+use tests::{client_field, entrypoint, source_file, TestProject};
+
+const SCHEMA: &str = "
+type Query {
+  pets(status: PetStatus): [Pet!]!
+}
+
+type Pet {
+  name: String!
+  nickname: String @deprecated(reason: \"Use name instead.\")
+  age: Int @deprecated
+}
+
+enum PetStatus {
+  AVAILABLE
+  SOLD @deprecated(reason: \"Use ADOPTED instead.\")
+  ADOPTED
+}
+";
+
+const ON_DEPRECATED_ERROR: &str = "{ \"on_deprecated_field_selection\": \"error\" }";
+
+fn pet_list(selections: &str) -> String {
+    source_file([
+        client_field(&format!("Query.PetList {{\n    {selections}\n  }}")),
+        entrypoint("Query.PetList"),
+    ])
+}
+
+#[test]
+fn deprecated_fields_cannot_be_selected_if_configured() {
+    let project = TestProject::with_options(
+        "deprecated_fields_cannot_be_selected_if_configured",
+        ON_DEPRECATED_ERROR,
+        SCHEMA,
+        &[("PetList.ts", &pet_list("pets { nickname }"))],
+    );
+    let error = project.validation_error();
+    assert!(
+        error.contains("The field `Pet.nickname` is deprecated. Reason: Use name instead."),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn deprecated_fields_without_a_reason_use_the_default_reason() {
+    let project = TestProject::with_options(
+        "deprecated_fields_without_a_reason_use_the_default_reason",
+        ON_DEPRECATED_ERROR,
+        SCHEMA,
+        &[("PetList.ts", &pet_list("pets { age }"))],
+    );
+    let error = project.validation_error();
+    assert!(
+        error.contains("The field `Pet.age` is deprecated. Reason: No longer supported"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn deprecated_fields_can_be_selected_by_default() {
+    let project = TestProject::new(
+        "deprecated_fields_can_be_selected_by_default",
+        SCHEMA,
+        &[("PetList.ts", &pet_list("pets { nickname }"))],
+    );
+    // Deprecated fields are documented in the generated types.
+    let param_type = project.artifact("Query/PetList/param_type.ts");
+    assert!(
+        param_type.contains("/** @deprecated Use name instead. */"),
+        "{}",
+        param_type
+    );
+}
+
+#[test]
+fn deprecated_selections_are_warnings_by_default() {
+    let project = TestProject::new(
+        "deprecated_selections_are_warnings_by_default",
+        SCHEMA,
+        &[(
+            "PetList.ts",
+            &pet_list("pets(status: SOLD) { nickname, name }"),
+        )],
+    );
+    let warnings = project.warnings();
+    assert_eq!(warnings.len(), 2, "{:?}", warnings);
+    assert!(warnings[0].starts_with(
+        "The enum value `PetStatus.SOLD` is deprecated. Reason: Use ADOPTED instead."
+    ));
+    assert!(warnings[1]
+        .starts_with("The field `Pet.nickname` is deprecated. Reason: Use name instead."));
+
+    let project = TestProject::with_options(
+        "deprecated_selections_are_not_warnings_if_ignored",
+        "{ \"on_deprecated_field_selection\": \"ignore\" }",
+        SCHEMA,
+        &[("PetList.ts", &pet_list("pets { nickname }"))],
+    );
+    assert!(project.warnings().is_empty());
+}
+
+#[test]
+fn deprecation_reasons_must_be_strings() {
+    let project = TestProject::new(
+        "deprecation_reasons_must_be_strings",
+        "
+type Query {
+  name: String @deprecated(reason: 1)
+}
+",
+        &[],
+    );
+    let error = project.validation_error();
+    assert!(
+        error.contains("Failed to deserialize invalid type: integer `1`, expected a string"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn deprecated_enum_values_cannot_be_passed_if_configured() {
+    let project = TestProject::with_options(
+        "deprecated_enum_values_cannot_be_passed_if_configured",
+        ON_DEPRECATED_ERROR,
+        SCHEMA,
+        &[("PetList.ts", &pet_list("pets(status: SOLD) { name }"))],
+    );
+    let error = project.validation_error();
+    assert!(
+        error.contains(
+            "The enum value `PetStatus.SOLD` is deprecated. Reason: Use ADOPTED instead."
+        ),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn enum_values_that_are_not_deprecated_can_be_passed() {
+    let project = TestProject::with_options(
+        "enum_values_that_are_not_deprecated_can_be_passed",
+        ON_DEPRECATED_ERROR,
+        SCHEMA,
+        &[("PetList.ts", &pet_list("pets(status: ADOPTED) { name }"))],
+    );
+    if let Err(error) = project.validate() {
+        panic!("Expected validation to succeed. {}", error);
+    }
+}
@@ -0,0 +1,105 @@
+use tests::{client_field, entrypoint, source_file, TestProject};
+
+const SCHEMA: &str = "
+type Query {
+  node: Node
+  searchResult: SearchResult
+}
+
+interface Node {
+  id: ID!
+}
+
+type Pet implements Node {
+  id: ID!
+  name: String!
+  nickname: String
+}
+
+type Stray implements Node {
+  id: ID!
+  name: String!
+}
+
+union SearchResult = Pet | Stray
+";
+
+fn node_detail(selections: &str) -> String {
+    source_file([
+        client_field(&format!("Query.NodeDetail {{\n    {selections}\n  }}")),
+        entrypoint("Query.NodeDetail"),
+    ])
+}
+
+#[test]
+fn selecting_typename_on_an_interface_generates_a_discriminated_union() {
+    let project = TestProject::new(
+        "selecting_typename_on_an_interface_generates_a_discriminated_union",
+        SCHEMA,
+        &[("NodeDetail.ts", &node_detail("node { __typename, id }"))],
+    );
+
+    assert!(project.artifact("Query/NodeDetail/param_type.ts").contains(
+        "readonly node: ({
+      readonly __typename: \"Pet\",
+      readonly id: string,
+    } | {
+      readonly __typename: \"Stray\",
+      readonly id: string,
+    } | null),"
+    ));
+}
+
+#[test]
+fn refinement_pointers_are_non_null_in_the_variant_for_their_type() {
+    let project = TestProject::new(
+        "refinement_pointers_are_non_null_in_the_variant_for_their_type",
+        SCHEMA,
+        &[(
+            "NodeDetail.ts",
+            &node_detail("searchResult { __typename, asPet { nickname } }"),
+        )],
+    );
+
+    let param_type = project.artifact("Query/NodeDetail/param_type.ts");
+    assert!(param_type.contains(
+        "readonly __typename: \"Pet\",
+      /**
+A client pointer for the Pet type.
+      */
+      readonly asPet: {
+        readonly nickname: (string | null),
+      },"
+    ));
+    assert!(param_type.contains(
+        "readonly __typename: \"Stray\",
+      /**
+A client pointer for the Pet type.
+      */
+      readonly asPet: null,"
+    ));
+}
+
+#[test]
+fn selecting_only_a_refinement_pointer_does_not_generate_a_discriminated_union() {
+    let project = TestProject::new(
+        "selecting_only_a_refinement_pointer_does_not_generate_a_discriminated_union",
+        SCHEMA,
+        &[(
+            "NodeDetail.ts",
+            &node_detail("node { id, asPet { nickname } }"),
+        )],
+    );
+
+    assert!(project.artifact("Query/NodeDetail/param_type.ts").contains(
+        "readonly node: ({
+      readonly id: string,
+      /**
+A client pointer for the Pet type.
+      */
+      readonly asPet: ({
+        readonly nickname: (string | null),
+      } | null),
+    } | null),"
+    ));
+}
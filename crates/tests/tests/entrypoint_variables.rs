@@ -0,0 +1,64 @@
+use tests::{client_field, entrypoint, source_file, TestProject};
+
+const SCHEMA: &str = "
+type Query {
+  node(id: ID!): Node
+  pets(first: Int!): [Pet!]!
+}
+
+interface Node {
+  id: ID!
+}
+
+type Pet implements Node {
+  id: ID!
+  name: String!
+}
+";
+
+#[test]
+fn entrypoint_variables_must_match_the_arguments_they_are_passed_to() {
+    let project = TestProject::new(
+        "entrypoint_variables_must_match_the_arguments_they_are_passed_to",
+        SCHEMA,
+        &[(
+            "PetList.ts",
+            &source_file([
+                client_field(
+                    "Query.PetNames($count: Int = 10) {
+    pets(first: $count) {
+      name
+    }
+  }",
+                ),
+                client_field(
+                    "Query.PetList($count: Int) {
+    PetNames(count: $count)
+  }",
+                ),
+                entrypoint("Query.PetList"),
+            ]),
+        )],
+    );
+
+    let error = project.validation_error();
+    assert!(
+        error.contains(
+            "In the entrypoint `Query.PetList`, the variable `$count` has type `Int`, \
+            but it is passed to the argument `first`, which has type `Int!`."
+        ),
+        "{}",
+        error
+    );
+    // The error is reported at the variable definition of the entrypoint.
+    assert!(
+        error.contains(
+            "src/PetList.ts
+
+  field Query.PetList($count: Int) {
+                       ^^^^^"
+        ),
+        "{}",
+        error
+    );
+}
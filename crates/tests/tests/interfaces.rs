@@ -0,0 +1,88 @@
+use tests::{client_field, entrypoint, source_file, TestProject};
+
+const SCHEMA: &str = "
+type Query {
+  node: Node
+  animal: Animal
+  pet: Pet
+}
+
+interface Node {
+  id: ID!
+}
+
+interface Animal implements Node {
+  id: ID!
+  name: String!
+}
+
+type Pet implements Node & Animal {
+  id: ID!
+  name: String!
+  nickname: String
+}
+";
+
+#[test]
+fn client_fields_on_an_interface_are_added_to_descendant_interfaces_and_objects() {
+    let project = TestProject::new(
+        "client_fields_on_an_interface_are_added_to_descendant_interfaces_and_objects",
+        SCHEMA,
+        &[(
+            "AnimalDetail.ts",
+            &source_file([
+                client_field("Node.NodeId {\n    id\n  }"),
+                client_field(
+                    "Query.AnimalDetail {
+    animal {
+      NodeId
+    }
+    pet {
+      NodeId
+    }
+  }",
+                ),
+                entrypoint("Query.AnimalDetail"),
+            ]),
+        )],
+    );
+
+    let param_type = project.artifact("Query/AnimalDetail/param_type.ts");
+    assert!(param_type.contains(
+        "readonly animal: ({
+      readonly NodeId: Node__NodeId__output_type,
+    } | null),"
+    ));
+    assert!(param_type.contains(
+        "readonly pet: ({
+      readonly NodeId: Node__NodeId__output_type,
+    } | null),"
+    ));
+}
+
+#[test]
+fn interfaces_implementing_each_other_do_not_loop_forever() {
+    let project = TestProject::new(
+        "interfaces_implementing_each_other_do_not_loop_forever",
+        "
+type Query {
+  a: A
+}
+
+interface A implements B {
+  id: ID!
+}
+
+interface B implements A {
+  id: ID!
+}
+
+type Pet implements A & B {
+  id: ID!
+}
+",
+        &[],
+    );
+
+    project.validate().expect("Expected validation to succeed");
+}
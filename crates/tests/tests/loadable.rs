@@ -0,0 +1,85 @@
+use tests::{client_field, entrypoint, source_file, TestProject};
+
+const SCHEMA: &str = "
+type Query {
+  pets: [Pet!]!
+}
+
+type Mutation {
+  adoptPet: Pet
+}
+
+type Pet {
+  id: ID!
+  name: String!
+}
+";
+
+#[test]
+fn client_fields_on_the_query_root_can_be_loaded() {
+    let project = TestProject::new(
+        "client_fields_on_the_query_root_can_be_loaded",
+        SCHEMA,
+        &[(
+            "Home.ts",
+            &source_file([
+                client_field(
+                    "Query.PetNames {
+    pets {
+      name
+    }
+  }",
+                ),
+                client_field(
+                    "Query.Home {
+    PetNames @loadable
+  }",
+                ),
+                entrypoint("Query.Home"),
+            ]),
+        )],
+    );
+
+    // The loadable field is refetched by selecting it at the root of a query.
+    let artifacts = project.artifacts().expect("Expected project to compile");
+    assert!(
+        artifacts.contains_key("Query/PetNames/refetch_reader.ts"),
+        "{:?}",
+        artifacts.keys().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn client_fields_on_the_mutation_root_cannot_be_loaded() {
+    let project = TestProject::new(
+        "client_fields_on_the_mutation_root_cannot_be_loaded",
+        SCHEMA,
+        &[(
+            "Adopt.ts",
+            &source_file([
+                client_field(
+                    "Mutation.AdoptedPet {
+    adoptPet {
+      name
+    }
+  }",
+                ),
+                client_field(
+                    "Mutation.Adopt {
+    AdoptedPet @loadable
+  }",
+                ),
+            ]),
+        )],
+    );
+
+    let error = project.validation_error();
+    assert!(
+        error.contains(
+            "`Mutation.AdoptedPet` cannot be selected with `@loadable`, because \
+            `Mutation` cannot be refetched."
+        ),
+        "{}",
+        error
+    );
+}
@@ -0,0 +1,171 @@
+use tests::{client_field, entrypoint, source_file, TestProject};
+
+const SCHEMA: &str = "
+interface Node {
+  id: ID!
+}
+
+type Query {
+  node(id: ID!): Node
+  viewer: User!
+}
+
+type User implements Node {
+  id: ID!
+  name: String!
+  friends(first: Int, after: String, last: Int, before: String): UserConnection!
+}
+
+type UserConnection {
+  edges: [UserEdge!]!
+  pageInfo: PageInfo!
+}
+
+type UserEdge {
+  cursor: String!
+  node: User!
+}
+
+type PageInfo {
+  hasNextPage: Boolean!
+  hasPreviousPage: Boolean!
+  startCursor: String
+  endCursor: String
+}
+";
+
+fn friends_list() -> String {
+    source_file([
+        client_field(
+            "Query.FriendsList($first: Int) {
+    viewer {
+      name
+      friends(first: $first) {
+        edges {
+          node {
+            name
+          }
+        }
+      } @paginated
+    }
+  }",
+        ),
+        entrypoint("Query.FriendsList"),
+    ])
+}
+
+#[test]
+fn paginate_query_uses_reserved_page_variables() {
+    let project = TestProject::new(
+        "paginate_query_uses_reserved_page_variables",
+        SCHEMA,
+        &[("FriendsList.ts", &friends_list())],
+    );
+    let refetch_query = project.artifact("Query/FriendsList/__refetch__0.ts");
+
+    assert!(
+        refetch_query.contains(
+            "query User__friends__paginate ($isograph__first: Int, \
+            $isograph__after: String, $isograph__refetch__id: ID!)"
+        ),
+        "{}",
+        refetch_query
+    );
+    assert!(
+        refetch_query.contains("friends(first: $isograph__first, after: $isograph__after)"),
+        "{}",
+        refetch_query
+    );
+
+    // The user's $first is only passed to the entrypoint, not the page query.
+    let entrypoint = project.artifact("Query/FriendsList/entrypoint.ts");
+    assert!(
+        entrypoint.contains("query FriendsList ($first: Int)"),
+        "{}",
+        entrypoint
+    );
+    assert!(
+        entrypoint.contains("friends(first: $first)"),
+        "{}",
+        entrypoint
+    );
+}
+
+#[test]
+fn paginated_field_selects_cursor_and_page_info() {
+    let project = TestProject::new(
+        "paginated_field_selects_cursor_and_page_info",
+        SCHEMA,
+        &[("FriendsList.ts", &friends_list())],
+    );
+    let entrypoint = project.artifact("Query/FriendsList/entrypoint.ts");
+
+    for expected in ["cursor,", "hasNextPage,", "endCursor,"] {
+        assert!(entrypoint.contains(expected), "{}", entrypoint);
+    }
+    assert!(!entrypoint.contains("hasPreviousPage"), "{}", entrypoint);
+
+    let reader = project.artifact("Query/FriendsList/resolver_reader.ts");
+    assert!(
+        reader.contains("countVariable: \"isograph__first\""),
+        "{}",
+        reader
+    );
+    assert!(
+        reader.contains("cursorVariable: \"isograph__after\""),
+        "{}",
+        reader
+    );
+}
+
+#[test]
+fn paginated_field_param_type_has_load_more() {
+    let project = TestProject::new(
+        "paginated_field_param_type_has_load_more",
+        SCHEMA,
+        &[("FriendsList.ts", &friends_list())],
+    );
+    let param_type = project.artifact("Query/FriendsList/param_type.ts");
+
+    assert!(
+        param_type.contains(
+            "      readonly friends: {
+        readonly edges: ReadonlyArray<{
+          readonly node: {
+            readonly name: string,
+          },
+        }>,
+        readonly loadMore: (count: number) => void,
+      },"
+        ),
+        "{}",
+        param_type
+    );
+}
+
+#[test]
+fn paginated_field_requires_count_argument() {
+    let project = TestProject::new(
+        "paginated_field_requires_count_argument",
+        SCHEMA,
+        &[(
+            "FriendsList.ts",
+            &source_file([client_field(
+                "Query.FriendsList {
+    viewer {
+      friends {
+        edges {
+          node {
+            name
+          }
+        }
+      } @paginated
+    }
+  }",
+            )]),
+        )],
+    );
+
+    let error = project.validation_error();
+    assert!(error.contains("@paginated"), "{}", error);
+}
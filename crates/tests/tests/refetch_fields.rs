@@ -0,0 +1,213 @@
+use tests::{client_field, entrypoint, query_text, source_file, TestProject};
+
+const SCHEMA: &str = "
+interface Node {
+  id: ID!
+}
+
+type Query {
+  node(id: ID!): Node
+  user(login: String!): User
+}
+
+type Mutation {
+  set_user_name(id: ID!, name: String!): SetUserNamePayload!
+  rename_repository(owner: String!, name: String!, new_name: String!): RenameRepositoryPayload!
+}
+
+type SetUserNamePayload {
+  user: User!
+}
+
+type RenameRepositoryPayload {
+  repository: Repository!
+}
+
+type User implements Node {
+  id: ID!
+  login: String!
+  name: String!
+  repository(id: ID!): Repository
+}
+
+type Repository implements Node {
+  id: ID!
+  name: String!
+  owner: User!
+}
+";
+
+#[test]
+fn refetch_variables_do_not_shadow_client_field_variables() {
+    let project = TestProject::new(
+        "refetch_variables_do_not_shadow_client_field_variables",
+        SCHEMA,
+        &[(
+            "UserRepository.ts",
+            &source_file([
+                client_field(
+                    "Query.UserRepository($login: String!, $id: ID!) {
+    user(login: $login) {
+      __refetch
+      repository(id: $id) {
+        name
+      }
+    }
+  }",
+                ),
+                entrypoint("Query.UserRepository"),
+            ]),
+        )],
+    );
+
+    let refetch_query = project.artifact("Query/UserRepository/__refetch__0.ts");
+    let query_text = query_text(&refetch_query);
+    for expected in [
+        "query User__refetch ($id: ID!, $isograph__refetch__id: ID!)",
+        "node(id: $isograph__refetch__id)",
+        "repository(id: $id)",
+    ] {
+        assert!(query_text.contains(expected), "{}", query_text);
+    }
+
+    let refetch_reader = project.artifact("User/__refetch/refetch_reader.ts");
+    assert!(
+        refetch_reader.contains("variables.isograph__refetch__id = readOutData.id;"),
+        "{}",
+        refetch_reader
+    );
+}
+
+#[test]
+fn refetch_field_directive_arguments_are_namespaced() {
+    let project = TestProject::with_schema_extension(
+        "refetch_field_directive_arguments_are_namespaced",
+        SCHEMA,
+        "extend type User @refetchField(field: \"user\", fieldMap: [{ from: \"login\", to: \"login\" }])",
+        &[(
+            "UserName.ts",
+            &source_file([
+                client_field(
+                    "Query.UserName($login: String!) {
+    user(login: $login) {
+      name
+      __refetch
+    }
+  }",
+                ),
+                entrypoint("Query.UserName"),
+            ]),
+        )],
+    );
+
+    let refetch_query = project.artifact("Query/UserName/__refetch__0.ts");
+    let query_text = query_text(&refetch_query);
+    assert!(
+        query_text.contains("query User__refetch ($isograph__refetch__login: String!)"),
+        "{}",
+        query_text
+    );
+    assert!(
+        query_text.contains("user(login: $isograph__refetch__login)"),
+        "{}",
+        query_text
+    );
+
+    let refetch_reader = project.artifact("User/__refetch/refetch_reader.ts");
+    assert!(
+        refetch_reader.contains("variables.isograph__refetch__login = readOutData.login;"),
+        "{}",
+        refetch_reader
+    );
+}
+
+#[test]
+fn exposed_field_arguments_from_the_field_map_are_namespaced() {
+    let project = TestProject::with_schema_extension(
+        "exposed_field_arguments_from_the_field_map_are_namespaced",
+        SCHEMA,
+        "extend type Mutation
+  @exposeField(
+    field: \"set_user_name\"
+    path: \"user\"
+    as: \"set_name\"
+    fieldMap: [{ from: \"id\", to: \"id\" }]
+  )",
+        &[(
+            "UserNameEditor.ts",
+            &source_file([
+                client_field(
+                    "Query.UserNameEditor($login: String!, $id: ID!) {
+    user(login: $login) {
+      name
+      set_name
+      repository(id: $id) {
+        name
+      }
+    }
+  }",
+                ),
+                entrypoint("Query.UserNameEditor"),
+            ]),
+        )],
+    );
+
+    let refetch_query = project.artifact("Query/UserNameEditor/__refetch__0.ts");
+    let query_text = query_text(&refetch_query);
+    // The id is provided by the field map, and the name by the caller.
+    for expected in [
+        "($id: ID!, $isograph__refetch__id: ID!, $name: String!)",
+        "set_user_name(id: $isograph__refetch__id, name: $name)",
+        "repository(id: $id)",
+    ] {
+        assert!(query_text.contains(expected), "{}", query_text);
+    }
+
+    let refetch_reader = project.artifact("User/set_name/refetch_reader.ts");
+    assert!(
+        refetch_reader.contains("variables.isograph__refetch__id = readOutData.id;"),
+        "{}",
+        refetch_reader
+    );
+}
+
+#[test]
+fn exposed_field_map_supports_paths() {
+    let project = TestProject::with_schema_extension(
+        "exposed_field_map_supports_paths",
+        SCHEMA,
+        "extend type Mutation
+  @exposeField(
+    field: \"rename_repository\"
+    path: \"repository\"
+    as: \"rename\"
+    fieldMap: [{ from: \"owner.login\", to: \"owner\" }, { from: \"name\", to: \"name\" }]
+  )",
+        &[(
+            "RepositoryName.ts",
+            &source_file([
+                client_field(
+                    "Query.RepositoryName($login: String!, $id: ID!) {
+    user(login: $login) {
+      repository(id: $id) {
+        name
+        rename
+      }
+    }
+  }",
+                ),
+                entrypoint("Query.RepositoryName"),
+            ]),
+        )],
+    );
+
+    let refetch_reader = project.artifact("Repository/rename/refetch_reader.ts");
+    for expected in [
+        "variables.isograph__refetch__owner = readOutData.owner.login;",
+        "variables.isograph__refetch__name = readOutData.name;",
+        "fieldName: \"owner\",",
+        "fieldName: \"login\",",
+    ] {
+        assert!(refetch_reader.contains(expected), "{}", refetch_reader);
+    }
+}
@@ -0,0 +1,114 @@
+use tests::{client_field, entrypoint, source_file, TestProject};
+
+const SCHEMA: &str = "
+type Query {
+  pets(onlyAdopted: Boolean, sort: PetSort): [Pet!]!
+}
+
+enum PetSort {
+  NAME
+  AGE
+}
+
+type Pet {
+  id: ID!
+  name: String!
+  nickname: String
+  age: Int @semanticNonNull
+}
+";
+
+fn pet_list(selections: &str) -> String {
+    source_file([
+        client_field(&format!(
+            "Query.PetList {{
+    {selections}
+  }}"
+        )),
+        entrypoint("Query.PetList"),
+    ])
+}
+
+#[test]
+fn required_with_action_none_makes_the_parent_nullable() {
+    let project = TestProject::new(
+        "required_with_action_none_makes_the_parent_nullable",
+        SCHEMA,
+        &[(
+            "PetList.ts",
+            &pet_list("pets { nickname @required(action: NONE) }"),
+        )],
+    );
+
+    let param_type = project.artifact("Query/PetList/param_type.ts");
+    assert!(param_type.contains(
+        "readonly pets: ReadonlyArray<({
+      readonly nickname: string,
+    } | null)>,"
+    ));
+    let reader = project.artifact("Query/PetList/resolver_reader.ts");
+    assert!(reader.contains("required: \"NONE\","));
+}
+
+#[test]
+fn misspelled_booleans_are_rejected() {
+    let project = TestProject::new(
+        "misspelled_booleans_are_rejected",
+        SCHEMA,
+        &[("PetList.ts", &pet_list("pets(onlyAdopted: treu) { name }"))],
+    );
+
+    assert!(project.validation_error().contains(
+        "`treu` is passed to the argument `onlyAdopted`, which has type `Boolean`, \
+         but it is not a value of that type."
+    ));
+}
+
+#[test]
+fn undefined_enum_values_are_rejected() {
+    let project = TestProject::new(
+        "undefined_enum_values_are_rejected",
+        SCHEMA,
+        &[("PetList.ts", &pet_list("pets(sort: NAEM) { name }"))],
+    );
+
+    assert!(project.validation_error().contains(
+        "`NAEM` is passed to the argument `sort`, which has type `PetSort`, \
+         but it is not a value of that type."
+    ));
+}
+
+#[test]
+fn booleans_and_defined_enum_values_are_accepted() {
+    let project = TestProject::new(
+        "booleans_and_defined_enum_values_are_accepted",
+        SCHEMA,
+        &[(
+            "PetList.ts",
+            &pet_list("pets(sort: NAME, onlyAdopted: true) { name }"),
+        )],
+    );
+
+    project.validate().expect("Expected validation to succeed");
+}
+
+#[test]
+fn semantically_non_null_fields_are_non_null() {
+    let project = TestProject::new(
+        "semantically_non_null_fields_are_non_null",
+        SCHEMA,
+        &[("PetList.ts", &pet_list("pets { nickname, age }"))],
+    );
+
+    let param_type = project.artifact("Query/PetList/param_type.ts");
+    assert!(
+        param_type.contains("readonly nickname: (string | null),"),
+        "{}",
+        param_type
+    );
+    assert!(
+        param_type.contains("readonly age: number,"),
+        "{}",
+        param_type
+    );
+}
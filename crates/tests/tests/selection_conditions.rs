@@ -0,0 +1,152 @@
+use tests::{client_field, entrypoint, source_file, TestProject};
+
+const SCHEMA: &str = "
+type Query {
+  pet: Pet
+}
+
+type Pet {
+  id: ID!
+  name: String!
+  nickname: String
+}
+";
+
+fn pet_detail(variables: &str, selections: &str) -> String {
+    source_file([
+        client_field(
+            "Pet.PetNickname {
+    nickname
+  }",
+        ),
+        client_field(&format!(
+            "Query.PetDetail{variables} {{
+    {selections}
+  }}"
+        )),
+        entrypoint("Query.PetDetail"),
+    ])
+}
+
+#[test]
+fn conditions_on_client_field_selections_apply_to_the_merged_selections() {
+    let project = TestProject::new(
+        "conditions_on_client_field_selections_apply_to_the_merged_selections",
+        SCHEMA,
+        &[(
+            "PetDetail.ts",
+            &pet_detail(
+                "($showNickname: Boolean!)",
+                "pet {
+      name
+      PetNickname @include(if: $showNickname)
+    }",
+            ),
+        )],
+    );
+
+    let entrypoint = project.artifact("Query/PetDetail/entrypoint.ts");
+    assert!(entrypoint.contains("nickname @include(if: $showNickname),"));
+    assert!(entrypoint.contains(
+        "fieldName: \"nickname\",
+        arguments: null,
+        conditions: [
+          { kind: \"Include\", if: { kind: \"Variable\", name: \"showNickname\" } },
+        ],"
+    ));
+    assert!(entrypoint.contains("    id,\\\n"));
+}
+
+#[test]
+fn conditional_and_unconditional_selections_of_a_field_are_kept_apart() {
+    let project = TestProject::new(
+        "conditional_and_unconditional_selections_of_a_field_are_kept_apart",
+        SCHEMA,
+        &[(
+            "PetDetail.ts",
+            &pet_detail(
+                "($hideName: Boolean = false)",
+                "pet {
+      name
+      hideableName: name @skip(if: $hideName)
+    }",
+            ),
+        )],
+    );
+
+    let entrypoint = project.artifact("Query/PetDetail/entrypoint.ts");
+    assert!(entrypoint.contains("    name,\\\n"));
+    assert!(entrypoint.contains("    name @skip(if: $hideName),\\\n"));
+    assert!(entrypoint.contains(
+        "      {
+        kind: \"Scalar\",
+        fieldName: \"name\",
+        arguments: null,
+      },"
+    ));
+    assert!(
+        entrypoint.contains("{ kind: \"Skip\", if: { kind: \"Variable\", name: \"hideName\" } },")
+    );
+}
+
+#[test]
+fn conditions_must_be_passed_an_if_argument() {
+    let project = TestProject::new(
+        "conditions_must_be_passed_an_if_argument",
+        SCHEMA,
+        &[("PetDetail.ts", &pet_detail("", "pet { name @include }"))],
+    );
+
+    assert!(project
+        .validation_error()
+        .contains("This field has missing arguments: $if"));
+}
+
+#[test]
+fn conditions_must_be_passed_a_boolean_or_a_variable() {
+    let project = TestProject::new(
+        "conditions_must_be_passed_a_boolean_or_a_variable",
+        SCHEMA,
+        &[(
+            "PetDetail.ts",
+            &pet_detail("", "pet { name @include(if: 1) }"),
+        )],
+    );
+
+    assert!(project
+        .validation_error()
+        .contains("The `if` argument of `@include` must be passed a Boolean or a variable."));
+}
+
+#[test]
+fn conditions_must_be_passed_a_boolean_variable() {
+    let project = TestProject::new(
+        "conditions_must_be_passed_a_boolean_variable",
+        SCHEMA,
+        &[(
+            "PetDetail.ts",
+            &pet_detail("($label: String)", "pet { name @skip(if: $label) }"),
+        )],
+    );
+
+    assert!(project.validation_error().contains(
+        "The variable `$label` has type `String`, but it is passed to the argument `if`, \
+         which has type `Boolean!`."
+    ));
+}
+
+#[test]
+fn conditions_must_be_passed_a_defined_variable() {
+    let project = TestProject::new(
+        "conditions_must_be_passed_a_defined_variable",
+        SCHEMA,
+        &[(
+            "PetDetail.ts",
+            &pet_detail("", "pet { name @include(if: $missing) }"),
+        )],
+    );
+
+    assert!(project
+        .validation_error()
+        .contains("This variable is not defined: $missing"));
+}
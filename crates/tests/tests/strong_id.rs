@@ -0,0 +1,165 @@
+use tests::{client_field, entrypoint, query_text, source_file, TestProject};
+
+const SCHEMA: &str = "
+type Query {
+  pet(nodeId: ID!): Pet
+}
+
+type Pet {
+  nodeId: ID!
+  name: String!
+}
+";
+
+fn pet_detail() -> String {
+    source_file([
+        client_field(
+            "Query.PetDetail($nodeId: ID!) {
+    pet(nodeId: $nodeId) {
+      name
+    }
+  }",
+        ),
+        entrypoint("Query.PetDetail"),
+    ])
+}
+
+#[test]
+fn the_strong_id_field_can_be_configured() {
+    let project = TestProject::with_options(
+        "the_strong_id_field_can_be_configured",
+        "{ \"strong_id_field\": \"nodeId\" }",
+        SCHEMA,
+        &[("PetDetail.ts", &pet_detail())],
+    );
+
+    let entrypoint = project.artifact("Query/PetDetail/entrypoint.ts");
+    // The strong id field is always selected, and the runtime normalizes by it.
+    assert!(
+        query_text(&entrypoint).contains("    nodeId,\\\n"),
+        "{}",
+        entrypoint
+    );
+    assert!(
+        entrypoint.contains("strongIdField: \"nodeId\","),
+        "{}",
+        entrypoint
+    );
+}
+
+#[test]
+fn the_strong_id_field_can_be_set_per_type_with_strong() {
+    let project = TestProject::new(
+        "the_strong_id_field_can_be_set_per_type_with_strong",
+        &SCHEMA.replace("nodeId: ID!\n", "nodeId: ID! @strong\n"),
+        &[("PetDetail.ts", &pet_detail())],
+    );
+
+    let entrypoint = project.artifact("Query/PetDetail/entrypoint.ts");
+    assert!(
+        query_text(&entrypoint).contains("    nodeId,\\\n"),
+        "{}",
+        entrypoint
+    );
+    assert!(
+        entrypoint.contains("strongIdField: \"nodeId\","),
+        "{}",
+        entrypoint
+    );
+}
+
+#[test]
+fn fields_named_id_are_not_the_strong_id_field_if_another_field_is() {
+    let project = TestProject::with_options(
+        "fields_named_id_are_not_the_strong_id_field_if_another_field_is",
+        "{ \"strong_id_field\": \"nodeId\" }",
+        "
+interface Node {
+  nodeId: ID!
+}
+
+type Query {
+  node(id: ID!): Node
+  pet(nodeId: ID!): Pet
+}
+
+type Pet implements Node {
+  nodeId: ID!
+  id: Int
+  name: String!
+  owner: Owner
+}
+
+type Owner {
+  nodeId: ID!
+  id: DatabaseId!
+  name: String!
+}
+
+type DatabaseId {
+  value: Int!
+}
+",
+        &[(
+            "PetDetail.ts",
+            &source_file([
+                client_field(
+                    "Query.PetDetail($nodeId: ID!) {
+    pet(nodeId: $nodeId) {
+      id
+      name
+      owner {
+        id {
+          value
+        }
+      }
+      __refetch
+    }
+  }",
+                ),
+                entrypoint("Query.PetDetail"),
+            ]),
+        )],
+    );
+
+    // Fields named id are selected like any other field, and the runtime
+    // normalizes by nodeId.
+    let entrypoint = project.artifact("Query/PetDetail/entrypoint.ts");
+    assert!(
+        query_text(&entrypoint).contains(
+            "  pet____nodeId___v_nodeId: pet(nodeId: $nodeId) {\\
+    nodeId,\\
+    id,\\
+    name,\\
+    owner {\\
+      nodeId,\\
+      id {\\
+        value,\\
+      },\\
+    },\\
+  },"
+        ),
+        "{}",
+        entrypoint
+    );
+    assert_eq!(
+        entrypoint.matches("strongIdField: \"nodeId\",").count(),
+        2,
+        "{}",
+        entrypoint
+    );
+
+    // Pets are refetched via node(id:), which is passed their nodeId.
+    let refetch_query = project.artifact("Query/PetDetail/__refetch__0.ts");
+    assert!(
+        query_text(&refetch_query).contains("node(id: $isograph__refetch__id) {"),
+        "{}",
+        refetch_query
+    );
+    let refetch_reader = project.artifact("Pet/__refetch/refetch_reader.ts");
+    assert!(
+        refetch_reader.contains("variables.isograph__refetch__id = readOutData.nodeId;"),
+        "{}",
+        refetch_reader
+    );
+}
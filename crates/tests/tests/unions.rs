@@ -0,0 +1,110 @@
+use tests::{client_field, entrypoint, query_text, source_file, TestProject};
+
+const SCHEMA: &str = "
+type Query {
+  searchResult: SearchResult
+}
+
+type Pet {
+  id: ID!
+  name: String!
+}
+
+type Stray {
+  id: ID!
+  name: String!
+}
+
+union SearchResult = Pet | Stray
+";
+
+#[test]
+fn union_members_are_selected_through_refinement_pointers() {
+    let project = TestProject::new(
+        "union_members_are_selected_through_refinement_pointers",
+        SCHEMA,
+        &[(
+            "SearchResultDetail.ts",
+            &source_file([
+                client_field(
+                    "Query.SearchResultDetail {
+    searchResult {
+      __typename
+      asPet {
+        name
+      }
+    }
+  }",
+                ),
+                entrypoint("Query.SearchResultDetail"),
+            ]),
+        )],
+    );
+
+    let entrypoint = project.artifact("Query/SearchResultDetail/entrypoint.ts");
+    let query_text = query_text(&entrypoint);
+    assert!(
+        query_text.contains(
+            "  searchResult {\\
+    __typename,\\
+    ... on Pet {\\
+      __typename,\\
+      id,\\
+      name,\\
+    },\\
+  },"
+        ),
+        "{}",
+        query_text
+    );
+}
+
+fn schema_error(name: &str, schema: &str) -> String {
+    TestProject::new(name, schema, &[]).validation_error()
+}
+
+#[test]
+fn union_members_must_be_defined() {
+    assert!(schema_error(
+        "union_members_must_be_defined",
+        "
+type Query {
+  searchResult: SearchResult
+}
+
+type Pet {
+  id: ID!
+}
+
+union SearchResult = Pet | Missing
+"
+    )
+    .contains("Type \"Missing\" is never defined."));
+}
+
+#[test]
+fn union_members_must_be_object_types() {
+    let message = "The union \"SearchResult\" has a member \"{member}\", which is not \
+                   an object type. Union members must be object types.";
+    for (member, definition) in [("Node", "interface Node { id: ID! }"), ("String", "")] {
+        let error = schema_error(
+            &format!("union_members_must_be_object_types_{member}"),
+            &format!(
+                "
+type Query {{
+  searchResult: SearchResult
+}}
+
+type Pet {{
+  id: ID!
+}}
+
+{definition}
+
+union SearchResult = Pet | {member}
+"
+            ),
+        );
+        assert!(error.contains(&message.replace("{member}", member)));
+    }
+}
@@ -0,0 +1,226 @@
+use tests::{client_field, entrypoint, source_file, TestProject};
+
+const SCHEMA: &str = "
+type Query {
+  pet: Pet
+}
+
+type Pet {
+  id: ID!
+  name: String!
+  nickname: String
+  age: Int @semanticNonNull
+  owner: Person
+}
+
+type Person {
+  id: ID!
+  name: String!
+}
+";
+
+fn pet_name_editor() -> String {
+    source_file([
+        client_field(
+            "Query.PetNameEditor {
+    pet {
+      name
+      owner {
+        name
+      } @updatable
+    }
+  }",
+        ),
+        entrypoint("Query.PetNameEditor"),
+    ])
+}
+
+#[test]
+fn updatable_selections_generate_an_updater_artifact() {
+    let project = TestProject::new(
+        "updatable_selections_generate_an_updater_artifact",
+        SCHEMA,
+        &[("PetNameEditor.ts", &pet_name_editor())],
+    );
+
+    let updater = project.artifact("Query/PetNameEditor/updater.ts");
+    // Only fields within the @updatable selection set can be written to.
+    assert!(
+        updater.contains(
+            "export type Query__PetNameEditor__updatable_data = {
+  readonly pet: ({
+    readonly name: string,
+    readonly owner: ({
+      name: string,
+    } | null),
+  } | null),
+};"
+        ),
+        "{}",
+        updater
+    );
+    assert!(
+        updater.contains(
+            "        selections: [
+          {
+            kind: \"Scalar\",
+            fieldName: \"name\",
+            alias: null,
+            arguments: null,
+            isUpdatable: true,
+          },
+        ],"
+        ),
+        "{}",
+        updater
+    );
+    assert_eq!(
+        updater.matches("isUpdatable: true").count(),
+        1,
+        "{}",
+        updater
+    );
+    assert!(updater.contains("kind: \"UpdaterArtifact\""), "{}", updater);
+
+    // The data passed to the client field is read-only.
+    let reader = project.artifact("Query/PetNameEditor/resolver_reader.ts");
+    assert!(!reader.contains("isUpdatable"), "{}", reader);
+    let param_type = project.artifact("Query/PetNameEditor/param_type.ts");
+    assert!(
+        param_type.contains("readonly name: string,"),
+        "{}",
+        param_type
+    );
+    assert!(!param_type.contains("  name: string,"), "{}", param_type);
+}
+
+#[test]
+fn client_fields_without_updatable_selections_have_no_updater_artifact() {
+    let project = TestProject::new(
+        "client_fields_without_updatable_selections_have_no_updater_artifact",
+        SCHEMA,
+        &[(
+            "PetName.ts",
+            &source_file([client_field(
+                "Query.PetName {
+    pet {
+      name
+    }
+  }",
+            )]),
+        )],
+    );
+
+    let artifacts = project.artifacts().expect("Expected project to compile");
+    assert!(!artifacts.contains_key("Query/PetName/updater.ts"));
+}
+
+#[test]
+fn id_and_typename_are_not_updatable() {
+    let project = TestProject::new(
+        "id_and_typename_are_not_updatable",
+        SCHEMA,
+        &[(
+            "PetEditor.ts",
+            &source_file([client_field(
+                "Query.PetEditor @updatable {
+    pet {
+      id
+      __typename
+      nickname
+    }
+  }",
+            )]),
+        )],
+    );
+
+    let updater = project.artifact("Query/PetEditor/updater.ts");
+    for expected in [
+        "    readonly id: string,",
+        "    readonly __typename: string,",
+        "    nickname: (string | null),",
+    ] {
+        assert!(updater.contains(expected), "{}", updater);
+    }
+    assert_eq!(
+        updater.matches("isUpdatable: true").count(),
+        1,
+        "{}",
+        updater
+    );
+}
+
+#[test]
+fn updatable_data_respects_required_and_semantic_non_null() {
+    let project = TestProject::new(
+        "updatable_data_respects_required_and_semantic_non_null",
+        SCHEMA,
+        &[(
+            "PetEditor.ts",
+            &source_file([client_field(
+                "Query.PetEditor {
+    pet {
+      nickname @required(action: THROW)
+      age
+    } @required(action: THROW) @updatable
+  }",
+            )]),
+        )],
+    );
+
+    let updater = project.artifact("Query/PetEditor/updater.ts");
+    assert!(
+        updater.contains(
+            "export type Query__PetEditor__updatable_data = {
+  readonly pet: {
+    nickname: string,
+    age: number,
+  },
+};"
+        ),
+        "{}",
+        updater
+    );
+    assert_eq!(
+        updater.matches("required: \"THROW\"").count(),
+        3,
+        "{}",
+        updater
+    );
+}
+
+#[test]
+fn client_fields_cannot_be_selected_in_updatable_selection_sets() {
+    let project = TestProject::new(
+        "client_fields_cannot_be_selected_in_updatable_selection_sets",
+        SCHEMA,
+        &[(
+            "PetEditor.ts",
+            &source_file([
+                client_field(
+                    "Pet.PetName {
+    name
+  }",
+                ),
+                client_field(
+                    "Query.PetEditor {
+    pet {
+      nickname
+      PetName
+    } @updatable
+  }",
+                ),
+            ]),
+        )],
+    );
+
+    let error = project.validation_error();
+    assert!(
+        error.contains(
+            "`PetName` is a client field, and cannot be selected within an `@updatable` \
+            selection set, since only server fields can be updated."
+        ),
+        "{}",
+        error
+    );
+}
@@ -6,7 +6,7 @@ import { Card, CardContent } from '@mui/material';
 import { RepoGitHubLink } from './RepoGitHubLink';
 
 export const PullRequestDetail = iso(`
-  field Query.PullRequestDetail($repositoryOwner: String, $repositoryName: String, $pullRequestNumber: Int) @component {
+  field Query.PullRequestDetail($repositoryOwner: String!, $repositoryName: String!, $pullRequestNumber: Int!) @component {
     repository(owner: $repositoryOwner, name: $repositoryName) {
       pullRequest(number: $pullRequestNumber) {
         title
@@ -1,4 +1,5 @@
 import { iso } from '@iso';
+import { Button } from '@mui/material';
 import { RepoGitHubLink } from './RepoGitHubLink';
 import { Route } from './GithubDemo';
 
@@ -17,7 +18,7 @@ export const IsStarred = iso(`
 });
 
 export const RepositoryDetail = iso(`
-  field Query.RepositoryDetail($first: Int, $repositoryName: String, $repositoryOwner: String) @component {
+  field Query.RepositoryDetail($first: Int, $repositoryName: String!, $repositoryOwner: String!) @component {
     repository(name: $repositoryName, owner: $repositoryOwner) {
       IsStarred
       nameWithOwner
@@ -28,7 +29,7 @@ export const RepositoryDetail = iso(`
 
       pullRequests(last: $first) {
         PullRequestTable
-      }
+      } @paginated
     }
   }
 `)(function RepositoryDetailComponent(
@@ -54,6 +55,12 @@ export const RepositoryDetail = iso(`
       ) : null}
       <repository.IsStarred />
       <repository.pullRequests.PullRequestTable setRoute={setRoute} />
+      <Button
+        onClick={() => repository.pullRequests.loadMore(10)}
+        variant="contained"
+      >
+        Load older pull requests
+      </Button>
     </>
   );
 });
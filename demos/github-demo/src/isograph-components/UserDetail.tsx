@@ -3,7 +3,7 @@ import { RepoGitHubLink } from './RepoGitHubLink';
 import { Route } from './GithubDemo';
 
 export const UserDetail = iso(`
-  field Query.UserDetail($userLogin: String) @component {
+  field Query.UserDetail($userLogin: String!) @component {
     user(login: $userLogin) {
       name
       RepositoryList
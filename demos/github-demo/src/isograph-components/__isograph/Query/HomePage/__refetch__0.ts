@@ -1,6 +1,6 @@
 import type { IsographEntrypoint, ReaderAst, FragmentReference, NormalizationAst, RefetchQueryNormalizationArtifact } from '@isograph/react';
-const queryText = 'query User__refetch ($id: ID!) {\
-  node____id___v_id: node(id: $id) {\
+const queryText = 'query User__refetch ($isograph__refetch__id: ID!) {\
+  node____id___v_isograph__refetch__id: node(id: $isograph__refetch__id) {\
     ... on User {\
       __typename,\
       id,\
@@ -45,7 +45,7 @@ const normalizationAst: NormalizationAst = [
     arguments: [
       [
         "id",
-        { kind: "Variable", name: "id" },
+        { kind: "Variable", name: "isograph__refetch__id" },
       ],
     ],
     concreteType: null,
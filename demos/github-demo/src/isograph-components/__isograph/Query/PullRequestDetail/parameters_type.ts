@@ -1,5 +1,5 @@
 export type Query__PullRequestDetail__parameters = {
-  readonly repositoryOwner?: string | null | void,
-  readonly repositoryName?: string | null | void,
-  readonly pullRequestNumber?: number | null | void,
+  readonly repositoryOwner: string,
+  readonly repositoryName: string,
+  readonly pullRequestNumber: number,
 };
@@ -29,6 +29,7 @@ A list of pull requests that have been opened in the repository.
       */
       readonly pullRequests: {
         readonly PullRequestTable: PullRequestConnection__PullRequestTable__output_type,
+        readonly loadMore: (count: number) => void,
       },
     } | null),
   },
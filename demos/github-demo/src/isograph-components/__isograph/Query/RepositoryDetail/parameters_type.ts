@@ -1,5 +1,5 @@
 export type Query__RepositoryDetail__parameters = {
   readonly first?: number | null | void,
-  readonly repositoryName?: string | null | void,
-  readonly repositoryOwner?: string | null | void,
+  readonly repositoryName: string,
+  readonly repositoryOwner: string,
 };
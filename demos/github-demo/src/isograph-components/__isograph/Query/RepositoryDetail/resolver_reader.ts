@@ -78,6 +78,36 @@ const readerAst: ReaderAst<Query__RepositoryDetail__param> = [
             usedRefetchQueries: [],
           },
         ],
+        pagination: {
+          refetchQuery: 0,
+          direction: "Backward",
+          countVariable: "isograph__last",
+          cursorVariable: "isograph__before",
+          hasMoreField: "hasPreviousPage",
+          pageCursorField: "startCursor",
+          pageArguments: [
+            [
+              "last",
+              { kind: "Variable", name: "isograph__last" },
+            ],
+
+            [
+              "before",
+              { kind: "Variable", name: "isograph__before" },
+            ],
+          ],
+          refetchReaderAst: [
+            {
+              kind: "Scalar",
+              fieldName: "id",
+              alias: null,
+              arguments: null,
+            },
+          ],
+          refetchFieldMap: [
+            { from: "id", to: "isograph__refetch__id" },
+          ],
+        },
       },
     ],
   },
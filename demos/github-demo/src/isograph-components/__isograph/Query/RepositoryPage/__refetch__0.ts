@@ -0,0 +1,248 @@
+import type { IsographEntrypoint, ReaderAst, FragmentReference, NormalizationAst, RefetchQueryNormalizationArtifact } from '@isograph/react';
+const queryText = 'query Repository__pullRequests__paginate ($isograph__before: String, $isograph__last: Int, $isograph__refetch__id: ID!) {\
+  node____id___v_isograph__refetch__id: node(id: $isograph__refetch__id) {\
+    ... on Repository {\
+      __typename,\
+      pullRequests____last___v_isograph__last____before___v_isograph__before: pullRequests(last: $isograph__last, before: $isograph__before) {\
+        edges {\
+          cursor,\
+          node {\
+            id,\
+            author {\
+              __typename,\
+              login,\
+              ... on User {\
+                __typename,\
+                id,\
+                twitterUsername,\
+              },\
+            },\
+            closed,\
+            createdAt,\
+            number,\
+            repository {\
+              id,\
+              name,\
+              owner {\
+                __typename,\
+                id,\
+                login,\
+              },\
+            },\
+            title,\
+            totalCommentsCount,\
+          },\
+        },\
+        pageInfo {\
+          hasPreviousPage,\
+          startCursor,\
+        },\
+      },\
+    },\
+  },\
+}';
+
+const normalizationAst: NormalizationAst = [
+  {
+    kind: "Linked",
+    fieldName: "node",
+    arguments: [
+      [
+        "id",
+        { kind: "Variable", name: "isograph__refetch__id" },
+      ],
+    ],
+    concreteType: null,
+    selections: [
+      {
+        kind: "InlineFragment",
+        type: "Repository",
+        selections: [
+          {
+            kind: "Scalar",
+            fieldName: "__typename",
+            arguments: null,
+          },
+          {
+            kind: "Linked",
+            fieldName: "pullRequests",
+            arguments: [
+              [
+                "last",
+                { kind: "Variable", name: "isograph__last" },
+              ],
+
+              [
+                "before",
+                { kind: "Variable", name: "isograph__before" },
+              ],
+            ],
+            concreteType: "PullRequestConnection",
+            selections: [
+              {
+                kind: "Linked",
+                fieldName: "edges",
+                arguments: null,
+                concreteType: "PullRequestEdge",
+                selections: [
+                  {
+                    kind: "Scalar",
+                    fieldName: "cursor",
+                    arguments: null,
+                  },
+                  {
+                    kind: "Linked",
+                    fieldName: "node",
+                    arguments: null,
+                    concreteType: "PullRequest",
+                    selections: [
+                      {
+                        kind: "Scalar",
+                        fieldName: "id",
+                        arguments: null,
+                      },
+                      {
+                        kind: "Linked",
+                        fieldName: "author",
+                        arguments: null,
+                        concreteType: null,
+                        selections: [
+                          {
+                            kind: "Scalar",
+                            fieldName: "__typename",
+                            arguments: null,
+                          },
+                          {
+                            kind: "Scalar",
+                            fieldName: "login",
+                            arguments: null,
+                          },
+                          {
+                            kind: "InlineFragment",
+                            type: "User",
+                            selections: [
+                              {
+                                kind: "Scalar",
+                                fieldName: "__typename",
+                                arguments: null,
+                              },
+                              {
+                                kind: "Scalar",
+                                fieldName: "id",
+                                arguments: null,
+                              },
+                              {
+                                kind: "Scalar",
+                                fieldName: "twitterUsername",
+                                arguments: null,
+                              },
+                            ],
+                          },
+                        ],
+                      },
+                      {
+                        kind: "Scalar",
+                        fieldName: "closed",
+                        arguments: null,
+                      },
+                      {
+                        kind: "Scalar",
+                        fieldName: "createdAt",
+                        arguments: null,
+                      },
+                      {
+                        kind: "Scalar",
+                        fieldName: "number",
+                        arguments: null,
+                      },
+                      {
+                        kind: "Linked",
+                        fieldName: "repository",
+                        arguments: null,
+                        concreteType: "Repository",
+                        selections: [
+                          {
+                            kind: "Scalar",
+                            fieldName: "id",
+                            arguments: null,
+                          },
+                          {
+                            kind: "Scalar",
+                            fieldName: "name",
+                            arguments: null,
+                          },
+                          {
+                            kind: "Linked",
+                            fieldName: "owner",
+                            arguments: null,
+                            concreteType: null,
+                            selections: [
+                              {
+                                kind: "Scalar",
+                                fieldName: "__typename",
+                                arguments: null,
+                              },
+                              {
+                                kind: "Scalar",
+                                fieldName: "id",
+                                arguments: null,
+                              },
+                              {
+                                kind: "Scalar",
+                                fieldName: "login",
+                                arguments: null,
+                              },
+                            ],
+                          },
+                        ],
+                      },
+                      {
+                        kind: "Scalar",
+                        fieldName: "title",
+                        arguments: null,
+                      },
+                      {
+                        kind: "Scalar",
+                        fieldName: "totalCommentsCount",
+                        arguments: null,
+                      },
+                    ],
+                  },
+                ],
+              },
+              {
+                kind: "Linked",
+                fieldName: "pageInfo",
+                arguments: null,
+                concreteType: "PageInfo",
+                selections: [
+                  {
+                    kind: "Scalar",
+                    fieldName: "hasPreviousPage",
+                    arguments: null,
+                  },
+                  {
+                    kind: "Scalar",
+                    fieldName: "startCursor",
+                    arguments: null,
+                  },
+                ],
+              },
+            ],
+          },
+        ],
+      },
+    ],
+  },
+];
+const artifact: RefetchQueryNormalizationArtifact = {
+  kind: "RefetchQuery",
+  networkRequestInfo: {
+    kind: "NetworkRequestInfo",
+    queryText,
+    normalizationAst,
+  },
+  concreteType: "Query",
+};
+
+export default artifact;
@@ -2,7 +2,10 @@ import type {IsographEntrypoint, NormalizationAst, RefetchQueryNormalizationArti
 import {Query__RepositoryPage__param} from './param_type';
 import {Query__RepositoryPage__output_type} from './output_type';
 import readerResolver from './resolver_reader';
-const nestedRefetchQueries: RefetchQueryNormalizationArtifactWrapper[] = [];
+import refetchQuery0 from './__refetch__0';
+const nestedRefetchQueries: RefetchQueryNormalizationArtifactWrapper[] = [
+  { artifact: refetchQuery0, allowedVariables: ["isograph__before", "isograph__last", "isograph__refetch__id", ] },
+];
 
 const queryText = 'query RepositoryPage ($repositoryName: String!, $repositoryOwner: String!, $first: Int!) {\
   repository____name___v_repositoryName____owner___v_repositoryOwner: repository(name: $repositoryName, owner: $repositoryOwner) {\
@@ -20,14 +23,15 @@ const queryText = 'query RepositoryPage ($repositoryName: String!, $repositoryOw
     },\
     pullRequests____last___v_first: pullRequests(last: $first) {\
       edges {\
+        cursor,\
         node {\
           id,\
           author {\
             __typename,\
             login,\
             ... on User {\
-              id,\
               __typename,\
+              id,\
               twitterUsername,\
             },\
           },\
@@ -47,6 +51,10 @@ const queryText = 'query RepositoryPage ($repositoryName: String!, $repositoryOw
           totalCommentsCount,\
         },\
       },\
+      pageInfo {\
+        hasPreviousPage,\
+        startCursor,\
+      },\
     },\
     stargazerCount,\
     viewerHasStarred,\
@@ -148,6 +156,11 @@ const normalizationAst: NormalizationAst = [
             arguments: null,
             concreteType: "PullRequestEdge",
             selections: [
+              {
+                kind: "Scalar",
+                fieldName: "cursor",
+                arguments: null,
+              },
               {
                 kind: "Linked",
                 fieldName: "node",
@@ -181,12 +194,12 @@ const normalizationAst: NormalizationAst = [
                         selections: [
                           {
                             kind: "Scalar",
-                            fieldName: "id",
+                            fieldName: "__typename",
                             arguments: null,
                           },
                           {
                             kind: "Scalar",
-                            fieldName: "__typename",
+                            fieldName: "id",
                             arguments: null,
                           },
                           {
@@ -268,6 +281,24 @@ const normalizationAst: NormalizationAst = [
               },
             ],
           },
+          {
+            kind: "Linked",
+            fieldName: "pageInfo",
+            arguments: null,
+            concreteType: "PageInfo",
+            selections: [
+              {
+                kind: "Scalar",
+                fieldName: "hasPreviousPage",
+                arguments: null,
+              },
+              {
+                kind: "Scalar",
+                fieldName: "startCursor",
+                arguments: null,
+              },
+            ],
+          },
         ],
       },
       {
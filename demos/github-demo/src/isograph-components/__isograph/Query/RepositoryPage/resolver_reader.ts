@@ -32,7 +32,7 @@ const readerAst: ReaderAst<Query__RepositoryPage__param> = [
       ],
     ],
     readerArtifact: Query__RepositoryDetail__resolver_reader,
-    usedRefetchQueries: [],
+    usedRefetchQueries: [0, ],
   },
 ];
 
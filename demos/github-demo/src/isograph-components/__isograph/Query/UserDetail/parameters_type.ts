@@ -1,3 +1,3 @@
 export type Query__UserDetail__parameters = {
-  readonly userLogin?: string | null | void,
+  readonly userLogin: string,
 };
@@ -4,8 +4,8 @@ import {User__RepositoryConnection__output_type} from './output_type';
 import readerResolver from './resolver_reader';
 const nestedRefetchQueries: RefetchQueryNormalizationArtifactWrapper[] = [];
 
-const queryText = 'query RepositoryConnection ($first: Int, $after: String, $id: ID!) {\
-  node____id___v_id: node(id: $id) {\
+const queryText = 'query RepositoryConnection ($first: Int, $after: String, $isograph__refetch__id: ID!) {\
+  node____id___v_isograph__refetch__id: node(id: $isograph__refetch__id) {\
     ... on User {\
       __typename,\
       id,\
@@ -47,7 +47,7 @@ const normalizationAst: NormalizationAst = [
     arguments: [
       [
         "id",
-        { kind: "Variable", name: "id" },
+        { kind: "Variable", name: "isograph__refetch__id" },
       ],
     ],
     concreteType: null,
@@ -30,6 +30,9 @@ const readerAst: ReaderAst<User__RepositoryList__param> = [
         arguments: null,
       },
     ],
+    refetchFieldMap: [
+      { from: "id", to: "isograph__refetch__id" },
+    ],
     entrypoint: User__RepositoryConnection__entrypoint,
   },
 ];
@@ -4,8 +4,8 @@ import {AdItem__AdItemDisplay__output_type} from './output_type';
 import readerResolver from './resolver_reader';
 const nestedRefetchQueries: RefetchQueryNormalizationArtifactWrapper[] = [];
 
-const queryText = 'query AdItemDisplay ($id: ID!) {\
-  node____id___v_id: node(id: $id) {\
+const queryText = 'query AdItemDisplay ($isograph__refetch__id: ID!) {\
+  node____id___v_isograph__refetch__id: node(id: $isograph__refetch__id) {\
     ... on AdItem {\
       __typename,\
       id,\
@@ -22,7 +22,7 @@ const normalizationAst: NormalizationAst = [
     arguments: [
       [
         "id",
-        { kind: "Variable", name: "id" },
+        { kind: "Variable", name: "isograph__refetch__id" },
       ],
     ],
     concreteType: null,
@@ -16,6 +16,9 @@ const readerAst: ReaderAst<AdItem__AdItemDisplayWrapper__param> = [
         arguments: null,
       },
     ],
+    refetchFieldMap: [
+      { from: "id", to: "isograph__refetch__id" },
+    ],
     entrypoint: { 
       kind: "EntrypointLoader",
       typeAndField: "AdItem__AdItemDisplay",
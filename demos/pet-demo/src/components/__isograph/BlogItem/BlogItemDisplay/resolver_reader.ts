@@ -35,6 +35,9 @@ const readerAst: ReaderAst<BlogItem__BlogItemDisplay__param> = [
         arguments: null,
       },
     ],
+    refetchFieldMap: [
+      { from: "id", to: "isograph__refetch__id" },
+    ],
     entrypoint: { 
       kind: "EntrypointLoader",
       typeAndField: "BlogItem__BlogItemMoreDetail",
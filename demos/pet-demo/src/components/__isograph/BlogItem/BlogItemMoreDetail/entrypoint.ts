@@ -4,8 +4,8 @@ import {BlogItem__BlogItemMoreDetail__output_type} from './output_type';
 import readerResolver from './resolver_reader';
 const nestedRefetchQueries: RefetchQueryNormalizationArtifactWrapper[] = [];
 
-const queryText = 'query BlogItemMoreDetail ($id: ID!) {\
-  node____id___v_id: node(id: $id) {\
+const queryText = 'query BlogItemMoreDetail ($isograph__refetch__id: ID!) {\
+  node____id___v_isograph__refetch__id: node(id: $isograph__refetch__id) {\
     ... on BlogItem {\
       __typename,\
       id,\
@@ -21,7 +21,7 @@ const normalizationAst: NormalizationAst = [
     arguments: [
       [
         "id",
-        { kind: "Variable", name: "id" },
+        { kind: "Variable", name: "isograph__refetch__id" },
       ],
     ],
     concreteType: null,
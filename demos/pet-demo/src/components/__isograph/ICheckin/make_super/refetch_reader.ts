@@ -1,6 +1,6 @@
 import type { RefetchReaderArtifact, ReaderAst, RefetchQueryNormalizationArtifact } from '@isograph/react';
 const includeReadOutData = (variables: any, readOutData: any) => {
-  variables.checkin_id = readOutData.id;
+  variables.isograph__refetch__checkin_id = readOutData.id;
   return variables;
 };
 
@@ -16,6 +16,9 @@ const readerAst: ReaderAst<Image__ImageDisplayWrapper__param> = [
         arguments: null,
       },
     ],
+    refetchFieldMap: [
+      { from: "id", to: "isograph__refetch__id" },
+    ],
     entrypoint: { 
       kind: "EntrypointLoader",
       typeAndField: "Image__ImageDisplay",
@@ -4,11 +4,11 @@ import {Pet__PetCheckinsCard__output_type} from './output_type';
 import readerResolver from './resolver_reader';
 import refetchQuery0 from './__refetch__0';
 const nestedRefetchQueries: RefetchQueryNormalizationArtifactWrapper[] = [
-  { artifact: refetchQuery0, allowedVariables: ["checkin_id", ] },
+  { artifact: refetchQuery0, allowedVariables: ["isograph__refetch__checkin_id", ] },
 ];
 
-const queryText = 'query PetCheckinsCard ($skip: Int, $limit: Int, $id: ID!) {\
-  node____id___v_id: node(id: $id) {\
+const queryText = 'query PetCheckinsCard ($skip: Int, $limit: Int, $isograph__refetch__id: ID!) {\
+  node____id___v_isograph__refetch__id: node(id: $isograph__refetch__id) {\
     ... on Pet {\
       __typename,\
       id,\
@@ -28,7 +28,7 @@ const normalizationAst: NormalizationAst = [
     arguments: [
       [
         "id",
-        { kind: "Variable", name: "id" },
+        { kind: "Variable", name: "isograph__refetch__id" },
       ],
     ],
     concreteType: null,
@@ -23,6 +23,9 @@ const readerAst: ReaderAst<Pet__PetDetailDeferredRouteInnerComponent__param> = [
         arguments: null,
       },
     ],
+    refetchFieldMap: [
+      { from: "id", to: "isograph__refetch__id" },
+    ],
     entrypoint: Pet__PetCheckinsCard__entrypoint,
   },
 ];
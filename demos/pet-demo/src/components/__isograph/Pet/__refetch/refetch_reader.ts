@@ -1,6 +1,6 @@
 import type { RefetchReaderArtifact, ReaderAst, RefetchQueryNormalizationArtifact } from '@isograph/react';
 const includeReadOutData = (variables: any, readOutData: any) => {
-  variables.id = readOutData.id;
+  variables.isograph__refetch__id = readOutData.id;
   return variables;
 };
 
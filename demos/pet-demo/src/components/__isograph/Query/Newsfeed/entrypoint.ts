@@ -10,12 +10,12 @@ const queryText = 'query Newsfeed  {\
     newsfeed____skip___l_0____limit___l_6: newsfeed(skip: 0, limit: 6) {\
       __typename,\
       ... on AdItem {\
-        id,\
         __typename,\
+        id,\
       },\
       ... on BlogItem {\
-        id,\
         __typename,\
+        id,\
         author,\
         content,\
         image {\
@@ -66,12 +66,12 @@ const normalizationAst: NormalizationAst = [
             selections: [
               {
                 kind: "Scalar",
-                fieldName: "id",
+                fieldName: "__typename",
                 arguments: null,
               },
               {
                 kind: "Scalar",
-                fieldName: "__typename",
+                fieldName: "id",
                 arguments: null,
               },
             ],
@@ -82,12 +82,12 @@ const normalizationAst: NormalizationAst = [
             selections: [
               {
                 kind: "Scalar",
-                fieldName: "id",
+                fieldName: "__typename",
                 arguments: null,
               },
               {
                 kind: "Scalar",
-                fieldName: "__typename",
+                fieldName: "id",
                 arguments: null,
               },
               {
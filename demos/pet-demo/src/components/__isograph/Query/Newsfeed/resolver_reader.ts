@@ -42,6 +42,9 @@ const readerAst: ReaderAst<Query__Newsfeed__param> = [
             arguments: null,
           },
         ],
+        refetchFieldMap: [
+          { from: "id", to: "isograph__refetch__id" },
+        ],
         entrypoint: Viewer__NewsfeedPaginationComponent__entrypoint,
       },
     ],
@@ -4,7 +4,7 @@ import {Query__PetCheckinListRoute__output_type} from './output_type';
 import readerResolver from './resolver_reader';
 import refetchQuery0 from './__refetch__0';
 const nestedRefetchQueries: RefetchQueryNormalizationArtifactWrapper[] = [
-  { artifact: refetchQuery0, allowedVariables: ["checkin_id", ] },
+  { artifact: refetchQuery0, allowedVariables: ["isograph__refetch__checkin_id", ] },
 ];
 
 const queryText = 'query PetCheckinListRoute ($id: ID!) {\
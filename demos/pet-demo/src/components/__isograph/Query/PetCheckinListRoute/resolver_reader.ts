@@ -42,6 +42,9 @@ const readerAst: ReaderAst<Query__PetCheckinListRoute__param> = [
             arguments: null,
           },
         ],
+        refetchFieldMap: [
+          { from: "id", to: "isograph__refetch__id" },
+        ],
         entrypoint: { 
           kind: "EntrypointLoader",
           typeAndField: "Pet__PetCheckinsCardList",
@@ -1,6 +1,6 @@
 import type { IsographEntrypoint, ReaderAst, FragmentReference, NormalizationAst, RefetchQueryNormalizationArtifact } from '@isograph/react';
-const queryText = 'mutation Query__set_best_friend ($id: ID!, $new_best_friend_id: ID!) {\
-  set_pet_best_friend____id___v_id____new_best_friend_id___v_new_best_friend_id: set_pet_best_friend(id: $id, new_best_friend_id: $new_best_friend_id) {\
+const queryText = 'mutation Query__set_best_friend ($isograph__refetch__id: ID!, $new_best_friend_id: ID!) {\
+  set_pet_best_friend____id___v_isograph__refetch__id____new_best_friend_id___v_new_best_friend_id: set_pet_best_friend(id: $isograph__refetch__id, new_best_friend_id: $new_best_friend_id) {\
     pet {\
       id,\
       age,\
@@ -44,7 +44,7 @@ const normalizationAst: NormalizationAst = [
     arguments: [
       [
         "id",
-        { kind: "Variable", name: "id" },
+        { kind: "Variable", name: "isograph__refetch__id" },
       ],
 
       [
@@ -1,6 +1,6 @@
 import type { IsographEntrypoint, ReaderAst, FragmentReference, NormalizationAst, RefetchQueryNormalizationArtifact } from '@isograph/react';
-const queryText = 'mutation Query__make_super ($checkin_id: ID!) {\
-  make_checkin_super____checkin_id___v_checkin_id: make_checkin_super(checkin_id: $checkin_id) {\
+const queryText = 'mutation Query__make_super ($isograph__refetch__checkin_id: ID!) {\
+  make_checkin_super____checkin_id___v_isograph__refetch__checkin_id: make_checkin_super(checkin_id: $isograph__refetch__checkin_id) {\
     checkin {\
       ... on Checkin {\
         __typename,\
@@ -19,7 +19,7 @@ const normalizationAst: NormalizationAst = [
     arguments: [
       [
         "checkin_id",
-        { kind: "Variable", name: "checkin_id" },
+        { kind: "Variable", name: "isograph__refetch__checkin_id" },
       ],
     ],
     concreteType: "MakeCheckinSuperResponse",
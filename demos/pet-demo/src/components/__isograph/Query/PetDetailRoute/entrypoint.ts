@@ -8,10 +8,10 @@ import refetchQuery2 from './__refetch__2';
 import refetchQuery3 from './__refetch__3';
 import refetchQuery4 from './__refetch__4';
 const nestedRefetchQueries: RefetchQueryNormalizationArtifactWrapper[] = [
-  { artifact: refetchQuery0, allowedVariables: ["id", ] },
-  { artifact: refetchQuery1, allowedVariables: ["id", "new_best_friend_id", ] },
+  { artifact: refetchQuery0, allowedVariables: ["isograph__refetch__id", ] },
+  { artifact: refetchQuery1, allowedVariables: ["isograph__refetch__id", "new_best_friend_id", ] },
   { artifact: refetchQuery2, allowedVariables: ["input", ] },
-  { artifact: refetchQuery3, allowedVariables: ["checkin_id", ] },
+  { artifact: refetchQuery3, allowedVariables: ["isograph__refetch__checkin_id", ] },
   { artifact: refetchQuery4, allowedVariables: ["id", ] },
 ];
 
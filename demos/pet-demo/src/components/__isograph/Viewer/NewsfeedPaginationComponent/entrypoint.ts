@@ -4,20 +4,20 @@ import {Viewer__NewsfeedPaginationComponent__output_type} from './output_type';
 import readerResolver from './resolver_reader';
 const nestedRefetchQueries: RefetchQueryNormalizationArtifactWrapper[] = [];
 
-const queryText = 'query NewsfeedPaginationComponent ($skip: Int!, $limit: Int!, $id: ID!) {\
-  node____id___v_id: node(id: $id) {\
+const queryText = 'query NewsfeedPaginationComponent ($skip: Int!, $limit: Int!, $isograph__refetch__id: ID!) {\
+  node____id___v_isograph__refetch__id: node(id: $isograph__refetch__id) {\
     ... on Viewer {\
       __typename,\
       id,\
       newsfeed____skip___v_skip____limit___v_limit: newsfeed(skip: $skip, limit: $limit) {\
         __typename,\
         ... on AdItem {\
-          id,\
           __typename,\
+          id,\
         },\
         ... on BlogItem {\
-          id,\
           __typename,\
+          id,\
           author,\
           content,\
           image {\
@@ -37,7 +37,7 @@ const normalizationAst: NormalizationAst = [
     arguments: [
       [
         "id",
-        { kind: "Variable", name: "id" },
+        { kind: "Variable", name: "isograph__refetch__id" },
       ],
     ],
     concreteType: null,
@@ -83,12 +83,12 @@ const normalizationAst: NormalizationAst = [
                 selections: [
                   {
                     kind: "Scalar",
-                    fieldName: "id",
+                    fieldName: "__typename",
                     arguments: null,
                   },
                   {
                     kind: "Scalar",
-                    fieldName: "__typename",
+                    fieldName: "id",
                     arguments: null,
                   },
                 ],
@@ -99,12 +99,12 @@ const normalizationAst: NormalizationAst = [
                 selections: [
                   {
                     kind: "Scalar",
-                    fieldName: "id",
+                    fieldName: "__typename",
                     arguments: null,
                   },
                   {
                     kind: "Scalar",
-                    fieldName: "__typename",
+                    fieldName: "id",
                     arguments: null,
                   },
                   {
@@ -75,6 +75,10 @@ export type MissingFieldHandler = (
   variables: Variables | null,
 ) => Link | undefined;
 
+/**
+ * If the query contains @defer or @stream, the response may contain the
+ * payloads received after the initial response in an incremental array.
+ */
 export type IsographNetworkFunction = (
   queryText: string,
   variables: Variables,
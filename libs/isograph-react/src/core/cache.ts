@@ -17,13 +17,14 @@ import {
 import {
   IsographEntrypoint,
   NormalizationAst,
+  NormalizationDeferredFragment,
   NormalizationInlineFragment,
   NormalizationLinkedField,
   NormalizationScalarField,
   RefetchQueryNormalizationArtifactWrapper,
 } from '../core/entrypoint';
 import { ReaderLinkedField, ReaderScalarField, type ReaderAst } from './reader';
-import { Argument, ArgumentValue } from './util';
+import { Argument, ArgumentValue, SelectionConditions } from './util';
 import { WithEncounteredRecords, readButDoNotEvaluate } from './read';
 import {
   FragmentReference,
@@ -140,6 +141,25 @@ export type NetworkResponseObject = {
   __typename?: TypeName;
 };
 
+/**
+ * A payload received after the initial response of a query containing @defer
+ * or @stream. It contains either the data of the deferred fragment with the
+ * given label, in which case the path is the path to the object containing the
+ * fragment, or more items of a streamed list, in which case the path is the
+ * path to the first of those items.
+ */
+export type IncrementalPayload =
+  | {
+      readonly label: string;
+      readonly path: ReadonlyArray<string | number>;
+      readonly data: NetworkResponseObject;
+    }
+  | {
+      readonly label: string;
+      readonly path: ReadonlyArray<string | number>;
+      readonly items: ReadonlyArray<NetworkResponseObject | null>;
+    };
+
 export function normalizeData(
   environment: IsographEnvironment,
   normalizationAst: NormalizationAst,
@@ -181,6 +201,320 @@ export function normalizeData(
   return encounteredIds;
 }
 
+export function normalizeIncrementalPayload(
+  environment: IsographEnvironment,
+  normalizationAst: NormalizationAst,
+  payload: IncrementalPayload,
+  variables: Variables,
+  nestedRefetchQueries: RefetchQueryNormalizationArtifactWrapper[],
+  root: Link,
+): EncounteredIds {
+  const encounteredIds: EncounteredIds = new Map();
+
+  if ('items' in payload) {
+    const pathToParent = payload.path.slice(0, -2);
+    const listFieldKey = payload.path[payload.path.length - 2];
+    const firstItemIndex = payload.path[payload.path.length - 1];
+    if (
+      typeof listFieldKey !== 'string' ||
+      typeof firstItemIndex !== 'number'
+    ) {
+      throw new Error(
+        'Unexpected path ' +
+          payload.path.join('.') +
+          ' of streamed items with label ' +
+          payload.label,
+      );
+    }
+    const parent = getIncrementalPayloadParent(
+      environment,
+      normalizationAst,
+      pathToParent,
+      variables,
+      root,
+    );
+    const astNode = findLinkedField(parent.selections, listFieldKey);
+    if (astNode == null) {
+      throw new Error(
+        'Unexpected streamed items with label ' +
+          payload.label +
+          '. This is indicative of a bug in Isograph.',
+      );
+    }
+
+    const parentRecordKey = getParentRecordKey(astNode, variables);
+    const existingValue = parent.record[parentRecordKey];
+    const dataIds: (Link | null)[] = Array.isArray(existingValue)
+      ? existingValue.map((item) => getLink(item))
+      : [];
+    for (let i = 0; i < payload.items.length; i++) {
+      const networkResponseObject = payload.items[i];
+      const index = firstItemIndex + i;
+      if (networkResponseObject == null) {
+        dataIds[index] = null;
+        continue;
+      }
+      const newStoreRecordId = normalizeNetworkResponseObject(
+        environment,
+        astNode,
+        networkResponseObject,
+        parent.link,
+        variables,
+        index,
+        nestedRefetchQueries,
+        encounteredIds,
+      );
+
+      const __typename =
+        astNode.concreteType ?? networkResponseObject[TYPENAME_FIELD_NAME];
+      if (__typename == null) {
+        throw new Error(
+          'Unexpected missing __typename in network response when normalizing a linked field. ' +
+            'This is indicative of a bug in Isograph.',
+        );
+      }
+      dataIds[index] = { __link: newStoreRecordId, __typename };
+    }
+    parent.record[parentRecordKey] = dataIds;
+    insertIfNotExists(encounteredIds, parent.link.__typename).add(
+      parent.link.__link,
+    );
+  } else {
+    const parent = getIncrementalPayloadParent(
+      environment,
+      normalizationAst,
+      payload.path,
+      variables,
+      root,
+    );
+    const deferredFragment = findDeferredFragment(
+      parent.selections,
+      payload.label,
+    );
+    if (deferredFragment == null) {
+      throw new Error(
+        'Unexpected deferred data with label ' +
+          payload.label +
+          '. This is indicative of a bug in Isograph.',
+      );
+    }
+    normalizeDataIntoRecord(
+      environment,
+      deferredFragment.selections,
+      payload.data,
+      parent.record,
+      parent.link,
+      variables,
+      nestedRefetchQueries,
+      encounteredIds,
+    );
+  }
+
+  logMessage(environment, {
+    kind: 'AfterNormalization',
+    store: environment.store,
+    encounteredIds,
+  });
+
+  callSubscriptions(environment, encounteredIds);
+  return encounteredIds;
+}
+
+/**
+ * Find the record that an incremental payload is normalized into, by following
+ * its path from the root of the query.
+ */
+function getIncrementalPayloadParent(
+  environment: IsographEnvironment,
+  normalizationAst: NormalizationAst,
+  path: ReadonlyArray<string | number>,
+  variables: Variables,
+  root: Link,
+): { selections: NormalizationAst; record: StoreRecord; link: Link } {
+  let selections = normalizationAst;
+  let link = root;
+  for (let i = 0; i < path.length; i++) {
+    const networkResponseKey = path[i];
+    const astNode =
+      typeof networkResponseKey === 'string'
+        ? findLinkedField(selections, networkResponseKey)
+        : null;
+    if (astNode == null) {
+      throw new Error(
+        'Unexpected path ' +
+          path.join('.') +
+          ' of incremental payload. This is indicative of a bug in Isograph.',
+      );
+    }
+    let value =
+      environment.store[link.__typename]?.[link.__link]?.[
+        getParentRecordKey(astNode, variables)
+      ];
+    const index = path[i + 1];
+    if (typeof index === 'number') {
+      value = Array.isArray(value) ? value[index] : undefined;
+      i++;
+    }
+    const nextLink = getLink(value);
+    if (nextLink == null) {
+      throw new Error(
+        'Expected a record at ' +
+          path.slice(0, i + 1).join('.') +
+          ' when normalizing an incremental payload.',
+      );
+    }
+    link = nextLink;
+    selections = astNode.selections;
+  }
+
+  const record = environment.store[link.__typename]?.[link.__link];
+  if (record == null) {
+    throw new Error(
+      'Expected a record at ' +
+        path.join('.') +
+        ' when normalizing an incremental payload.',
+    );
+  }
+  return { selections, record, link };
+}
+
+function findLinkedField(
+  selections: NormalizationAst,
+  networkResponseKey: string,
+): NormalizationLinkedField | null {
+  for (const selection of selections) {
+    switch (selection.kind) {
+      case 'Linked': {
+        if (getNetworkResponseKey(selection) === networkResponseKey) {
+          return selection;
+        }
+        break;
+      }
+      case 'InlineFragment':
+      case 'Defer': {
+        const linkedField = findLinkedField(
+          selection.selections,
+          networkResponseKey,
+        );
+        if (linkedField != null) {
+          return linkedField;
+        }
+        break;
+      }
+    }
+  }
+  return null;
+}
+
+function findDeferredFragment(
+  selections: NormalizationAst,
+  label: string,
+): NormalizationDeferredFragment | null {
+  for (const selection of selections) {
+    switch (selection.kind) {
+      case 'Defer': {
+        if (selection.label === label) {
+          return selection;
+        }
+        const deferredFragment = findDeferredFragment(
+          selection.selections,
+          label,
+        );
+        if (deferredFragment != null) {
+          return deferredFragment;
+        }
+        break;
+      }
+      case 'InlineFragment': {
+        const deferredFragment = findDeferredFragment(
+          selection.selections,
+          label,
+        );
+        if (deferredFragment != null) {
+          return deferredFragment;
+        }
+        break;
+      }
+    }
+  }
+  return null;
+}
+
+/**
+ * Merge a page of a connection selected with @paginated (stored at pageKey on
+ * the parent record) into the connection (stored at connectionKey), by adding
+ * the page's edges to the end (or, when paginating backward, the start) of the
+ * connection's edges and replacing its pageInfo.
+ */
+export function mergeConnectionPage(
+  environment: IsographEnvironment,
+  parentLink: Link,
+  connectionKey: string,
+  pageKey: string,
+  direction: 'Forward' | 'Backward',
+) {
+  const parentRecord =
+    environment.store[parentLink.__typename]?.[parentLink.__link];
+  const connectionLink = getLink(parentRecord?.[connectionKey]);
+  const pageLink = getLink(parentRecord?.[pageKey]);
+  if (
+    connectionLink == null ||
+    pageLink == null ||
+    (connectionLink.__typename === pageLink.__typename &&
+      connectionLink.__link === pageLink.__link)
+  ) {
+    return;
+  }
+
+  const connection =
+    environment.store[connectionLink.__typename]?.[connectionLink.__link];
+  const page = environment.store[pageLink.__typename]?.[pageLink.__link];
+  if (connection == null || page == null) {
+    return;
+  }
+
+  const existingEdges = Array.isArray(connection.edges) ? connection.edges : [];
+  const pageEdges = Array.isArray(page.edges) ? page.edges : [];
+  connection.edges =
+    direction === 'Forward'
+      ? [...existingEdges, ...pageEdges]
+      : [...pageEdges, ...existingEdges];
+  connection.pageInfo = page.pageInfo;
+
+  const encounteredIds: EncounteredIds = new Map();
+  insertIfNotExists(encounteredIds, connectionLink.__typename).add(
+    connectionLink.__link,
+  );
+  callSubscriptions(environment, encounteredIds);
+}
+
+/**
+ * Write a field selected in an @updatable selection set to the store, and
+ * notify the subscriptions that read the record.
+ */
+export function writeUpdatableField(
+  environment: IsographEnvironment,
+  link: Link,
+  storeRecordName: string,
+  value: DataTypeValue,
+) {
+  const record = environment.store[link.__typename]?.[link.__link];
+  if (record == null) {
+    throw new Error(
+      'Cannot update ' +
+        storeRecordName +
+        ' on ' +
+        link.__link +
+        ', because the record does not exist.',
+    );
+  }
+  record[storeRecordName] = value;
+
+  const encounteredIds: EncounteredIds = new Map();
+  insertIfNotExists(encounteredIds, link.__typename).add(link.__link);
+  callSubscriptions(environment, encounteredIds);
+}
+
 export function subscribeToAnyChange(
   environment: IsographEnvironment,
   callback: () => void,
@@ -386,6 +720,14 @@ function normalizeDataIntoRecord(
 ): RecordHasBeenUpdated {
   let recordHasBeenUpdated = false;
   for (const normalizationNode of normalizationAst) {
+    if (
+      normalizationNode.kind !== 'InlineFragment' &&
+      normalizationNode.kind !== 'Defer' &&
+      !selectionConditionsHold(normalizationNode.conditions, variables)
+    ) {
+      // The field was skipped, so it is absent from the network response.
+      continue;
+    }
     switch (normalizationNode.kind) {
       case 'Scalar': {
         const scalarFieldResultedInChange = normalizeScalarField(
@@ -428,6 +770,32 @@ function normalizeDataIntoRecord(
           recordHasBeenUpdated || inlineFragmentResultedInChange;
         break;
       }
+      case 'Defer': {
+        // Unless the server chose not to defer the fragment, its data is
+        // received in an incremental payload instead.
+        if (
+          !deferredFragmentIsInResponse(
+            normalizationNode,
+            networkResponseParentRecord,
+            variables,
+          )
+        ) {
+          break;
+        }
+        const deferredFragmentResultedInChange = normalizeDataIntoRecord(
+          environment,
+          normalizationNode.selections,
+          networkResponseParentRecord,
+          targetParentRecord,
+          targetParentRecordLink,
+          variables,
+          nestedRefetchQueries,
+          mutableEncounteredIds,
+        );
+        recordHasBeenUpdated =
+          recordHasBeenUpdated || deferredFragmentResultedInChange;
+        break;
+      }
       default: {
         // Ensure we have covered all variants
         let _: never = normalizationNode;
@@ -608,6 +976,27 @@ function normalizeInlineFragment(
   return false;
 }
 
+function deferredFragmentIsInResponse(
+  astNode: NormalizationDeferredFragment,
+  networkResponseParentRecord: NetworkResponseObject,
+  variables: Variables,
+): boolean {
+  return astNode.selections.every((selection) => {
+    switch (selection.kind) {
+      case 'Scalar':
+      case 'Linked':
+        return (
+          !selectionConditionsHold(selection.conditions, variables) ||
+          networkResponseParentRecord[getNetworkResponseKey(selection)] !==
+            undefined
+        );
+      case 'InlineFragment':
+      case 'Defer':
+        return true;
+    }
+  });
+}
+
 function dataIdsAreTheSame(
   existingValue: DataTypeValue,
   newDataIds: (Link | null)[],
@@ -702,6 +1091,28 @@ function isNullOrEmptyArray(data: unknown): data is never[] | null[] | null {
   return data === null;
 }
 
+/**
+ * Whether a field with @skip and @include conditions is selected, given the
+ * variables with which it is normalized or read.
+ */
+export function selectionConditionsHold(
+  conditions: SelectionConditions | undefined,
+  variables: Variables,
+): boolean {
+  if (conditions == null) {
+    return true;
+  }
+  return conditions.every((condition) => {
+    const value =
+      condition.if.kind === 'Variable'
+        ? variables[condition.if.name]
+        : condition.if.kind === 'Literal'
+          ? condition.if.value
+          : null;
+    return condition.kind === 'Include' ? value === true : value !== true;
+  });
+}
+
 export function getParentRecordKey(
   astNode:
     | NormalizationLinkedField
@@ -810,12 +1221,15 @@ function getDataIdOfNetworkResponse(
     return ROOT_ID;
   }
 
-  // Check whether the dataToNormalize has an id field. If so, that is the key.
+  // Check whether the dataToNormalize has a strong id field. If so, that is the key.
   // If not, we construct an id from the parentRecordId and the field parameters.
 
-  const dataId = dataToNormalize.id;
+  const strongIdField =
+    astNode.strongIdField === undefined ? 'id' : astNode.strongIdField;
+  const dataId =
+    strongIdField == null ? null : dataToNormalize[strongIdField];
   if (dataId != null) {
-    return dataId;
+    return dataId as DataId;
   }
 
   let storeKey = `${parentRecordLink.__typename}:${parentRecordLink.__link}.${astNode.fieldName}`;
@@ -1,4 +1,4 @@
-import { getParentRecordKey } from './cache';
+import { getParentRecordKey, selectionConditionsHold } from './cache';
 import { NormalizationAst } from './entrypoint';
 import { Variables } from './FragmentReference';
 import {
@@ -59,6 +59,13 @@ function checkFromRecord(
   recordLink: Link,
 ): CheckResult {
   normalizationAstLoop: for (const normalizationAstNode of normalizationAst) {
+    if (
+      normalizationAstNode.kind !== 'InlineFragment' &&
+      normalizationAstNode.kind !== 'Defer' &&
+      !selectionConditionsHold(normalizationAstNode.conditions, variables)
+    ) {
+      continue normalizationAstLoop;
+    }
     switch (normalizationAstNode.kind) {
       case 'Scalar': {
         const parentRecordKey = getParentRecordKey(
@@ -191,6 +198,21 @@ function checkFromRecord(
 
         continue normalizationAstLoop;
       }
+      case 'Defer': {
+        const result = checkFromRecord(
+          environment,
+          normalizationAstNode.selections,
+          variables,
+          record,
+          recordLink,
+        );
+
+        if (result.kind === 'MissingData') {
+          return result;
+        }
+
+        continue normalizationAstLoop;
+      }
       default: {
         let _: never = normalizationAstNode;
         _;
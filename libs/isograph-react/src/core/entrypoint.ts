@@ -1,6 +1,6 @@
 import type { TypeName } from './IsographEnvironment';
 import { TopLevelReaderArtifact } from './reader';
-import { Arguments } from './util';
+import { Arguments, SelectionConditions } from './util';
 
 export type ReaderWithRefetchQueries<
   TReadFromStore extends { parameters: object; data: object },
@@ -49,13 +49,15 @@ export type IsographEntrypointLoader<
 export type NormalizationAstNode =
   | NormalizationScalarField
   | NormalizationLinkedField
-  | NormalizationInlineFragment;
+  | NormalizationInlineFragment
+  | NormalizationDeferredFragment;
 export type NormalizationAst = ReadonlyArray<NormalizationAstNode>;
 
 export type NormalizationScalarField = {
   readonly kind: 'Scalar';
   readonly fieldName: string;
   readonly arguments: Arguments | null;
+  readonly conditions?: SelectionConditions;
 };
 
 export type NormalizationLinkedField = {
@@ -64,6 +66,22 @@ export type NormalizationLinkedField = {
   readonly arguments: Arguments | null;
   readonly selections: NormalizationAst;
   readonly concreteType: TypeName | null;
+  /**
+   * The field that uniquely identifies the object. If absent, the object is
+   * identified by its id field. If null, the object has no such field.
+   */
+  readonly strongIdField?: string | null;
+  readonly conditions?: SelectionConditions;
+  /**
+   * Present if the field is selected with @stream. Items after the first
+   * initialCount items are received in incremental payloads with this label.
+   */
+  readonly stream?: NormalizationStream;
+};
+
+export type NormalizationStream = {
+  readonly label: string;
+  readonly initialCount: number;
 };
 
 export type NormalizationInlineFragment = {
@@ -72,6 +90,16 @@ export type NormalizationInlineFragment = {
   readonly selections: NormalizationAst;
 };
 
+/**
+ * Selections made with @defer. These are received in an incremental payload
+ * with this label, unless the server chose not to defer them.
+ */
+export type NormalizationDeferredFragment = {
+  readonly kind: 'Defer';
+  readonly label: string;
+  readonly selections: NormalizationAst;
+};
+
 // This is more like an entrypoint, but one specifically for a refetch query/mutation
 export type RefetchQueryNormalizationArtifact = {
   readonly kind: 'RefetchQuery';
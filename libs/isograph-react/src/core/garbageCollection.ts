@@ -165,6 +165,15 @@ function recordReachableIdsFromRecord(
           }
         }
 
+        continue;
+      case 'Defer':
+        recordReachableIdsFromRecord(
+          store,
+          currentRecord,
+          mutableRetainedIds,
+          selection.selections,
+          variables,
+        );
         continue;
       case 'Scalar':
         continue;
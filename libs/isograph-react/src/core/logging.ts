@@ -73,6 +73,11 @@ export type LogMessage =
       arguments: Arguments | null;
       variables: Variables;
     }
+  | {
+      kind: 'RequiredFieldIsNull';
+      root: Link;
+      fieldName: string;
+    }
   | {
       kind: 'DoneReading';
       response: ReadDataResult<any>;
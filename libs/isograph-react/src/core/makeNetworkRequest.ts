@@ -17,7 +17,7 @@ import {
   wrapPromise,
   wrapResolvedValue,
 } from './PromiseWrapper';
-import { normalizeData } from './cache';
+import { normalizeData, normalizeIncrementalPayload } from './cache';
 import { logMessage } from './logging';
 import { check, DEFAULT_SHOULD_FETCH_VALUE, FetchOptions } from './check';
 import { readButDoNotEvaluate } from './read';
@@ -124,6 +124,18 @@ export function makeNetworkRequest<
             : [],
           root,
         );
+        for (const payload of networkResponse.incremental ?? []) {
+          normalizeIncrementalPayload(
+            environment,
+            artifact.networkRequestInfo.normalizationAst,
+            payload,
+            variables,
+            artifact.kind === 'Entrypoint'
+              ? artifact.readerWithRefetchQueries.nestedRefetchQueries
+              : [],
+            root,
+          );
+        }
         const retainedQuery = {
           normalizationAst: artifact.networkRequestInfo.normalizationAst,
           variables,
@@ -1,7 +1,9 @@
 import {
   getParentRecordKey,
   insertIfNotExists,
+  mergeConnectionPage,
   onNextChangeToRecord,
+  selectionConditionsHold,
   type EncounteredIds,
 } from './cache';
 import { getOrCreateCachedComponent } from './componentCache';
@@ -17,11 +19,15 @@ import {
 } from './FragmentReference';
 import {
   assertLink,
+  getLink,
   getOrLoadIsographArtifact,
   IsographEnvironment,
   type Link,
 } from './IsographEnvironment';
-import { maybeMakeNetworkRequest } from './makeNetworkRequest';
+import {
+  makeNetworkRequest,
+  maybeMakeNetworkRequest,
+} from './makeNetworkRequest';
 import {
   getPromiseState,
   PromiseWrapper,
@@ -29,7 +35,11 @@ import {
   wrapPromise,
   wrapResolvedValue,
 } from './PromiseWrapper';
-import { ReaderAst } from './reader';
+import {
+  ReaderAst,
+  type ReaderPagination,
+  type RequiredAction,
+} from './reader';
 import { Arguments } from './util';
 import { logMessage } from './logging';
 import { CleanupFn } from '@isograph/disposable-types';
@@ -112,6 +122,40 @@ export type ReadDataResult<TReadFromStore> =
       readonly recordLink: Link;
     };
 
+/**
+ * A field selected with @required, or a semantically non-null field, is null.
+ * Either throw, or make the record containing the field null.
+ */
+function readNullRequiredField<TReadFromStore>(
+  environment: IsographEnvironment,
+  required: RequiredAction,
+  storeRecordName: string,
+  root: Link,
+  mutableEncounteredRecords: EncounteredIds,
+): ReadDataResult<TReadFromStore> {
+  if (required === 'THROW') {
+    throw new Error(
+      'Required field ' +
+        storeRecordName +
+        ' on root ' +
+        root.__link +
+        ' is null.',
+    );
+  }
+  if (required === 'LOG') {
+    logMessage(environment, {
+      kind: 'RequiredFieldIsNull',
+      root,
+      fieldName: storeRecordName,
+    });
+  }
+  return {
+    kind: 'Success',
+    data: null as any,
+    encounteredRecords: mutableEncounteredRecords,
+  };
+}
+
 function readData<TReadFromStore>(
   environment: IsographEnvironment,
   ast: ReaderAst<TReadFromStore>,
@@ -147,12 +191,20 @@ function readData<TReadFromStore>(
   let target: { [index: string]: any } = {};
 
   for (const field of ast) {
+    if (!selectionConditionsHold(field.conditions, variables)) {
+      // Fields that are not selected are absent, rather than missing.
+      continue;
+    }
     switch (field.kind) {
       case 'Scalar': {
         const storeRecordName = getParentRecordKey(field, variables);
-        const value = storeRecord[storeRecordName];
+        let value = storeRecord[storeRecordName];
         // TODO consider making scalars into discriminated unions. This probably has
         // to happen for when we handle errors.
+        if (value === undefined && field.isClientOnly) {
+          // Client-only fields are never fetched, so an unset value is null.
+          value = null;
+        }
         if (value === undefined) {
           return {
             kind: 'MissingData',
@@ -161,6 +213,15 @@ function readData<TReadFromStore>(
             recordLink: root,
           };
         }
+        if (value === null && field.required != null) {
+          return readNullRequiredField(
+            environment,
+            field.required,
+            storeRecordName,
+            root,
+            mutableEncounteredRecords,
+          );
+        }
         target[field.alias ?? field.fieldName] = value;
         break;
       }
@@ -255,6 +316,11 @@ function readData<TReadFromStore>(
           }
         }
 
+        if (link === undefined && field.isClientOnly) {
+          // Client-only fields are never fetched, so an unset link is null.
+          link = null;
+        }
+
         if (link === undefined) {
           // TODO make this configurable, and also generated and derived from the schema
           const missingFieldHandler = environment.missingFieldHandler;
@@ -291,6 +357,15 @@ function readData<TReadFromStore>(
             link = altLink;
           }
         } else if (link === null) {
+          if (field.required != null) {
+            return readNullRequiredField(
+              environment,
+              field.required,
+              storeRecordName,
+              root,
+              mutableEncounteredRecords,
+            );
+          }
           target[field.alias ?? field.fieldName] = null;
           break;
         }
@@ -314,6 +389,66 @@ function readData<TReadFromStore>(
             recordLink: data.recordLink,
           };
         }
+        // The linked record is null if it contains a null field selected with
+        // @required(action: LOG) or @required(action: NONE).
+        if (data.data === null && field.required != null) {
+          return readNullRequiredField(
+            environment,
+            field.required,
+            storeRecordName,
+            root,
+            mutableEncounteredRecords,
+          );
+        }
+        const pagination = field.pagination;
+        if (pagination != null) {
+          // Read what is needed to refetch the parent (e.g. its id), so that
+          // loadMore can fetch the next page.
+          const refetchReaderParams = readData(
+            environment,
+            pagination.refetchReaderAst,
+            root,
+            variables,
+            [],
+            networkRequest,
+            networkRequestOptions,
+            mutableEncounteredRecords,
+          );
+          if (refetchReaderParams.kind === 'MissingData') {
+            return {
+              kind: 'MissingData',
+              reason:
+                'Missing data for ' +
+                storeRecordName +
+                ' on root ' +
+                root.__link,
+              nestedReason: refetchReaderParams,
+              recordLink: refetchReaderParams.recordLink,
+            };
+          }
+          const refetchQuery = nestedRefetchQueries[pagination.refetchQuery];
+          if (refetchQuery == null) {
+            throw new Error(
+              'refetchQuery is null in paginated Linked field. This is indicative of a bug in Isograph.',
+            );
+          }
+          target[field.alias ?? field.fieldName] = {
+            ...data.data,
+            loadMore: (count: number) =>
+              loadMorePaginatedField(
+                environment,
+                root,
+                field.fieldName,
+                storeRecordName,
+                pagination,
+                refetchQuery,
+                refetchReaderParams.data,
+                variables,
+                count,
+              ),
+          };
+          break;
+        }
         target[field.alias ?? field.fieldName] = data.data;
         break;
       }
@@ -467,9 +602,14 @@ function readData<TReadFromStore>(
             // TODO get the associated type for FetchOptions from the loadably selected field
             fetchOptions?: FetchOptions<any>,
           ) => {
-            // TODO we should use the reader AST for this
+            // Copy each field read by the refetch reader AST (e.g. "owner.login")
+            // into the variable it is passed as (e.g. "owner").
             const includeReadOutData = (variables: any, readOutData: any) => {
-              variables.id = readOutData.id;
+              for (const { from, to } of field.refetchFieldMap) {
+                variables[to] = from
+                  .split('.')
+                  .reduce((value, key) => value?.[key], readOutData);
+              }
               return variables;
             };
             const localVariables = includeReadOutData(
@@ -625,6 +765,88 @@ function readData<TReadFromStore>(
   };
 }
 
+/**
+ * Fetch the next page of a connection selected with @paginated, and append
+ * (or, when paginating backward, prepend) it to the connection in the store.
+ * The cursor is read from the store when loadMore is called, so that calling
+ * it repeatedly fetches consecutive pages.
+ */
+function loadMorePaginatedField(
+  environment: IsographEnvironment,
+  parentLink: Link,
+  fieldName: string,
+  connectionKey: string,
+  pagination: ReaderPagination,
+  refetchQuery: RefetchQueryNormalizationArtifactWrapper,
+  refetchReaderData: any,
+  variables: Variables,
+  count: number,
+): void {
+  const connectionLink = getLink(
+    environment.store[parentLink.__typename]?.[parentLink.__link]?.[
+      connectionKey
+    ] ?? null,
+  );
+  const connection =
+    connectionLink == null
+      ? null
+      : environment.store[connectionLink.__typename]?.[connectionLink.__link];
+  const pageInfoLink = getLink(connection?.pageInfo ?? null);
+  const pageInfo =
+    pageInfoLink == null
+      ? null
+      : environment.store[pageInfoLink.__typename]?.[pageInfoLink.__link];
+  if (pageInfo?.[pagination.hasMoreField] === false) {
+    return;
+  }
+
+  const localVariables: { [index: string]: any } = { ...variables };
+  // Copy each field read by the refetch reader AST (e.g. "owner.login")
+  // into the variable it is passed as (e.g. "owner").
+  for (const { from, to } of pagination.refetchFieldMap) {
+    localVariables[to] = from
+      .split('.')
+      .reduce((value, key) => value?.[key], refetchReaderData);
+  }
+  localVariables[pagination.countVariable] = count;
+  localVariables[pagination.cursorVariable] =
+    pageInfo?.[pagination.pageCursorField] ?? null;
+  const pageVariables = filterVariables(
+    localVariables,
+    refetchQuery.allowedVariables,
+  );
+
+  const pageKey = getParentRecordKey(
+    {
+      kind: 'Scalar',
+      fieldName,
+      alias: null,
+      arguments: pagination.pageArguments,
+    },
+    pageVariables,
+  );
+
+  const [, disposeNetworkRequest] = makeNetworkRequest(
+    environment,
+    refetchQuery.artifact,
+    pageVariables,
+    {
+      onComplete: () => {
+        mergeConnectionPage(
+          environment,
+          parentLink,
+          connectionKey,
+          pageKey,
+          pagination.direction,
+        );
+        // The merged edges are retained by the query that fetched the connection,
+        // so the page's query no longer needs to be retained.
+        disposeNetworkRequest();
+      },
+    },
+  );
+}
+
 function filterVariables(
   variables: Variables,
   allowedVariables: string[],
@@ -15,7 +15,7 @@ import {
   RefetchQueryNormalizationArtifact,
   RefetchQueryNormalizationArtifactWrapper,
 } from './entrypoint';
-import { Arguments } from './util';
+import { Arguments, SelectionConditions } from './util';
 import { FetchOptions } from './check';
 
 export type TopLevelReaderArtifact<
@@ -81,14 +81,34 @@ export type ReaderAstNode =
   | ReaderImperativelyLoadedField
   | ReaderLoadableField;
 
+export type UpdaterArtifact<TUpdatableData> = {
+  readonly kind: 'UpdaterArtifact';
+  readonly readerAst: ReaderAst<TUpdatableData>;
+};
+
 // @ts-ignore
 export type ReaderAst<TReadFromStore> = ReadonlyArray<ReaderAstNode>;
 
+/**
+ * What happens when reading a field selected with @required that is null.
+ * THROW throws an error. LOG and NONE make the record containing the field
+ * null; LOG also logs a message.
+ */
+export type RequiredAction = 'THROW' | 'LOG' | 'NONE';
+
 export type ReaderScalarField = {
   readonly kind: 'Scalar';
   readonly fieldName: string;
   readonly alias: string | null;
   readonly arguments: Arguments | null;
+  // Present if the field is local state, which is never fetched from the server
+  readonly isClientOnly?: boolean;
+  // Present if the field was selected with @required, or is semantically non-null
+  readonly required?: RequiredAction;
+  // Present in updater artifacts if the field was selected within an @updatable
+  // selection set, i.e. it can be written to
+  readonly isUpdatable?: boolean;
+  readonly conditions?: SelectionConditions;
 };
 export type ReaderLinkedField = {
   readonly kind: 'Linked';
@@ -100,6 +120,34 @@ export type ReaderLinkedField = {
     { data: object; parameters: object },
     boolean | Link | null
   > | null;
+  // Present if the field was selected with @paginated
+  readonly pagination?: ReaderPagination;
+  // Present if the field is local state, which is never fetched from the server
+  readonly isClientOnly?: boolean;
+  // Present if the field was selected with @required, or is semantically non-null
+  readonly required?: RequiredAction;
+  readonly conditions?: SelectionConditions;
+};
+
+export type ReaderPagination = {
+  // The index of the query that fetches a page of the connection
+  readonly refetchQuery: number;
+  readonly direction: 'Forward' | 'Backward';
+  // The variables passed as the count and cursor arguments (e.g. first and
+  // after) when fetching a page, e.g. isograph__first and isograph__after
+  readonly countVariable: string;
+  readonly cursorVariable: string;
+  // The fields on pageInfo, e.g. hasNextPage and endCursor
+  readonly hasMoreField: string;
+  readonly pageCursorField: string;
+  // The arguments passed to the connection when fetching a page
+  readonly pageArguments: Arguments | null;
+  // Reads (and maps to variables) what is needed to refetch the connection's parent
+  readonly refetchReaderAst: ReaderAst<any>;
+  readonly refetchFieldMap: ReadonlyArray<{
+    readonly from: string;
+    readonly to: string;
+  }>;
 };
 
 export type ReaderNonLoadableResolverField = {
@@ -109,6 +157,7 @@ export type ReaderNonLoadableResolverField = {
   readonly readerArtifact: TopLevelReaderArtifact<any, any, any>;
   readonly arguments: Arguments | null;
   readonly usedRefetchQueries: number[];
+  readonly conditions?: SelectionConditions;
 };
 
 export type ReaderImperativelyLoadedField = {
@@ -117,6 +166,7 @@ export type ReaderImperativelyLoadedField = {
   readonly refetchReaderArtifact: RefetchReaderArtifact;
   readonly refetchQuery: number;
   readonly name: string;
+  readonly conditions?: SelectionConditions;
 };
 
 export type ReaderLoadableField = {
@@ -129,11 +179,18 @@ export type ReaderLoadableField = {
   readonly name: string;
   readonly queryArguments: Arguments | null;
   readonly refetchReaderAst: ReaderAst<any>;
+  // Which of the fields read by the refetchReaderAst are passed as which
+  // variables to the entrypoint.
+  readonly refetchFieldMap: ReadonlyArray<{
+    readonly from: string;
+    readonly to: string;
+  }>;
 
   // TODO we should not type these as any
   readonly entrypoint:
     | IsographEntrypoint<any, any>
     | IsographEntrypointLoader<any, any>;
+  readonly conditions?: SelectionConditions;
 };
 
 type StableId = string;
@@ -0,0 +1,129 @@
+import {
+  getParentRecordKey,
+  selectionConditionsHold,
+  writeUpdatableField,
+} from './cache';
+import { Variables } from './FragmentReference';
+import {
+  assertLink,
+  type DataTypeValue,
+  IsographEnvironment,
+  type Link,
+} from './IsographEnvironment';
+import { ReaderAst, UpdaterArtifact } from './reader';
+
+/**
+ * Read the updatable data of an updater artifact, starting at root.
+ *
+ * Unlike readButDoNotEvaluate, every field is read from the store when it is
+ * accessed, so the data never goes stale. Assigning to an updatable field writes
+ * the new value to the store and notifies subscribers of the record.
+ */
+export function readUpdatableData<TUpdatableData>(
+  environment: IsographEnvironment,
+  artifact: UpdaterArtifact<TUpdatableData>,
+  root: Link,
+  variables: Variables,
+): TUpdatableData {
+  return readUpdatableRecord(
+    environment,
+    artifact.readerAst,
+    root,
+    variables,
+  ) as TUpdatableData;
+}
+
+function readUpdatableRecord(
+  environment: IsographEnvironment,
+  ast: ReaderAst<unknown>,
+  root: Link,
+  variables: Variables,
+): { [index: string]: any } {
+  const target: { [index: string]: any } = {};
+
+  for (const field of ast) {
+    if (!selectionConditionsHold(field.conditions, variables)) {
+      // Fields that are not selected are absent, rather than missing.
+      continue;
+    }
+    switch (field.kind) {
+      case 'Scalar': {
+        const storeRecordName = getParentRecordKey(field, variables);
+        const isClientOnly = field.isClientOnly === true;
+        Object.defineProperty(target, field.alias ?? field.fieldName, {
+          enumerable: true,
+          get: () =>
+            readStoreValue(environment, root, storeRecordName, isClientOnly),
+          set: field.isUpdatable
+            ? (newValue: DataTypeValue) =>
+                writeUpdatableField(
+                  environment,
+                  root,
+                  storeRecordName,
+                  newValue,
+                )
+            : undefined,
+        });
+        break;
+      }
+      case 'Linked': {
+        const storeRecordName = getParentRecordKey(field, variables);
+        const isClientOnly = field.isClientOnly === true;
+        const readLink = (item: DataTypeValue) => {
+          const link = assertLink(item);
+          if (link == null) {
+            return link;
+          }
+          return readUpdatableRecord(
+            environment,
+            field.selections,
+            link,
+            variables,
+          );
+        };
+        Object.defineProperty(target, field.alias ?? field.fieldName, {
+          enumerable: true,
+          get: () => {
+            const value = readStoreValue(
+              environment,
+              root,
+              storeRecordName,
+              isClientOnly,
+            );
+            return Array.isArray(value) ? value.map(readLink) : readLink(value);
+          },
+        });
+        break;
+      }
+      default: {
+        throw new Error(
+          'Unexpected field kind in updatable data. ' +
+            'This is indicative of a bug in Isograph.',
+        );
+      }
+    }
+  }
+
+  return target;
+}
+
+function readStoreValue(
+  environment: IsographEnvironment,
+  root: Link,
+  storeRecordName: string,
+  isClientOnly: boolean,
+): DataTypeValue {
+  const value = environment.store[root.__typename]?.[root.__link]?.[
+    storeRecordName
+  ];
+  if (value === undefined) {
+    if (isClientOnly) {
+      // Client-only fields are never fetched, so an unset value is null.
+      return null;
+    }
+    throw new Error(
+      'No value for ' + storeRecordName + ' on root ' + root.__link,
+    );
+  }
+  return value;
+}
@@ -25,3 +25,11 @@ export type ArgumentValue =
       readonly kind: 'Enum';
       readonly value: string;
     };
+
+// From @skip and @include. A field with conditions is only fetched and read
+// if all of its conditions hold.
+export type SelectionCondition = {
+  readonly kind: 'Skip' | 'Include';
+  readonly if: ArgumentValue;
+};
+export type SelectionConditions = ReadonlyArray<SelectionCondition>;
@@ -11,7 +11,12 @@ export {
   wrapResolvedValue,
   wrapPromise,
 } from './core/PromiseWrapper';
-export { subscribe, normalizeData } from './core/cache';
+export {
+  subscribe,
+  normalizeData,
+  normalizeIncrementalPayload,
+  type IncrementalPayload,
+} from './core/cache';
 export { makeNetworkRequest } from './core/makeNetworkRequest';
 export {
   ROOT_ID,
@@ -32,15 +37,19 @@ export {
   type ReaderAst,
   type ReaderAstNode,
   type ReaderLinkedField,
+  type ReaderPagination,
   type ReaderNonLoadableResolverField,
   type ReaderScalarField,
+  type RequiredAction,
   type TopLevelReaderArtifact,
+  type UpdaterArtifact,
   type LoadableField,
   type ResolverFirstParameter,
 } from './core/reader';
 export {
   type NormalizationAst,
   type NormalizationAstNode,
+  type NormalizationDeferredFragment,
   type NormalizationLinkedField,
   type NormalizationScalarField,
   type IsographEntrypoint,
@@ -53,6 +62,7 @@ export {
   type NetworkRequestInfo,
 } from './core/entrypoint';
 export { readButDoNotEvaluate } from './core/read';
+export { readUpdatableData } from './core/updatable';
 export {
   type ExtractSecondParam,
   type CombineWithIntrinsicAttributes,
@@ -60,6 +70,8 @@ export {
   type ArgumentName,
   type ArgumentValue,
   type Arguments,
+  type SelectionCondition,
+  type SelectionConditions,
 } from './core/util';
 export {
   type FragmentReference,
@@ -1,5 +1,10 @@
 import { describe, expect, test, vi } from 'vitest';
-import { getOrCreateCacheForArtifact, normalizeData } from '../core/cache';
+import {
+  getOrCreateCacheForArtifact,
+  normalizeData,
+  normalizeIncrementalPayload,
+} from '../core/cache';
+import type { NormalizationAst } from '../core/entrypoint';
 import {
   createIsographEnvironment,
   createIsographStore,
@@ -67,6 +72,125 @@ describe('normalizeData', () => {
   });
 });
 
+describe('normalizeIncrementalPayload', () => {
+  const root = { __link: ROOT_ID, __typename: 'Query' };
+
+  test('deferred data should be normalized into the record at its path', () => {
+    const normalizationAst: NormalizationAst = [
+      {
+        kind: 'Linked',
+        fieldName: 'me',
+        arguments: null,
+        concreteType: 'Economist',
+        selections: [
+          { kind: 'Scalar', fieldName: 'id', arguments: null },
+          {
+            kind: 'Defer',
+            label: 'Economist__successor__defer',
+            selections: [
+              {
+                kind: 'Linked',
+                fieldName: 'successor',
+                arguments: null,
+                concreteType: 'Economist',
+                selections: [
+                  { kind: 'Scalar', fieldName: 'id', arguments: null },
+                ],
+              },
+            ],
+          },
+        ],
+      },
+    ];
+    const store = createIsographStore();
+    const environment = createIsographEnvironment(store, vi.fn());
+
+    normalizeData(
+      environment,
+      normalizationAst,
+      { me: { id: '1' } },
+      {},
+      [],
+      root,
+    );
+    expect(store.Economist).toStrictEqual({ '1': { id: '1' } });
+
+    normalizeIncrementalPayload(
+      environment,
+      normalizationAst,
+      {
+        label: 'Economist__successor__defer',
+        path: ['me'],
+        data: { successor: { id: '2' } },
+      },
+      {},
+      [],
+      root,
+    );
+    expect(store.Economist).toStrictEqual({
+      '1': { id: '1', successor: { __link: '2', __typename: 'Economist' } },
+      '2': { id: '2' },
+    });
+  });
+
+  test('streamed items should be added to the list at their path', () => {
+    const normalizationAst: NormalizationAst = [
+      {
+        kind: 'Linked',
+        fieldName: 'me',
+        arguments: null,
+        concreteType: 'Economist',
+        selections: [
+          { kind: 'Scalar', fieldName: 'id', arguments: null },
+          {
+            kind: 'Linked',
+            fieldName: 'friends',
+            arguments: null,
+            concreteType: 'Economist',
+            stream: { label: 'Economist__friends__stream', initialCount: 1 },
+            selections: [{ kind: 'Scalar', fieldName: 'id', arguments: null }],
+          },
+        ],
+      },
+    ];
+    const store = createIsographStore();
+    const environment = createIsographEnvironment(store, vi.fn());
+
+    normalizeData(
+      environment,
+      normalizationAst,
+      { me: { id: '1', friends: [{ id: '2' }] } },
+      {},
+      [],
+      root,
+    );
+    normalizeIncrementalPayload(
+      environment,
+      normalizationAst,
+      {
+        label: 'Economist__friends__stream',
+        path: ['me', 'friends', 1],
+        items: [{ id: '3' }],
+      },
+      {},
+      [],
+      root,
+    );
+
+    expect(store.Economist).toStrictEqual({
+      '1': {
+        id: '1',
+        friends: [
+          { __link: '2', __typename: 'Economist' },
+          { __link: '3', __typename: 'Economist' },
+        ],
+      },
+      '2': { id: '2' },
+      '3': { id: '3' },
+    });
+  });
+});
+
 describe('readData', () => {
   test('nested Query should be read', () => {
     const store: IsographStore = {